@@ -0,0 +1,147 @@
+use crate::parse::{self, ParseError};
+use std::collections::HashMap;
+
+/// Where the checked-in known answers live.
+pub const DEFAULT_PATH: &str = "src/bin/answers.txt";
+
+/// Known answers keyed by day, part and the path of the input they were produced from.
+pub type Answers = HashMap<(u8, u8, String), String>;
+
+/// Parse an answers file.  Each line holds a day, a part, an input path and the answer, separated
+/// by whitespace; the answer runs to the end of the line so it may contain spaces itself.  Lines
+/// starting with `#` are comments.
+pub fn parse(input: &str) -> Result<Answers, ParseError> {
+    let mut answers: Answers = HashMap::new();
+    for (i, line) in parse::lines(input) {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+        let (day, rest) = split_field(line);
+        let (part, rest) = split_field(rest);
+        let (path, answer) = split_field(rest);
+        if answer.is_empty() {
+            return Err(ParseError::within(
+                line,
+                line,
+                "expected a day, part, input path and answer",
+            )
+            .on_line(i));
+        }
+        let day = parse::number::<u8>(line, day).map_err(|e| e.on_line(i))?;
+        let part = parse::number::<u8>(line, part).map_err(|e| e.on_line(i))?;
+        let key = (day, part, path.to_string());
+        if answers.contains_key(&key) {
+            return Err(ParseError::within(
+                line,
+                line,
+                "an answer for this day, part and input was already given",
+            )
+            .on_line(i));
+        }
+        answers.insert(key, answer.trim_end().to_string());
+    }
+    Ok(answers)
+}
+
+/// Split the first whitespace separated field off `line`, returning it and the rest of the line.
+fn split_field(line: &str) -> (&str, &str) {
+    let line = line.trim_start();
+    match line.find(char::is_whitespace) {
+        Some(end) => (&line[..end], line[end..].trim_start()),
+        None => (line, ""),
+    }
+}
+
+/// How an answer compares with the one on record.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+pub fn check(answers: &Answers, day: u8, part: u8, input: &str, actual: &str) -> Verdict {
+    match answers.get(&(day, part, input.to_string())) {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.clone(),
+        },
+        None => Verdict::Missing,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("# day part input answer\n1 1 src/bin/day01.txt 514579\n22 2 in.txt (1, 23)\n"),
+            Ok(hashmap!(
+                (1, 1, "src/bin/day01.txt".to_string()) => "514579".to_string(),
+                (22, 2, "in.txt".to_string()) => "(1, 23)".to_string()
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_aligned_columns() {
+        assert_eq!(
+            parse("01  1   a.txt   42  \n"),
+            Ok(hashmap!((1, 1, "a.txt".to_string()) => "42".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_parse_missing_answer() {
+        assert_eq!(
+            parse("1 1 a.txt 42\n2 1 b.txt\n"),
+            Err(ParseError::new(
+                2,
+                1,
+                "2 1 b.txt",
+                "expected a day, part, input path and answer"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_part() {
+        assert_eq!(
+            parse("1 one a.txt 42\n"),
+            Err(ParseError::new(1, 3, "one", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_parse_duplicate() {
+        assert_eq!(
+            parse("1 1 a.txt 42\n1 1 a.txt 43\n").map_err(|e| e.line),
+            Err(2)
+        )
+    }
+
+    #[test]
+    fn test_check_pass() {
+        let answers = parse("1 1 a.txt 42\n").unwrap();
+        assert_eq!(check(&answers, 1, 1, "a.txt", "42"), Verdict::Pass)
+    }
+
+    #[test]
+    fn test_check_fail() {
+        let answers = parse("1 1 a.txt 42\n").unwrap();
+        assert_eq!(
+            check(&answers, 1, 1, "a.txt", "41"),
+            Verdict::Fail {
+                expected: "42".to_string()
+            }
+        )
+    }
+
+    #[test]
+    fn test_check_missing_input() {
+        let answers = parse("1 1 a.txt 42\n").unwrap();
+        assert_eq!(check(&answers, 1, 1, "b.txt", "42"), Verdict::Missing)
+    }
+}
@@ -0,0 +1,262 @@
+//! A cellular automaton engine: each step, every cell's next state is decided by a rule from its
+//! current state and how many of its neighbours are live.
+//!
+//! An automaton is configured with a topology, which says which cells neighbour a given cell, and
+//! a rule.  Its state can be stored densely, as a `Grid` holding every cell, or sparsely, as the
+//! set of positions of the live cells in an unbounded space.
+
+use crate::grid::{self, Grid, NEIGHBOURS8};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A cell state that is either live or not, as far as its neighbours are concerned.
+pub trait Cell {
+    fn is_live(&self) -> bool;
+}
+
+impl Cell for bool {
+    fn is_live(&self) -> bool {
+        *self
+    }
+}
+
+/// Which cells neighbour a given cell.
+pub trait Topology<P> {
+    fn neighbours(&self, pos: &P) -> Vec<P>;
+}
+
+/// Any function from a position to its neighbours is a topology.
+impl<P, F: Fn(&P) -> Vec<P>> Topology<P> for F {
+    fn neighbours(&self, pos: &P) -> Vec<P> {
+        self(pos)
+    }
+}
+
+/// All eight adjacent cells in a grid of the given size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adjacent {
+    pub height: usize,
+    pub width: usize,
+}
+
+impl Adjacent {
+    pub fn of<T>(grid: &Grid<T>) -> Adjacent {
+        Adjacent {
+            height: grid.height(),
+            width: grid.width(),
+        }
+    }
+}
+
+impl Topology<grid::Pos> for Adjacent {
+    fn neighbours(&self, &(row, col): &grid::Pos) -> Vec<grid::Pos> {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(|&(d_row, d_col)| {
+                Some((
+                    row.checked_add_signed(d_row)?,
+                    col.checked_add_signed(d_col)?,
+                ))
+            })
+            .filter(|&(row, col)| row < self.height && col < self.width)
+            .collect()
+    }
+}
+
+/// A way of holding the state of every cell.
+pub trait Storage: Sized + PartialEq {
+    type Pos;
+    type Cell;
+
+    /// Apply `rule` to every cell at once.
+    fn step(
+        &self,
+        topology: &impl Topology<Self::Pos>,
+        rule: &impl Fn(&Self::Cell, usize) -> Self::Cell,
+    ) -> Self;
+
+    /// How many cells are live.
+    fn population(&self) -> usize;
+}
+
+/// Dense storage: every cell of a fixed-size grid.
+impl<C: Cell + PartialEq> Storage for Grid<C> {
+    type Pos = grid::Pos;
+    type Cell = C;
+
+    fn step(&self, topology: &impl Topology<grid::Pos>, rule: &impl Fn(&C, usize) -> C) -> Grid<C> {
+        self.map(|pos, cell| {
+            let live = topology
+                .neighbours(&pos)
+                .iter()
+                .filter(|neighbour| self[**neighbour].is_live())
+                .count();
+            rule(cell, live)
+        })
+    }
+
+    fn population(&self) -> usize {
+        self.iter().filter(|(_, cell)| cell.is_live()).count()
+    }
+}
+
+/// Sparse storage: the positions of the live cells, with every other cell dead.  Only live cells
+/// and their neighbours are visited, so the rule must keep a dead cell with no live neighbours
+/// dead.
+impl<P: Copy + Eq + Hash> Storage for HashSet<P> {
+    type Pos = P;
+    type Cell = bool;
+
+    fn step(
+        &self,
+        topology: &impl Topology<P>,
+        rule: &impl Fn(&bool, usize) -> bool,
+    ) -> HashSet<P> {
+        let mut counts: HashMap<P, usize> = self.iter().map(|pos| (*pos, 0)).collect();
+        for pos in self {
+            for neighbour in topology.neighbours(pos) {
+                *counts.entry(neighbour).or_default() += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .filter(|(pos, live)| rule(&self.contains(pos), *live))
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    fn population(&self) -> usize {
+        self.len()
+    }
+}
+
+pub struct Automaton<T, R> {
+    topology: T,
+    rule: R,
+}
+
+impl<T, R> Automaton<T, R> {
+    /// An automaton whose cells change according to `rule`, given a cell's current state and the
+    /// number of its neighbours under `topology` that are live.
+    pub fn new(topology: T, rule: R) -> Automaton<T, R> {
+        Automaton { topology, rule }
+    }
+
+    pub fn step<S>(&self, state: &S) -> S
+    where
+        S: Storage,
+        T: Topology<S::Pos>,
+        R: Fn(&S::Cell, usize) -> S::Cell,
+    {
+        state.step(&self.topology, &self.rule)
+    }
+
+    /// The state after `steps` steps.
+    pub fn run<S>(&self, state: S, steps: usize) -> S
+    where
+        S: Storage,
+        T: Topology<S::Pos>,
+        R: Fn(&S::Cell, usize) -> S::Cell,
+    {
+        (0..steps).fold(state, |state, step| {
+            debug!("step {}: population {}", step, state.population());
+            self.step(&state)
+        })
+    }
+
+    /// The first state that a step leaves unchanged, and the number of steps taken to reach it.
+    /// Never returns if the automaton doesn't settle.
+    pub fn run_until_stable<S>(&self, state: S) -> (S, usize)
+    where
+        S: Storage,
+        T: Topology<S::Pos>,
+        R: Fn(&S::Cell, usize) -> S::Cell,
+    {
+        let mut state = state;
+        let mut steps = 0;
+        loop {
+            let next = self.step(&state);
+            if next == state {
+                return (state, steps);
+            }
+            debug!("step {}: population {}", steps, next.population());
+            state = next;
+            steps += 1;
+        }
+    }
+}
+
+/// Conway's Game of Life: a live cell survives with two or three live neighbours and a dead cell
+/// comes alive with exactly three.
+pub fn life(live: &bool, neighbours: usize) -> bool {
+    neighbours == 3 || (*live && neighbours == 2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        NEIGHBOURS8
+            .iter()
+            .map(|&(dx, dy)| (x + dx as i32, y + dy as i32))
+            .collect()
+    }
+
+    fn grid(rows: &[&str]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_adjacent_corner() {
+        assert_eq!(
+            Adjacent {
+                height: 3,
+                width: 3
+            }
+            .neighbours(&(0, 0)),
+            vec![(0, 1), (1, 1), (1, 0)]
+        )
+    }
+
+    #[test]
+    fn test_dense_blinker() {
+        let automaton = Automaton::new(Adjacent::of(&grid(&["...", "...", "..."])), life);
+        assert_eq!(
+            automaton.step(&grid(&["...", "###", "..."])),
+            grid(&[".#.", ".#.", ".#."])
+        )
+    }
+
+    #[test]
+    fn test_sparse_blinker() {
+        let automaton = Automaton::new(square, life);
+        assert_eq!(
+            automaton.step(&hashset!((-1, 0), (0, 0), (1, 0))),
+            hashset!((0, -1), (0, 0), (0, 1))
+        )
+    }
+
+    #[test]
+    fn test_run() {
+        let automaton = Automaton::new(square, life);
+        assert_eq!(
+            automaton.run(hashset!((-1, 0), (0, 0), (1, 0)), 2),
+            hashset!((-1, 0), (0, 0), (1, 0))
+        )
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        let automaton = Automaton::new(square, life);
+        assert_eq!(
+            automaton.run_until_stable(hashset!((0, 0), (0, 1), (1, 0))),
+            (hashset!((0, 0), (0, 1), (1, 0), (1, 1)), 1)
+        )
+    }
+
+    #[test]
+    fn test_population() {
+        assert_eq!(grid(&["#..", ".##"]).population(), 3)
+    }
+}
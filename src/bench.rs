@@ -0,0 +1,370 @@
+use crate::parse::{self, ParseError};
+use crate::registry::{Answer, Overrides, Solution, SolveError};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Changes in a median smaller than this are put down to noise rather than reported as
+/// regressions, however large they are relative to the baseline.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// The spread of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarise a non-empty set of samples.  The median of an even number of samples is the
+    /// mean of the middle two.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}/{:?}/{:?}", self.min, self.median, self.max)
+    }
+}
+
+/// How long a solution took to parse its input and to solve it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// The answer from a single run of a solution and how long each phase took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Parse and solve `input` once, timing the two phases separately.
+pub fn run(
+    solution: &Solution,
+    input: &str,
+    overrides: Option<&Overrides>,
+) -> Result<Run, SolveError> {
+    let start = Instant::now();
+    let solver = solution.prepare(input, overrides)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solver().map_err(SolveError::Unsolved)?;
+    Ok(Run {
+        answer,
+        parse,
+        solve: start.elapsed(),
+    })
+}
+
+/// Parse and solve `input` `iterations` times, timing the two phases separately.
+pub fn measure(
+    solution: &Solution,
+    input: &str,
+    overrides: Option<&Overrides>,
+    iterations: usize,
+) -> Result<Timings, SolveError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        let run = run(solution, input, overrides)?;
+        parse.push(run.parse);
+        solve.push(run.solve);
+    }
+    Ok(Timings {
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+    })
+}
+
+/// Timings from an earlier run, keyed by day and part.
+pub type Baseline = HashMap<(u8, u8), Timings>;
+
+/// Parse a baseline file.  Each line holds a day and part followed by the minimum, median and
+/// maximum parse times and then the same for solving, all in nanoseconds.  Lines starting with
+/// `#` are comments.
+pub fn parse_baseline(input: &str) -> Result<Baseline, ParseError> {
+    let mut baseline = HashMap::new();
+    for (i, line) in parse::lines(input) {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        if fields.len() != 8 {
+            return Err(
+                ParseError::within(line, line, "expected a day, part and six timings").on_line(i),
+            );
+        }
+        let day = parse::number::<u8>(line, fields[0]).map_err(|e| e.on_line(i))?;
+        let part = parse::number::<u8>(line, fields[1]).map_err(|e| e.on_line(i))?;
+        let mut nanos = [Duration::default(); 6];
+        for (n, field) in nanos.iter_mut().zip(&fields[2..]) {
+            *n = Duration::from_nanos(parse::number(line, field).map_err(|e| e.on_line(i))?);
+        }
+        let timings = Timings {
+            parse: Stats {
+                min: nanos[0],
+                median: nanos[1],
+                max: nanos[2],
+            },
+            solve: Stats {
+                min: nanos[3],
+                median: nanos[4],
+                max: nanos[5],
+            },
+        };
+        if baseline.insert((day, part), timings).is_some() {
+            return Err(ParseError::within(
+                line,
+                line,
+                "timings for this day and part were already given",
+            )
+            .on_line(i));
+        }
+    }
+    Ok(baseline)
+}
+
+/// Write a baseline in the format read by `parse_baseline`, ordered by day and part.
+pub fn format_baseline(baseline: &Baseline) -> String {
+    let mut keys = baseline.keys().collect::<Vec<&(u8, u8)>>();
+    keys.sort_unstable();
+    let mut out =
+        String::from("# day part parse min/median/max solve min/median/max, in nanoseconds\n");
+    for key in keys {
+        let timings = &baseline[key];
+        let stats = [timings.parse, timings.solve];
+        let nanos = stats
+            .iter()
+            .flat_map(|s| vec![s.min, s.median, s.max])
+            .map(|d| d.as_nanos().to_string())
+            .collect::<Vec<String>>();
+        out.push_str(&format!("{:02} {} {}\n", key.0, key.1, nanos.join(" ")));
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve => write!(f, "solve"),
+        }
+    }
+}
+
+/// A phase whose median got slower than its baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub phase: Phase,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let change = (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0;
+        write!(
+            f,
+            "{} median {:?} -> {:?} (+{:.0}%)",
+            self.phase, self.before, self.after, change
+        )
+    }
+}
+
+/// The phases whose median is more than `tolerance` (a fraction, so 0.1 is 10%) slower than in
+/// `before`.  Slowdowns smaller than `NOISE_FLOOR` are ignored.
+pub fn regressions(before: &Timings, after: &Timings, tolerance: f64) -> Vec<Regression> {
+    let phases = [
+        (Phase::Parse, before.parse.median, after.parse.median),
+        (Phase::Solve, before.solve.median, after.solve.median),
+    ];
+    phases
+        .iter()
+        .filter(|(_, before, after)| {
+            *after > *before + NOISE_FLOOR
+                && after.as_secs_f64() > before.as_secs_f64() * (1.0 + tolerance)
+        })
+        .map(|&(phase, before, after)| Regression {
+            phase,
+            before,
+            after,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn timings(parse: u64, solve: u64) -> Timings {
+        Timings {
+            parse: Stats {
+                min: ms(parse),
+                median: ms(parse),
+                max: ms(parse),
+            },
+            solve: Stats {
+                min: ms(solve),
+                median: ms(solve),
+                max: ms(solve),
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats_odd() {
+        assert_eq!(
+            Stats::from_samples(&[ms(5), ms(1), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        )
+    }
+
+    #[test]
+    fn test_stats_even() {
+        assert_eq!(
+            Stats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8)
+            }
+        )
+    }
+
+    #[test]
+    fn test_run() {
+        let solution = registry::find(1, 1).unwrap();
+        let run = run(&solution, "1721\n979\n366\n299\n675\n1456\n", None).unwrap();
+        assert_eq!(run.answer, Answer::Int(514579))
+    }
+
+    #[test]
+    fn test_measure() {
+        let solution = registry::find(1, 1).unwrap();
+        let timings = measure(&solution, "1721\n979\n366\n299\n675\n1456\n", None, 3).unwrap();
+        assert!(
+            timings.solve.min <= timings.solve.median && timings.solve.median <= timings.solve.max
+        )
+    }
+
+    #[test]
+    fn test_measure_parse_error() {
+        let solution = registry::find(1, 1).unwrap();
+        assert_eq!(
+            measure(&solution, "1721\n97x\n", None, 3),
+            Err(SolveError::Parse(ParseError::new(
+                2,
+                1,
+                "97x",
+                "expected a number"
+            )))
+        )
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = hashmap!((1, 2) => timings(1, 20), (1, 1) => timings(3, 4));
+        assert_eq!(parse_baseline(&format_baseline(&baseline)), Ok(baseline))
+    }
+
+    #[test]
+    fn test_format_baseline() {
+        assert_eq!(
+            format_baseline(&hashmap!((3, 1) => timings(1, 2))),
+            "# day part parse min/median/max solve min/median/max, in nanoseconds\n\
+             03 1 1000000 1000000 1000000 2000000 2000000 2000000\n"
+        )
+    }
+
+    #[test]
+    fn test_parse_baseline_short_line() {
+        assert_eq!(
+            parse_baseline("1 1 1 2 3\n"),
+            Err(ParseError::new(
+                1,
+                1,
+                "1 1 1 2 3",
+                "expected a day, part and six timings"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_regressions() {
+        assert_eq!(
+            regressions(&timings(1, 10), &timings(1, 12), 0.1),
+            vec![Regression {
+                phase: Phase::Solve,
+                before: ms(10),
+                after: ms(12)
+            }]
+        )
+    }
+
+    #[test]
+    fn test_regressions_within_tolerance() {
+        assert_eq!(regressions(&timings(1, 10), &timings(1, 12), 0.25), vec![])
+    }
+
+    #[test]
+    fn test_regressions_below_noise_floor() {
+        let before = Timings {
+            parse: Stats::from_samples(&[Duration::from_micros(10)]),
+            solve: Stats::from_samples(&[Duration::from_micros(10)]),
+        };
+        let after = Timings {
+            parse: Stats::from_samples(&[Duration::from_micros(50)]),
+            solve: Stats::from_samples(&[Duration::from_micros(10)]),
+        };
+        assert_eq!(regressions(&before, &after, 0.1), vec![])
+    }
+
+    #[test]
+    fn test_display_regression() {
+        assert_eq!(
+            Regression {
+                phase: Phase::Parse,
+                before: ms(2),
+                after: ms(3)
+            }
+            .to_string(),
+            "parse median 2ms -> 3ms (+50%)"
+        )
+    }
+}
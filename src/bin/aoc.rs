@@ -0,0 +1,603 @@
+use advent_2020::answers::{self, Verdict};
+use advent_2020::bench::{self, Baseline, Run, Stats};
+use advent_2020::day19::Symbol;
+use advent_2020::json::Json;
+use advent_2020::registry::{self, Answer, Overrides, Solution};
+use advent_2020::trace;
+use std::collections::HashMap;
+use std::io::Read;
+use std::process;
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> [part] [--input <path>] [--overrides <path>]
+            [--format <text|json>]
+    aoc run all [--overrides <path>] [--format <text|json>]
+    aoc verify [all | <day> [part]] [--input <path>] [--overrides <path>]
+               [--answers <path>] [--format <text|json>]
+    aoc bench [all | <day> [part]] [--input <path>] [--overrides <path>]
+              [--iterations <n>] [--save <path>]
+              [--baseline <path> [--tolerance <percent>]] [--format <text|json>]
+
+Every command accepts -v to show what the solvers are doing and -vv to trace
+each step they take; this goes to stderr.
+
+Without a part both parts of the day are run.  The input defaults to
+src/bin/dayNN.txt; pass `--input -` to read it from stdin.  `--format json`
+prints a JSON array with an object per part giving its day, part, input path
+and results, with every timing in nanoseconds.
+
+Day 19 part 2 also reads rules to replace in the input's grammar, from
+src/bin/day19-overrides.txt unless `--overrides` names another file.
+
+verify compares answers with the known answers in src/bin/answers.txt, which
+are keyed by day, part and input path, and fails if any of them differ.
+
+bench times parsing and solving separately over a number of iterations (10 by
+default) and prints the min/median/max of each.  `--save` writes the timings to
+a baseline file; `--baseline` compares them with an earlier one and fails if a
+median is more than the tolerance (10% by default) slower.";
+
+/// Why a run failed.  Only mistakes in the arguments are followed by the usage text.
+enum Error {
+    Usage(String),
+    Input(String),
+}
+
+/// Positional arguments and flag values given to a command.
+struct Options<'a> {
+    positional: Vec<&'a str>,
+    flags: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Options<'a> {
+    fn flag(&self, name: &str) -> Option<&'a str> {
+        self.flags.get(name).copied()
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    match run(&args) {
+        Ok(()) => (),
+        Err(Error::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(1);
+        }
+        Err(Error::Input(message)) => {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Error> {
+    let command = args
+        .first()
+        .ok_or_else(|| Error::Usage("no command given".to_string()))?;
+    let args = &args[1..];
+    match command.as_str() {
+        "run" => run_solutions(&parse_options(
+            args,
+            &["--input", "--overrides", "--format"],
+        )?),
+        "verify" => verify(&parse_options(
+            args,
+            &["--input", "--overrides", "--answers", "--format"],
+        )?),
+        "bench" => benchmark(&parse_options(
+            args,
+            &[
+                "--input",
+                "--overrides",
+                "--iterations",
+                "--save",
+                "--baseline",
+                "--tolerance",
+                "--format",
+            ],
+        )?),
+        command => Err(Error::Usage(format!("unknown command '{}'", command))),
+    }
+}
+
+/// Split a command's arguments into positional arguments and the values of `flags`, each of
+/// which takes a single value.  `-i` is short for `--input`.  Verbosity flags (`-v`, `-vv`) are
+/// accepted by every command and set the trace level straight away.
+fn parse_options<'a>(args: &'a [String], flags: &[&str]) -> Result<Options<'a>, Error> {
+    let mut options = Options {
+        positional: Vec::new(),
+        flags: HashMap::new(),
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if let Some(level) = trace::parse_flag(arg) {
+            trace::set_level(level.max(trace::level()));
+            continue;
+        }
+        let arg = match arg.as_str() {
+            "-i" => "--input",
+            arg => arg,
+        };
+        match flags.iter().find(|&&flag| flag == arg) {
+            Some(flag) => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| Error::Usage(format!("{} requires a value", flag)))?;
+                options.flags.insert(arg, value.as_str());
+            }
+            None if arg.starts_with("--") => {
+                return Err(Error::Usage(format!("unknown flag '{}'", arg)))
+            }
+            None => options.positional.push(arg),
+        }
+    }
+    Ok(options)
+}
+
+/// The solutions picked out by the positional arguments: `all`, a day, or a day and part.
+fn select(positional: &[&str]) -> Result<Vec<Solution>, Error> {
+    match positional {
+        ["all"] => Ok(registry::solutions()),
+        [day] => {
+            let day = parse_number(day, "day")?;
+            let solutions = registry::solutions()
+                .into_iter()
+                .filter(|solution| solution.day == day)
+                .collect::<Vec<Solution>>();
+            if solutions.is_empty() {
+                return Err(Error::Usage(format!(
+                    "no solutions registered for day {}",
+                    day
+                )));
+            }
+            Ok(solutions)
+        }
+        [day, part] => {
+            let day = parse_number(day, "day")?;
+            let part = parse_number(part, "part")?;
+            let solution = registry::find(day, part).ok_or_else(|| {
+                Error::Usage(format!(
+                    "no solution registered for day {} part {}",
+                    day, part
+                ))
+            })?;
+            Ok(vec![solution])
+        }
+        [] => Err(Error::Usage("expected a day or 'all'".to_string())),
+        _ => Err(Error::Usage("too many arguments".to_string())),
+    }
+}
+
+fn run_solutions(options: &Options) -> Result<(), Error> {
+    let mut report = Report::new(output_format(options)?);
+    let solutions = select(&options.positional)?;
+    match options.positional.as_slice() {
+        ["all"] => {
+            if options.flag("--input").is_some() {
+                return Err(Error::Usage(
+                    "--input cannot be used when running all days".to_string(),
+                ));
+            }
+            run_all(options, solutions, &mut report);
+        }
+        [_] => {
+            // read the input once so stdin can feed both parts
+            let path = input_path(options, &solutions[0]);
+            let text = read_input(&path).map_err(Error::Input)?;
+            for solution in solutions {
+                let overrides = load_overrides(options, &solution).map_err(Error::Input)?;
+                let run =
+                    run_part(&solution, &path, &text, overrides.as_ref()).map_err(Error::Input)?;
+                report.add(
+                    format_answer(&solution, &run.answer.to_string()),
+                    run_record(&solution, &path, &run),
+                );
+            }
+        }
+        _ => {
+            let solution = &solutions[0];
+            let path = input_path(options, solution);
+            let text = read_input(&path).map_err(Error::Input)?;
+            let overrides = load_overrides(options, solution).map_err(Error::Input)?;
+            let run = run_part(solution, &path, &text, overrides.as_ref()).map_err(Error::Input)?;
+            report.add(run.answer.to_string(), run_record(solution, &path, &run));
+        }
+    }
+    report.finish();
+    Ok(())
+}
+
+/// Run every registered solution against its default input, skipping days without one and
+/// reporting any input that fails to parse without stopping the run.
+fn run_all(options: &Options, solutions: Vec<Solution>, report: &mut Report) {
+    for solution in solutions {
+        let path = solution.default_input();
+        let (answer, record) = match read_input(&path) {
+            Ok(text) => match load_overrides(options, &solution) {
+                Ok(overrides) => match bench::run(&solution, &text, overrides.as_ref()) {
+                    Ok(run) => (run.answer.to_string(), run_record(&solution, &path, &run)),
+                    Err(e) => (
+                        format!("failed ({}: {})", path, e),
+                        error_record(&solution, &path, &e.to_string()),
+                    ),
+                },
+                Err(message) => (
+                    format!("failed ({})", message),
+                    error_record(&solution, &path, &message),
+                ),
+            },
+            Err(message) => (
+                format!("skipped ({})", message),
+                error_record(&solution, &path, &message),
+            ),
+        };
+        report.add(format_answer(&solution, &answer), record);
+    }
+}
+
+/// Check the selected solutions against the known answers, failing if any of them has changed.
+/// Parts with no recorded answer, or no input to run against, are reported as missing but do
+/// not fail the check.
+fn verify(options: &Options) -> Result<(), Error> {
+    let mut report = Report::new(output_format(options)?);
+    let solutions = if options.positional.is_empty() {
+        registry::solutions()
+    } else {
+        select(&options.positional)?
+    };
+    let answers_path = options.flag("--answers").unwrap_or(answers::DEFAULT_PATH);
+    let answers_text = read_input(answers_path).map_err(Error::Input)?;
+    let answers = answers::parse(&answers_text)
+        .map_err(|e| Error::Input(format!("{}: {}", answers_path, e.render(&answers_text))))?;
+
+    // inputs are only read once so stdin can feed every part
+    let mut inputs: HashMap<String, Result<String, String>> = HashMap::new();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solution in solutions {
+        let path = input_path(options, &solution);
+        let text = inputs
+            .entry(path.clone())
+            .or_insert_with(|| read_input(&path));
+        let (verdict, fields) = match text {
+            Ok(text) => match load_overrides(options, &solution)
+                .and_then(|overrides| solve(&solution, &path, text, overrides.as_ref()))
+            {
+                Ok(answer) => {
+                    let verdict = answers::check(
+                        &answers,
+                        solution.day,
+                        solution.part,
+                        &path,
+                        &answer.to_string(),
+                    );
+                    let (line, status, expected) = match verdict {
+                        Verdict::Pass => {
+                            passed += 1;
+                            ("pass".to_string(), "pass", Json::from(&answer))
+                        }
+                        Verdict::Fail { expected } => {
+                            failed += 1;
+                            (
+                                format!("FAIL (expected {}, got {})", expected, answer),
+                                "fail",
+                                Json::answer(&expected),
+                            )
+                        }
+                        Verdict::Missing => {
+                            missing += 1;
+                            (
+                                format!("missing (no answer recorded for {})", path),
+                                "missing",
+                                Json::Null,
+                            )
+                        }
+                    };
+                    let fields = vec![
+                        ("status", Json::from(status)),
+                        ("answer", Json::from(&answer)),
+                        ("expected", expected),
+                    ];
+                    (line, fields)
+                }
+                Err(message) => {
+                    failed += 1;
+                    let fields = vec![
+                        ("status", Json::from("fail")),
+                        ("error", Json::from(message.as_str())),
+                    ];
+                    (format!("FAIL ({})", message), fields)
+                }
+            },
+            Err(message) => {
+                missing += 1;
+                let fields = vec![
+                    ("status", Json::from("missing")),
+                    ("error", Json::from(message.as_str())),
+                ];
+                (format!("missing ({})", message), fields)
+            }
+        };
+        report.add(
+            format_answer(&solution, &verdict),
+            record(&solution, &path, fields),
+        );
+    }
+    if report.format == Format::Text {
+        println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    }
+    report.finish();
+
+    match failed {
+        0 => Ok(()),
+        1 => Err(Error::Input("1 answer did not match".to_string())),
+        n => Err(Error::Input(format!("{} answers did not match", n))),
+    }
+}
+
+/// Time the selected solutions, optionally saving the timings as a baseline and flagging any
+/// that are slower than an earlier one.
+fn benchmark(options: &Options) -> Result<(), Error> {
+    let mut report = Report::new(output_format(options)?);
+    let solutions = if options.positional.is_empty() {
+        registry::solutions()
+    } else {
+        select(&options.positional)?
+    };
+    let iterations = match options.flag("--iterations") {
+        Some(value) => value
+            .parse::<usize>()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| {
+                Error::Usage(format!(
+                    "iterations must be a positive number, got '{}'",
+                    value
+                ))
+            })?,
+        None => 10,
+    };
+    let tolerance = match options.flag("--tolerance") {
+        Some(value) => value
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .ok()
+            .filter(|&t| t >= 0.0)
+            .ok_or_else(|| {
+                Error::Usage(format!("tolerance must be a percentage, got '{}'", value))
+            })?,
+        None => 10.0,
+    } / 100.0;
+    let previous = match options.flag("--baseline") {
+        Some(path) => {
+            let text = read_input(path).map_err(Error::Input)?;
+            let baseline = bench::parse_baseline(&text)
+                .map_err(|e| Error::Input(format!("{}: {}", path, e.render(&text))))?;
+            Some(baseline)
+        }
+        None => None,
+    };
+
+    let mut inputs: HashMap<String, Result<String, String>> = HashMap::new();
+    let mut current = Baseline::new();
+    let mut regressed = 0;
+    for solution in solutions {
+        let path = input_path(options, &solution);
+        let text = inputs
+            .entry(path.clone())
+            .or_insert_with(|| read_input(&path));
+        let text = match text {
+            Ok(text) => text,
+            Err(message) => {
+                report.add(
+                    format_answer(&solution, &format!("skipped ({})", message)),
+                    error_record(&solution, &path, message),
+                );
+                continue;
+            }
+        };
+        let overrides = load_overrides(options, &solution).map_err(Error::Input)?;
+        let timings = bench::measure(&solution, text, overrides.as_ref(), iterations)
+            .map_err(|e| Error::Input(format!("{}: {}", path, e.render(text))))?;
+        let mut line = format!("parse {}, solve {}", timings.parse, timings.solve);
+        let key = (solution.day, solution.part);
+        let regressions = match previous.as_ref().and_then(|previous| previous.get(&key)) {
+            Some(before) => bench::regressions(before, &timings, tolerance),
+            None => Vec::new(),
+        };
+        if !regressions.is_empty() {
+            regressed += 1;
+        }
+        for regression in &regressions {
+            line.push_str(&format!("\n    REGRESSED {}", regression));
+        }
+        let fields = vec![
+            ("iterations", Json::from(iterations)),
+            ("parse", stats_json(&timings.parse)),
+            ("solve", stats_json(&timings.solve)),
+            (
+                "regressions",
+                Json::Array(
+                    regressions
+                        .iter()
+                        .map(|regression| {
+                            Json::object(vec![
+                                ("phase", Json::from(regression.phase.to_string())),
+                                ("before_ns", Json::from(regression.before)),
+                                ("after_ns", Json::from(regression.after)),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ];
+        report.add(
+            format_answer(&solution, &line),
+            record(&solution, &path, fields),
+        );
+        current.insert(key, timings);
+    }
+    report.finish();
+
+    if let Some(path) = options.flag("--save") {
+        std::fs::write(path, bench::format_baseline(&current))
+            .map_err(|e| Error::Input(format!("unable to write {}: {}", path, e)))?;
+    }
+    match regressed {
+        0 => Ok(()),
+        1 => Err(Error::Input("1 part got slower".to_string())),
+        n => Err(Error::Input(format!("{} parts got slower", n))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn output_format(options: &Options) -> Result<Format, Error> {
+    match options.flag("--format") {
+        None | Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(format) => Err(Error::Usage(format!(
+            "unknown format '{}', expected text or json",
+            format
+        ))),
+    }
+}
+
+/// Collects a command's results, printing each line of text as it comes or every record as a
+/// single JSON array once the command has finished.
+struct Report {
+    format: Format,
+    records: Vec<Json>,
+}
+
+impl Report {
+    fn new(format: Format) -> Report {
+        Report {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, line: String, record: Json) {
+        match self.format {
+            Format::Text => println!("{}", line),
+            Format::Json => self.records.push(record),
+        }
+    }
+
+    fn finish(self) {
+        if self.format == Format::Json {
+            println!("{}", Json::Array(self.records));
+        }
+    }
+}
+
+/// A JSON record for one part, starting with the day, part and input it was run against.
+fn record(solution: &Solution, path: &str, fields: Vec<(&str, Json)>) -> Json {
+    let mut all = vec![
+        ("day", Json::from(solution.day)),
+        ("part", Json::from(solution.part)),
+        ("input", Json::from(path)),
+    ];
+    all.extend(fields);
+    Json::object(all)
+}
+
+fn run_record(solution: &Solution, path: &str, run: &Run) -> Json {
+    record(
+        solution,
+        path,
+        vec![
+            ("answer", Json::from(&run.answer)),
+            (
+                "timings",
+                Json::object(vec![
+                    ("parse_ns", Json::from(run.parse)),
+                    ("solve_ns", Json::from(run.solve)),
+                ]),
+            ),
+        ],
+    )
+}
+
+fn error_record(solution: &Solution, path: &str, message: &str) -> Json {
+    record(solution, path, vec![("error", Json::from(message))])
+}
+
+fn stats_json(stats: &Stats) -> Json {
+    Json::object(vec![
+        ("min_ns", Json::from(stats.min)),
+        ("median_ns", Json::from(stats.median)),
+        ("max_ns", Json::from(stats.max)),
+    ])
+}
+
+/// The input given on the command line, or the solution's own input if there wasn't one.
+fn input_path(options: &Options, solution: &Solution) -> String {
+    options
+        .flag("--input")
+        .map_or_else(|| solution.default_input(), String::from)
+}
+
+/// The rule overrides for a solution that takes them: those in the file given on the command
+/// line, or else the solution's own.
+fn load_overrides(options: &Options, solution: &Solution) -> Result<Option<Overrides>, String> {
+    let path = match (solution.default_overrides(), options.flag("--overrides")) {
+        (None, _) => return Ok(None),
+        (Some(_), Some(path)) => path.to_string(),
+        (Some(path), None) => path,
+    };
+    let text = read_input(&path)?;
+    Symbol::parse_overrides(&text)
+        .map(Some)
+        .map_err(|e| format!("{}: {}", path, e.render(&text)))
+}
+
+/// Solve a part, rendering any parse error against the input it came from.
+fn solve(
+    solution: &Solution,
+    path: &str,
+    text: &str,
+    overrides: Option<&Overrides>,
+) -> Result<Answer, String> {
+    solution
+        .solve(text, overrides)
+        .map_err(|e| format!("{}: {}", path, e.render(text)))
+}
+
+/// Solve a part once, timing it, and rendering any parse error against the input it came from.
+fn run_part(
+    solution: &Solution,
+    path: &str,
+    text: &str,
+    overrides: Option<&Overrides>,
+) -> Result<Run, String> {
+    bench::run(solution, text, overrides).map_err(|e| format!("{}: {}", path, e.render(text)))
+}
+
+fn format_answer(solution: &Solution, answer: &str) -> String {
+    format!("day {:02} part {}: {}", solution.day, solution.part, answer)
+}
+
+fn parse_number(value: &str, name: &str) -> Result<u8, Error> {
+    value
+        .parse::<u8>()
+        .map_err(|_| Error::Usage(format!("{} must be a number, got '{}'", name, value)))
+}
+
+/// Read an input file, or stdin when the path is `-`.
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("unable to read stdin: {}", e))?;
+        Ok(text)
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("unable to read {}: {}", path, e))
+    }
+}
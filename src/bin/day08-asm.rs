@@ -0,0 +1,53 @@
+use advent_2020::day08::asm;
+use advent_2020::day08::{Instruction, Isa};
+use std::process;
+
+const USAGE: &str = "\
+usage: day08-asm [--extended] assemble <source> <output>
+       day08-asm [--extended] disassemble <file>
+
+assemble     write the bytecode for an assembly program (labels and comments allowed)
+disassemble  print a bytecode or assembly program as labelled assembly
+
+--extended   allow the extended instruction set";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn read(path: &str) -> Vec<u8> {
+    std::fs::read(path).unwrap_or_else(|e| fail(&format!("Unable to open file {}: {}", path, e)))
+}
+
+/// Read a program from assembly or, if it starts with the bytecode header, bytecode.
+fn load(path: &str, isa: Isa) -> Vec<Instruction> {
+    let bytes = read(path);
+    if bytes.starts_with(asm::MAGIC) {
+        return asm::decode_with(&bytes, isa).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    }
+    let source = String::from_utf8(bytes)
+        .unwrap_or_else(|_| fail(&format!("{}: neither bytecode nor assembly", path)));
+    asm::assemble_with(&source, isa)
+        .unwrap_or_else(|e| fail(&format!("{}: {}", path, e.render(&source))))
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<String>>();
+    let isa = match args.iter().position(|arg| arg == "--extended") {
+        Some(i) => {
+            args.remove(i);
+            Isa::Extended
+        }
+        None => Isa::Strict,
+    };
+    match args.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        ["assemble", source, output] => {
+            let program = load(source, isa);
+            std::fs::write(output, asm::encode(&program))
+                .unwrap_or_else(|e| fail(&format!("Unable to write {}: {}", output, e)));
+        }
+        ["disassemble", path] => print!("{}", asm::disassemble(&load(path, isa))),
+        _ => fail(USAGE),
+    }
+}
@@ -0,0 +1,14 @@
+use advent_2020::day08;
+use advent_2020::day08::cfg::Cfg;
+use advent_2020::parse;
+use advent_2020::trace;
+
+/// Print the control-flow graph of a day 08 program as Graphviz DOT, e.g.
+/// `cargo run --bin day08-cfg | dot -Tsvg > day08.svg`.  Pass `--extended` to allow the
+/// extended instruction set.
+fn main() {
+    trace::init_from_args();
+    let day08::Args { path, isa } = day08::args("day08-cfg");
+    let program = parse::load(&path, |source| day08::asm::assemble_with(source, isa));
+    print!("{}", Cfg::new(&program).to_dot());
+}
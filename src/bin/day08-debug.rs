@@ -0,0 +1,35 @@
+use advent_2020::day08;
+use advent_2020::day08::debugger::{Command, Debugger};
+use advent_2020::parse;
+use advent_2020::trace;
+use std::io::{self, BufRead, Write};
+
+fn main() {
+    trace::init_from_args();
+    let day08::Args { path, isa } = day08::args("day08-debug");
+    let mut debugger = Debugger::new(parse::load(&path, |input| day08::parse_with(input, isa)));
+    println!(
+        "loaded {} instructions from {}; 'help' lists commands",
+        debugger.vm().program().len(),
+        path
+    );
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("(day08) ");
+        io::stdout().flush().expect("Unable to write prompt");
+        let line = match lines.next() {
+            Some(line) => line.expect("Unable to read command"),
+            None => break,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        match Command::parse_with(&line, isa) {
+            Ok(Command::Quit) => break,
+            Ok(command) => println!("{}", debugger.execute(command)),
+            Err(message) => println!("{}", message),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use advent_2020::day08::{self, asm, profile};
+use advent_2020::parse;
+use advent_2020::trace;
+
+/// Run a day 08 program and print its listing annotated with how often each instruction ran.
+fn main() {
+    trace::init_from_args();
+    let day08::Args { path, isa } = day08::args("day08-profile");
+    let program = parse::load(&path, |input| asm::assemble_with(input, isa));
+    let profile = profile::profile(program.clone());
+    println!("{}", profile::listing(&program, &profile));
+}
@@ -0,0 +1,41 @@
+use advent_2020::day18::calc::{Calculator, Command, Mode};
+use advent_2020::trace;
+use std::io::{self, BufRead, Write};
+
+fn main() {
+    trace::init_from_args();
+    let mode = match std::env::args()
+        .skip(1)
+        .find(|arg| trace::parse_flag(arg).is_none())
+    {
+        Some(name) => Mode::from_name(&name).unwrap_or_else(|| {
+            eprintln!(
+                "No mode called '{}'; try equal, addition-first or standard",
+                name
+            );
+            std::process::exit(1);
+        }),
+        None => Mode::Equal,
+    };
+    let mut calculator = Calculator::new(mode);
+    println!("mode: {}; ':help' lists commands", calculator.mode().name());
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("(day18 {}) ", calculator.mode().name());
+        io::stdout().flush().expect("Unable to write prompt");
+        let line = match lines.next() {
+            Some(line) => line.expect("Unable to read expression"),
+            None => break,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        match Command::parse(&line) {
+            Ok(Command::Quit) => break,
+            Ok(command) => println!("{}", calculator.execute(command)),
+            Err(message) => println!("{}", message),
+        }
+    }
+}
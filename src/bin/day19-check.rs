@@ -0,0 +1,22 @@
+use advent_2020::day19;
+use advent_2020::day19::analysis;
+use advent_2020::trace;
+
+const USAGE: &str = "usage: day19-check [--input <file>] [--overrides <file>] [-v | -vv]";
+
+/// Check the day 19 grammar for mistakes and print how long a message each rule matches, after
+/// applying the overrides in the file given with `--overrides`, if any.  Exits with 1 if anything
+/// is wrong.
+fn main() {
+    trace::init_from_args();
+    let args = day19::args(USAGE);
+    if let Some(arg) = args.rest.first() {
+        eprintln!("error: unexpected argument '{}'\n\n{}", arg, USAGE);
+        std::process::exit(1);
+    }
+    let report = analysis::check(&day19::load_grammar(&args), 0);
+    print!("{}", report);
+    if !report.is_clean() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,55 @@
+use advent_2020::day19;
+use advent_2020::day19::generate::{self, Generator};
+use advent_2020::trace;
+use std::process;
+
+const USAGE: &str = "\
+usage: day19-generate [--input <file>] [--overrides <file>] all <max-length>
+       day19-generate [--input <file>] [--overrides <file>] sample <count> [seed]
+       day19-generate [--input <file>] [--overrides <file>] near-miss <count> [seed]
+
+all        print every message rule 0 matches, up to the given length
+sample     print random messages that rule 0 matches
+near-miss  print random messages, one edit away from valid, that rule 0 doesn't match
+
+--input      read the grammar from the file rather than src/bin/day19.txt
+--overrides  replace rules of the day 19 grammar with those in the file first";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn number<T: std::str::FromStr>(arg: &str) -> T {
+    arg.parse()
+        .unwrap_or_else(|_| fail(&format!("expected a number, not '{}'", arg)))
+}
+
+fn main() {
+    trace::init_from_args();
+    let args = day19::args(USAGE);
+    let grammar = day19::load_grammar(&args);
+
+    let messages = match args.rest.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        ["all", max_len] => generate::enumerate(&grammar, 0, number(max_len))
+            .into_iter()
+            .collect(),
+        [kind @ ("sample" | "near-miss"), count, ref seed @ ..] if seed.len() <= 1 => {
+            let mut generator = Generator::new(&grammar, seed.first().map_or(0, |s| number(s)));
+            (0..number::<usize>(count))
+                .map(|_| {
+                    if kind == "sample" {
+                        generator.sample(0)
+                    } else {
+                        generator.near_miss(0)
+                    }
+                    .unwrap_or_else(|| fail("rule 0 doesn't match any messages"))
+                })
+                .collect::<Vec<String>>()
+        }
+        _ => fail(USAGE),
+    };
+    for message in messages {
+        println!("{}", message);
+    }
+}
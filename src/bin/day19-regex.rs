@@ -0,0 +1,23 @@
+use advent_2020::day19;
+use advent_2020::day19::compile;
+use advent_2020::trace;
+
+const USAGE: &str = "usage: day19-regex [--input <file>] [--overrides <file>] [-v | -vv]";
+
+/// Print the regex that rule 0 of the day 19 grammar compiles to, after applying the overrides in
+/// the file given with `--overrides`, if any.
+fn main() {
+    trace::init_from_args();
+    let args = day19::args(USAGE);
+    if let Some(arg) = args.rest.first() {
+        eprintln!("error: unexpected argument '{}'\n\n{}", arg, USAGE);
+        std::process::exit(1);
+    }
+    match compile::pattern(&day19::load_grammar(&args), 0) {
+        Ok(pattern) => println!("{}", pattern),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::parse::{self, ParseError};
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| parse::number(line, line).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<u32>, ParseError>>()
+}
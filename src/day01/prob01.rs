@@ -0,0 +1,30 @@
+pub fn find(mut charges: Vec<u32>, amount: u32) -> Option<u32> {
+    charges.sort();
+    let mid = match charges.binary_search(&(amount / 2)) {
+        Ok(i) => i,
+        Err(i) => i,
+    };
+
+    let mut out: Option<u32> = None;
+    for i in 0..mid {
+        for j in mid..charges.len() {
+            if charges[i] + charges[j] == amount {
+                out = Some(charges[i] * charges[j]);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(
+            find(vec![1721, 979, 366, 299, 675, 1456], 2020),
+            Some(514579)
+        )
+    }
+}
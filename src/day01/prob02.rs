@@ -0,0 +1,26 @@
+pub fn find(charges: Vec<u32>, amount: u32) -> Option<u32> {
+    let mut out: Option<u32> = None;
+    for i in 0..charges.len() {
+        for j in (i + 1)..charges.len() {
+            for k in (j + 1)..charges.len() {
+                if charges[i] + charges[j] + charges[k] == amount {
+                    out = Some(charges[i] * charges[j] * charges[k]);
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(
+            find(vec![1721, 979, 366, 299, 675, 1456], 2020),
+            Some(241861950)
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use crate::parse::{self, ParseError};
+use regex::Regex;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<Password>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| Password::from_line(line.to_string()).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Password>, ParseError>>()
+}
+
+/// A password alongside the policy it was created under.  How the two policy numbers are
+/// interpreted depends on the problem being solved.
+#[derive(Debug, PartialEq)]
+pub struct Password {
+    pub first: usize,
+    pub second: usize,
+    pub char: char,
+    pub password: String,
+}
+
+impl Password {
+    pub fn new(first: usize, second: usize, char: char, password: String) -> Password {
+        Password {
+            first,
+            second,
+            char,
+            password,
+        }
+    }
+
+    pub fn from_line(line: String) -> Result<Password, ParseError> {
+        let pattern = Regex::new(r"^([0-9]+)-([0-9]+) (.): (.+)$").expect("Invalid regex");
+        let captures = pattern.captures(line.as_str()).ok_or_else(|| {
+            ParseError::within(
+                &line,
+                &line,
+                "expected a policy and password like '1-3 a: abcde'",
+            )
+        })?;
+
+        let first = parse::number(&line, &captures[1])?;
+        let second = parse::number(&line, &captures[2])?;
+        let char = captures[3]
+            .chars()
+            .next()
+            .expect("Regex matched an empty char");
+        let password = captures[4].to_string();
+
+        Ok(Password::new(first, second, char, password))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_line() {
+        assert_eq!(
+            Password::from_line("1-3 a: abcde".to_string()),
+            Ok(Password::new(1, 3, 'a', "abcde".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_line_doubledigit() {
+        assert_eq!(
+            Password::from_line("11-33 a: abcde".to_string()),
+            Ok(Password::new(11, 33, 'a', "abcde".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_line_missing_colon() {
+        assert_eq!(
+            Password::from_line("1-3 a abcde".to_string()),
+            Err(ParseError::new(
+                1,
+                1,
+                "1-3 a abcde",
+                "expected a policy and password like '1-3 a: abcde'"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_from_line_number_too_large() {
+        assert_eq!(
+            Password::from_line("1-99999999999999999999 a: abcde".to_string()),
+            Err(ParseError::new(
+                1,
+                3,
+                "99999999999999999999",
+                "expected a number"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_error_line() {
+        assert_eq!(
+            parse("1-3 a: abcde\n\n1-3 b cdefg\n").map_err(|e| (e.line, e.column)),
+            Err((3, 1))
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::day02::Password;
+
+/// The policy numbers give the fewest and most times the character may appear.
+pub fn is_valid(password: &Password) -> bool {
+    let count = password
+        .password
+        .chars()
+        .filter(|c| *c == password.char)
+        .count();
+    count <= password.second && count >= password.first
+}
+
+pub fn count_valid(passwords: &[Password]) -> usize {
+    passwords.iter().filter(|p| is_valid(p)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_yes1() {
+        assert!(is_valid(&Password::new(1, 3, 'a', "abcde".to_string())))
+    }
+
+    #[test]
+    fn test_is_valid_yes2() {
+        assert!(is_valid(&Password::new(2, 9, 'c', "ccccccccc".to_string())))
+    }
+
+    #[test]
+    fn test_is_valid_no() {
+        assert!(!is_valid(&Password::new(1, 3, 'b', "cdefg".to_string())))
+    }
+}
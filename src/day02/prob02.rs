@@ -0,0 +1,38 @@
+use crate::day02::Password;
+
+/// The policy numbers give two 1-indexed positions, exactly one of which must hold the character.
+pub fn is_valid(password: &Password) -> bool {
+    let chars = password.password.chars().collect::<Vec<char>>();
+    let has_first = chars[password.first - 1] == password.char;
+    let has_second = chars[password.second - 1] == password.char;
+    (has_first || has_second) && !(has_first && has_second)
+}
+
+pub fn count_valid(passwords: &[Password]) -> usize {
+    passwords.iter().filter(|p| is_valid(p)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_yes() {
+        assert!(is_valid(&Password::new(1, 3, 'a', "abcde".to_string())))
+    }
+
+    #[test]
+    fn test_is_valid_no1() {
+        assert!(!is_valid(&Password::new(1, 3, 'b', "cdefg".to_string())))
+    }
+
+    #[test]
+    fn test_is_valid_no2() {
+        assert!(!is_valid(&Password::new(
+            2,
+            9,
+            'c',
+            "ccccccccc".to_string()
+        )))
+    }
+}
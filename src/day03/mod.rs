@@ -0,0 +1,183 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use std::rc::Rc;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input)
+}
+
+pub fn traverse_hill(hill: Rc<Grid<bool>>, right: usize, down: usize) -> usize {
+    let mut point = Point::initial(hill);
+    let mut end_loop = false;
+    let mut num_trees: usize = 0;
+    while !end_loop {
+        match point.traverse(down, right) {
+            Some(new_point) => {
+                if new_point.is_tree {
+                    num_trees += 1;
+                }
+                point = new_point;
+            }
+            None => end_loop = true,
+        }
+    }
+    num_trees
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub is_tree: bool,
+    hill: Rc<Grid<bool>>,
+}
+
+impl Point {
+    pub fn initial(hill: Rc<Grid<bool>>) -> Point {
+        Point::new(0, 0, false, hill)
+    }
+
+    pub fn new(x: usize, y: usize, is_tree: bool, hill: Rc<Grid<bool>>) -> Point {
+        Point {
+            x,
+            y,
+            is_tree,
+            hill,
+        }
+    }
+
+    pub fn traverse(&self, down: usize, right: usize) -> Option<Point> {
+        let new_y = self.y + down;
+        if new_y >= self.hill.height() {
+            None
+        } else {
+            let new_x = self.x + right;
+
+            let temp_x = new_x % self.hill.width();
+            let new_is_tree = self.hill[(new_y, temp_x)];
+
+            Some(Point::new(new_x, new_y, new_is_tree, self.hill.clone()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("..##.......\n"),
+            Ok(Grid::from_rows(vec![vec![
+                false, false, true, true, false, false, false, false, false, false, false
+            ]]))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_char() {
+        assert_eq!(
+            parse("..#x."),
+            Err(ParseError::new(1, 4, "x", "expected '.' or '#'"))
+        )
+    }
+
+    #[test]
+    fn test_traverse_normal() {
+        let hill = Rc::new(Grid::from_rows(vec![
+            vec![false, false, false],
+            vec![false, true, false],
+        ]));
+        assert_eq!(
+            Point::initial(hill.clone()).traverse(1, 1),
+            Some(Point::new(1, 1, true, hill.clone()))
+        )
+    }
+
+    #[test]
+    fn test_traverse_go_oob() {
+        let hill = Rc::new(Grid::from_rows(vec![
+            vec![false, false, false],
+            vec![true, false, false],
+        ]));
+        assert_eq!(
+            Point::initial(hill.clone()).traverse(1, 3),
+            Some(Point::new(3, 1, true, hill.clone()))
+        )
+    }
+
+    #[test]
+    fn test_traverse_remain_oob() {
+        let hill = Rc::new(Grid::from_rows(vec![
+            vec![false, false, false],
+            vec![false, false, false],
+            vec![true, false, false],
+        ]));
+        assert_eq!(
+            Point::initial(hill.clone())
+                .traverse(1, 3)
+                .unwrap()
+                .traverse(1, 3),
+            Some(Point::new(6, 2, true, hill.clone()))
+        )
+    }
+
+    #[test]
+    fn test_traverse_reach_end() {
+        let hill = Rc::new(Grid::from_rows(vec![
+            vec![false, false, false],
+            vec![true, false, false],
+        ]));
+        assert_eq!(
+            Point::initial(hill.clone())
+                .traverse(1, 3)
+                .unwrap()
+                .traverse(1, 3),
+            None
+        )
+    }
+
+    #[test]
+    fn test_traverse_hill() {
+        let hill = Rc::new(Grid::from_rows(vec![
+            vec![
+                false, false, true, true, false, false, false, false, false, false, false,
+            ],
+            vec![
+                true, false, false, false, true, false, false, false, true, false, false,
+            ],
+            vec![
+                false, true, false, false, false, false, true, false, false, true, false,
+            ],
+            vec![
+                false, false, true, false, true, false, false, false, true, false, true,
+            ],
+            vec![
+                false, true, false, false, false, true, true, false, false, true, false,
+            ],
+            vec![
+                false, false, true, false, true, true, false, false, false, false, false,
+            ],
+            vec![
+                false, true, false, true, false, true, false, false, false, false, true,
+            ],
+            vec![
+                false, true, false, false, false, false, false, false, false, false, true,
+            ],
+            vec![
+                true, false, true, true, false, false, false, true, false, false, false,
+            ],
+            vec![
+                true, false, false, false, true, true, false, false, false, false, true,
+            ],
+            vec![
+                false, true, false, false, true, false, false, false, true, false, true,
+            ],
+        ]));
+        assert_eq!(traverse_hill(hill, 3, 1), 7)
+    }
+}
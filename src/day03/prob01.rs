@@ -0,0 +1,7 @@
+use crate::day03::traverse_hill;
+use crate::grid::Grid;
+use std::rc::Rc;
+
+pub fn count_trees(hill: Rc<Grid<bool>>) -> usize {
+    traverse_hill(hill, 3, 1)
+}
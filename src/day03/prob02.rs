@@ -0,0 +1,11 @@
+use crate::day03::traverse_hill;
+use crate::grid::Grid;
+use std::rc::Rc;
+
+pub fn multiply_slopes(hill: Rc<Grid<bool>>) -> usize {
+    traverse_hill(hill.clone(), 1, 1)
+        * traverse_hill(hill.clone(), 3, 1)
+        * traverse_hill(hill.clone(), 5, 1)
+        * traverse_hill(hill.clone(), 7, 1)
+        * traverse_hill(hill, 1, 2)
+}
@@ -0,0 +1,436 @@
+use crate::parse::ParseError;
+use std::collections::HashMap;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<Passport>, ParseError> {
+    Ok(ParseResult::merge(
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, val)| ParseResult::from_line(val.to_string()).map_err(|e| e.on_line(i + 1)))
+            .collect::<Result<Vec<ParseResult>, ParseError>>()?,
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseResult {
+    Line(Passport),
+    Divider,
+}
+
+impl ParseResult {
+    pub fn from_line(line: String) -> Result<ParseResult, ParseError> {
+        if line.is_empty() {
+            Ok(ParseResult::Divider)
+        } else {
+            Passport::from_line(line).map(ParseResult::Line)
+        }
+    }
+
+    /// Merge the lines between dividers into passports.  Runs of dividers, and dividers at the
+    /// start, don't make empty passports.
+    pub fn merge(lines: Vec<ParseResult>) -> Vec<Passport> {
+        let mut out: Vec<Passport> = Vec::new();
+
+        let mut partials: Vec<Passport> = Vec::new();
+        for line in lines {
+            match line {
+                ParseResult::Divider if partials.is_empty() => {}
+                ParseResult::Divider => {
+                    out.push(Passport::merge(partials));
+                    partials = Vec::new();
+                }
+                ParseResult::Line(passport) => partials.push(passport),
+            }
+        }
+        if !partials.is_empty() {
+            out.push(Passport::merge(partials));
+        }
+
+        out
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Passport {
+    pub birth_year: Option<String>,
+    pub issue_year: Option<String>,
+    pub expiration_year: Option<String>,
+    pub height: Option<String>,
+    pub hair_color: Option<String>,
+    pub eye_color: Option<String>,
+    pub passport_id: Option<String>,
+    pub country_id: Option<String>,
+}
+
+impl Passport {
+    #[allow(clippy::too_many_arguments, reason = "one argument per passport field")]
+    pub fn new(
+        birth_year: Option<String>,
+        issue_year: Option<String>,
+        expiration_year: Option<String>,
+        height: Option<String>,
+        hair_color: Option<String>,
+        eye_color: Option<String>,
+        passport_id: Option<String>,
+        country_id: Option<String>,
+    ) -> Passport {
+        Passport {
+            birth_year,
+            issue_year,
+            expiration_year,
+            height,
+            hair_color,
+            eye_color,
+            passport_id,
+            country_id,
+        }
+    }
+
+    fn parse_field_str(entries: &HashMap<&str, &str>, key: &str) -> Option<String> {
+        entries.get(key).map(|v| v.to_string())
+    }
+
+    pub fn from_line(line: String) -> Result<Passport, ParseError> {
+        let entries = line
+            .split(' ')
+            .map(|field| {
+                let mut pieces = field.splitn(2, ':');
+                match (pieces.next(), pieces.next()) {
+                    (Some(key), Some(value)) => Ok((key, value)),
+                    _ => Err(ParseError::within(
+                        &line,
+                        field,
+                        "expected a field like 'key:value'",
+                    )),
+                }
+            })
+            .collect::<Result<HashMap<&str, &str>, ParseError>>()?;
+
+        Ok(Passport::new(
+            Passport::parse_field_str(&entries, "byr"),
+            Passport::parse_field_str(&entries, "iyr"),
+            Passport::parse_field_str(&entries, "eyr"),
+            Passport::parse_field_str(&entries, "hgt"),
+            Passport::parse_field_str(&entries, "hcl"),
+            Passport::parse_field_str(&entries, "ecl"),
+            Passport::parse_field_str(&entries, "pid"),
+            Passport::parse_field_str(&entries, "cid"),
+        ))
+    }
+
+    pub fn merge(partials: Vec<Passport>) -> Passport {
+        let mut out = partials[0].clone();
+        for partial in partials.iter().skip(1) {
+            if out.birth_year.is_none() {
+                out.birth_year = partial.birth_year.clone();
+            }
+            if out.issue_year.is_none() {
+                out.issue_year = partial.issue_year.clone();
+            }
+            if out.expiration_year.is_none() {
+                out.expiration_year = partial.expiration_year.clone();
+            }
+            if out.height.is_none() {
+                out.height = partial.height.clone();
+            }
+            if out.hair_color.is_none() {
+                out.hair_color = partial.hair_color.clone();
+            }
+            if out.eye_color.is_none() {
+                out.eye_color = partial.eye_color.clone();
+            }
+            if out.passport_id.is_none() {
+                out.passport_id = partial.passport_id.clone();
+            }
+            if out.country_id.is_none() {
+                out.country_id = partial.country_id.clone();
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_passport_from_line_single() {
+        assert_eq!(
+            Passport::from_line("byr:1937".to_string()),
+            Ok(Passport::new(
+                Some("1937".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn test_passport_from_line_multiple() {
+        assert_eq!(
+            Passport::from_line("byr:1937 ecl:gry pid:860033327".to_string()),
+            Ok(Passport::new(
+                Some("1937".to_string()),
+                None,
+                None,
+                None,
+                None,
+                Some("gry".to_string()),
+                Some("860033327".to_string()),
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn test_passport_from_line_missing_value() {
+        assert_eq!(
+            Passport::from_line("byr:1937 ecl pid:860033327".to_string()),
+            Err(ParseError::new(
+                1,
+                10,
+                "ecl",
+                "expected a field like 'key:value'"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_passport_merge() {
+        assert_eq!(
+            Passport::merge(vec![
+                Passport::new(
+                    Some("1937".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                ),
+                Passport::new(
+                    None,
+                    Some("1990".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                ),
+                Passport::new(
+                    None,
+                    None,
+                    Some("2000".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )
+            ]),
+            Passport::new(
+                Some("1937".to_string()),
+                Some("1990".to_string()),
+                Some("2000".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None
+            )
+        )
+    }
+
+    #[test]
+    fn test_parseresult_from_line_empty() {
+        assert_eq!(
+            ParseResult::from_line("".to_string()),
+            Ok(ParseResult::Divider)
+        )
+    }
+
+    #[test]
+    fn test_parseresult_from_line_full() {
+        assert_eq!(
+            ParseResult::from_line("byr:1937".to_string()),
+            Ok(ParseResult::Line(Passport::new(
+                Some("1937".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            )))
+        )
+    }
+
+    #[test]
+    fn test_parse_extra_blank_lines() {
+        assert_eq!(
+            parse("\nbyr:1937\n\n\niyr:1990\n\n").map(|passports| passports.len()),
+            Ok(2)
+        )
+    }
+
+    #[test]
+    fn test_parseresult_merge_single_term_divider() {
+        assert_eq!(
+            ParseResult::merge(vec![
+                ParseResult::Line(Passport::new(
+                    Some("1937".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Line(Passport::new(
+                    None,
+                    Some("1990".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Divider
+            ]),
+            vec![Passport::new(
+                Some("1937".to_string()),
+                Some("1990".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            )]
+        )
+    }
+
+    #[test]
+    fn test_parseresult_merge_single_no_term_divider() {
+        assert_eq!(
+            ParseResult::merge(vec![
+                ParseResult::Line(Passport::new(
+                    Some("1937".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Line(Passport::new(
+                    None,
+                    Some("1990".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+            ]),
+            vec![Passport::new(
+                Some("1937".to_string()),
+                Some("1990".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None
+            )]
+        )
+    }
+
+    #[test]
+    fn test_parseresult_merge_multiple() {
+        assert_eq!(
+            ParseResult::merge(vec![
+                ParseResult::Line(Passport::new(
+                    Some("1937".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Line(Passport::new(
+                    None,
+                    Some("1990".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Divider,
+                ParseResult::Line(Passport::new(
+                    None,
+                    Some("1990".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Line(Passport::new(
+                    None,
+                    None,
+                    Some("2000".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )),
+                ParseResult::Divider
+            ]),
+            vec![
+                Passport::new(
+                    Some("1937".to_string()),
+                    Some("1990".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                ),
+                Passport::new(
+                    None,
+                    Some("1990".to_string()),
+                    Some("2000".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )
+            ]
+        )
+    }
+}
@@ -0,0 +1,76 @@
+use crate::day04::Passport;
+
+pub fn is_valid(passport: &Passport) -> bool {
+    passport.birth_year.is_some()
+        && passport.issue_year.is_some()
+        && passport.expiration_year.is_some()
+        && passport.height.is_some()
+        && passport.hair_color.is_some()
+        && passport.eye_color.is_some()
+        && passport.passport_id.is_some()
+}
+
+pub fn count_valid(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| is_valid(p)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_passport_is_valid1() {
+        assert!(is_valid(&Passport::new(
+            Some("1937".to_string()),
+            Some("2017".to_string()),
+            Some("2020".to_string()),
+            Some("183cm".to_string()),
+            Some("#fffffd".to_string()),
+            Some("gry".to_string()),
+            Some("860033327".to_string()),
+            Some("147".to_string())
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid2() {
+        assert!(!is_valid(&Passport::new(
+            Some("1929".to_string()),
+            Some("2013".to_string()),
+            Some("2023".to_string()),
+            None,
+            Some("#cfa07d".to_string()),
+            Some("amb".to_string()),
+            Some("028048884".to_string()),
+            Some("350".to_string())
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid3() {
+        assert!(is_valid(&Passport::new(
+            Some("1931".to_string()),
+            Some("2013".to_string()),
+            Some("2024".to_string()),
+            Some("179cm".to_string()),
+            Some("#ae17e1".to_string()),
+            Some("brn".to_string()),
+            Some("760753108".to_string()),
+            None
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid4() {
+        assert!(!is_valid(&Passport::new(
+            None,
+            Some("2011".to_string()),
+            Some("2025".to_string()),
+            Some("59in".to_string()),
+            Some("#cfa07d".to_string()),
+            Some("brn".to_string()),
+            Some("166559648".to_string()),
+            None
+        )))
+    }
+}
@@ -0,0 +1,296 @@
+use crate::day04::Passport;
+
+fn is_valid_year(value: &Option<String>, min: u16, max: u16) -> bool {
+    match value {
+        Some(v) => match v.parse::<u16>() {
+            Ok(year) => min <= year && year <= max,
+            Err(_) => false,
+        },
+        None => false,
+    }
+}
+
+fn substring(value: &str, start: usize, end: usize) -> String {
+    value
+        .chars()
+        .skip(start)
+        .take(end - start)
+        .collect::<String>()
+}
+
+fn is_valid_height(value: &Option<String>) -> bool {
+    match value {
+        Some(v) => {
+            if v.ends_with("cm") {
+                match substring(v, 0, v.len() - 2).parse::<u16>() {
+                    Ok(cm) => (150..=193).contains(&cm),
+                    Err(_) => false,
+                }
+            } else if v.ends_with("in") {
+                match substring(v, 0, v.len() - 2).parse::<u16>() {
+                    Ok(inches) => (59..=76).contains(&inches),
+                    Err(_) => false,
+                }
+            } else {
+                false
+            }
+        }
+        None => false,
+    }
+}
+
+fn is_valid_hair_color(value: &Option<String>) -> bool {
+    match value {
+        Some(v) if v.len() == 7 && v.starts_with('#') => substring(v, 1, 7)
+            .chars()
+            .all(|c: char| c.is_ascii_digit() || ('a'..='f').contains(&c)),
+        _ => false,
+    }
+}
+
+fn is_valid_eye_color(value: &Option<String>) -> bool {
+    match value {
+        Some(v) => {
+            v == "amb"
+                || v == "blu"
+                || v == "brn"
+                || v == "gry"
+                || v == "grn"
+                || v == "hzl"
+                || v == "oth"
+        }
+        None => false,
+    }
+}
+
+fn is_valid_passport_id(value: &Option<String>) -> bool {
+    match value {
+        Some(v) => v.len() == 9 && v.chars().all(|c: char| c.is_ascii_digit()),
+        None => false,
+    }
+}
+
+fn is_valid_country_id(_value: &Option<String>) -> bool {
+    true
+}
+
+pub fn is_valid(passport: &Passport) -> bool {
+    is_valid_year(&passport.birth_year, 1920, 2002)
+        && is_valid_year(&passport.issue_year, 2010, 2020)
+        && is_valid_year(&passport.expiration_year, 2020, 2030)
+        && is_valid_height(&passport.height)
+        && is_valid_hair_color(&passport.hair_color)
+        && is_valid_eye_color(&passport.eye_color)
+        && is_valid_passport_id(&passport.passport_id)
+        && is_valid_country_id(&passport.country_id)
+}
+
+pub fn count_valid(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| is_valid(p)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_passport_is_valid1() {
+        assert!(is_valid(&Passport::new(
+            Some("1937".to_string()),
+            Some("2017".to_string()),
+            Some("2020".to_string()),
+            Some("183cm".to_string()),
+            Some("#fffffd".to_string()),
+            Some("gry".to_string()),
+            Some("860033327".to_string()),
+            Some("147".to_string())
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid2() {
+        assert!(!is_valid(&Passport::new(
+            Some("1929".to_string()),
+            Some("2013".to_string()),
+            Some("2023".to_string()),
+            None,
+            Some("#cfa07d".to_string()),
+            Some("amb".to_string()),
+            Some("028048884".to_string()),
+            Some("350".to_string())
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid3() {
+        assert!(is_valid(&Passport::new(
+            Some("1931".to_string()),
+            Some("2013".to_string()),
+            Some("2024".to_string()),
+            Some("179cm".to_string()),
+            Some("#ae17e1".to_string()),
+            Some("brn".to_string()),
+            Some("760753108".to_string()),
+            None
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid4() {
+        assert!(!is_valid(&Passport::new(
+            None,
+            Some("2011".to_string()),
+            Some("2025".to_string()),
+            Some("59in".to_string()),
+            Some("#cfa07d".to_string()),
+            Some("brn".to_string()),
+            Some("166559648".to_string()),
+            None
+        )))
+    }
+
+    #[test]
+    fn test_passport_is_valid_year() {
+        assert!(is_valid_year(&Some("1983".to_string()), 1980, 1990))
+    }
+
+    #[test]
+    fn test_passport_is_valid_year_nan() {
+        assert!(!is_valid_year(&Some("abcd".to_string()), 1980, 1990))
+    }
+
+    #[test]
+    fn test_passport_is_valid_year_too_early() {
+        assert!(!is_valid_year(&Some("1975".to_string()), 1980, 1990))
+    }
+
+    #[test]
+    fn test_passport_is_valid_year_too_late() {
+        assert!(!is_valid_year(&Some("1995".to_string()), 1980, 1990))
+    }
+
+    #[test]
+    fn test_passport_is_valid_year_none() {
+        assert!(!is_valid_year(&None, 1980, 1990))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_cm() {
+        assert!(is_valid_height(&Some("170cm".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_cm_nan() {
+        assert!(!is_valid_height(&Some("asdcm".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_cm_too_short() {
+        assert!(!is_valid_height(&Some("140cm".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_cm_too_tall() {
+        assert!(!is_valid_height(&Some("200cm".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_in() {
+        assert!(is_valid_height(&Some("70in".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_in_nan() {
+        assert!(!is_valid_height(&Some("asin".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_in_too_short() {
+        assert!(!is_valid_height(&Some("50in".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_in_too_tall() {
+        assert!(!is_valid_height(&Some("80in".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_bad_units() {
+        assert!(!is_valid_height(&Some("80m".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_height_none() {
+        assert!(!is_valid_height(&None))
+    }
+
+    #[test]
+    fn test_passport_is_valid_hair_color() {
+        assert!(is_valid_hair_color(&Some("#ad45f0".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_hair_color_missing_pound() {
+        assert!(!is_valid_hair_color(&Some("ad45f0".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_hair_color_upper_case() {
+        assert!(!is_valid_hair_color(&Some("#AD45F0".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_hair_color_not_hex() {
+        assert!(!is_valid_hair_color(&Some("#ag45f0".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_hair_color_none() {
+        assert!(!is_valid_hair_color(&None))
+    }
+
+    #[test]
+    fn test_passport_is_valid_eye_color() {
+        assert!(is_valid_eye_color(&Some("amb".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_eye_color_invalid_color() {
+        assert!(!is_valid_eye_color(&Some("foo".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_eye_color_none() {
+        assert!(!is_valid_eye_color(&None))
+    }
+
+    #[test]
+    fn test_passport_is_valid_passport_id() {
+        assert!(is_valid_passport_id(&Some("012345679".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_passport_id_length() {
+        assert!(!is_valid_passport_id(&Some("123".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_passport_id_chars() {
+        assert!(!is_valid_passport_id(&Some("abcdefghi".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_passport_id_none() {
+        assert!(!is_valid_passport_id(&None))
+    }
+
+    #[test]
+    fn test_passport_is_valid_country_id() {
+        assert!(is_valid_country_id(&Some("123".to_string())))
+    }
+
+    #[test]
+    fn test_passport_is_valid_country_id_none() {
+        assert!(is_valid_country_id(&None))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::parse::{self, ParseError};
+use std::ops::Range;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<Seat>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| Seat::from_partition(line.to_string()).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Seat>, ParseError>>()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Seat {
+    pub row: u8,
+    pub col: u8,
+}
+
+impl Seat {
+    pub fn new(row: u8, col: u8) -> Seat {
+        Seat { row, col }
+    }
+
+    /// Binary search over `line[range]`, where `back` keeps the lower half and `forward` the
+    /// upper half.
+    fn search(
+        line: &str,
+        range: Range<usize>,
+        back: char,
+        forward: char,
+    ) -> Result<u8, ParseError> {
+        line.chars()
+            .enumerate()
+            .skip(range.start)
+            .take(range.len())
+            .try_fold(0u8, |acc, (i, char)| {
+                let half = if char == back {
+                    0
+                } else if char == forward {
+                    1
+                } else {
+                    return Err(ParseError::new(
+                        1,
+                        i + 1,
+                        &char.to_string(),
+                        &format!("expected '{}' or '{}'", back, forward),
+                    ));
+                };
+                Ok(acc << 1 | half)
+            })
+    }
+
+    pub fn from_partition(line: String) -> Result<Seat, ParseError> {
+        if line.chars().count() != 10 {
+            return Err(ParseError::within(
+                &line,
+                &line,
+                "expected 7 row and 3 column characters",
+            ));
+        }
+        let row = Seat::search(&line, 0..7, 'F', 'B')?;
+        let col = Seat::search(&line, 7..10, 'L', 'R')?;
+        Ok(Seat::new(row, col))
+    }
+
+    pub fn get_id(&self) -> u16 {
+        (self.row as u16) * 8 + (self.col as u16)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search1() {
+        assert_eq!(Seat::search("FBFBBFF", 0..7, 'F', 'B'), Ok(44))
+    }
+
+    #[test]
+    fn test_search2() {
+        assert_eq!(Seat::search("FBFBBFFRLR", 7..10, 'L', 'R'), Ok(5))
+    }
+
+    #[test]
+    fn test_from_partition1() {
+        assert_eq!(
+            Seat::from_partition("FBFBBFFRLR".to_string()),
+            Ok(Seat::new(44, 5))
+        )
+    }
+
+    #[test]
+    fn test_from_partition2() {
+        assert_eq!(
+            Seat::from_partition("BFFFBBFRRR".to_string()),
+            Ok(Seat::new(70, 7))
+        )
+    }
+
+    #[test]
+    fn test_from_partition3() {
+        assert_eq!(
+            Seat::from_partition("FFFBBBFRRR".to_string()),
+            Ok(Seat::new(14, 7))
+        )
+    }
+
+    #[test]
+    fn test_from_partition4() {
+        assert_eq!(
+            Seat::from_partition("BBFFBBFRLL".to_string()),
+            Ok(Seat::new(102, 4))
+        )
+    }
+
+    #[test]
+    fn test_from_partition_bad_char() {
+        assert_eq!(
+            Seat::from_partition("FBFBBFFRXR".to_string()),
+            Err(ParseError::new(1, 9, "X", "expected 'L' or 'R'"))
+        )
+    }
+
+    #[test]
+    fn test_from_partition_too_short() {
+        assert_eq!(
+            Seat::from_partition("FBFBBFF".to_string()),
+            Err(ParseError::new(
+                1,
+                1,
+                "FBFBBFF",
+                "expected 7 row and 3 column characters"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_get_id() {
+        assert_eq!(Seat::new(44, 5).get_id(), 357)
+    }
+
+    #[test]
+    fn test_get_id2() {
+        assert_eq!(Seat::new(70, 7).get_id(), 567)
+    }
+
+    #[test]
+    fn test_get_id3() {
+        assert_eq!(Seat::new(14, 7).get_id(), 119)
+    }
+
+    #[test]
+    fn test_get_id4() {
+        assert_eq!(Seat::new(102, 4).get_id(), 820)
+    }
+}
@@ -0,0 +1,5 @@
+use crate::day05::Seat;
+
+pub fn max_id(seats: &[Seat]) -> Option<u16> {
+    seats.iter().map(|s| s.get_id()).max()
+}
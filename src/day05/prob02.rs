@@ -0,0 +1,34 @@
+use crate::day05::Seat;
+
+/// The only free seat with taken seats either side of it.
+pub fn find_seat(seats: Vec<Seat>) -> Result<u16, String> {
+    // assign seats into array representing all seats on airplane
+    let max_id = seats
+        .iter()
+        .map(|s| s.get_id())
+        .max()
+        .ok_or_else(|| "no boarding passes".to_string())?;
+    let mut airplane: Vec<Option<Seat>> = vec![None; max_id as usize + 1];
+    for seat in seats {
+        airplane[seat.get_id() as usize] = Some(seat.clone());
+    }
+
+    let missing = airplane
+        .iter()
+        .enumerate()
+        .filter(|(id, seat)| {
+            seat.is_none()
+                && (id > &0usize && airplane[id - 1].is_some())
+                && (id < &((max_id + 1) as usize) && airplane[id + 1].is_some())
+        })
+        .map(|(id, _)| id as u16)
+        .collect::<Vec<u16>>();
+    if missing.len() != 1 {
+        return Err(format!(
+            "{} seats could be mine: {:?}",
+            missing.len(),
+            missing
+        ));
+    }
+    Ok(missing[0])
+}
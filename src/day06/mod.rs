@@ -0,0 +1,193 @@
+use crate::parse::ParseError;
+use std::collections::HashSet;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<CustomsGroup>, ParseError> {
+    Ok(ParseResult::merge(
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| ParseResult::from_raw(line).map_err(|e| e.on_line(i + 1)))
+            .collect::<Result<Vec<ParseResult>, ParseError>>()?,
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CustomsGroup {
+    pub forms: Vec<Customs>,
+}
+
+impl CustomsGroup {
+    pub fn new(forms: Vec<Customs>) -> CustomsGroup {
+        CustomsGroup { forms }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseResult {
+    Form(Customs),
+    Divider,
+}
+
+impl ParseResult {
+    pub fn from_raw(line: &str) -> Result<ParseResult, ParseError> {
+        if line.is_empty() {
+            Ok(ParseResult::Divider)
+        } else {
+            Customs::from_raw(line).map(ParseResult::Form)
+        }
+    }
+
+    pub fn merge(results: Vec<ParseResult>) -> Vec<CustomsGroup> {
+        let mut out: Vec<CustomsGroup> = Vec::new();
+
+        let mut partials: Vec<Customs> = Vec::new();
+        for result in results {
+            match result {
+                ParseResult::Divider => {
+                    out.push(CustomsGroup::new(partials));
+                    partials = Vec::new();
+                }
+                ParseResult::Form(customs) => partials.push(customs),
+            }
+        }
+        if !partials.is_empty() {
+            out.push(CustomsGroup::new(partials));
+        }
+
+        out
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Customs {
+    pub questions: HashSet<char>,
+}
+
+impl Customs {
+    pub fn new(questions: HashSet<char>) -> Customs {
+        Customs { questions }
+    }
+
+    pub fn from_raw(line: &str) -> Result<Customs, ParseError> {
+        let trimmed = line.trim();
+        if let Some((i, c)) = trimmed
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            return Err(ParseError::within(
+                line,
+                &trimmed[i..i + c.len_utf8()],
+                "expected a question letter a-z",
+            ));
+        }
+        Ok(Customs::new(trimmed.chars().collect::<HashSet<char>>()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_customs_from_raw() {
+        assert_eq!(
+            Customs::from_raw("abcx"),
+            Ok(Customs::new(hashset!('a', 'b', 'c', 'x')))
+        )
+    }
+
+    #[test]
+    fn test_customs_from_raw_bad_char() {
+        assert_eq!(
+            Customs::from_raw("ab1x"),
+            Err(ParseError::new(1, 3, "1", "expected a question letter a-z"))
+        )
+    }
+
+    #[test]
+    fn test_parseresult_from_raw_form() {
+        assert_eq!(
+            ParseResult::from_raw("abcx"),
+            Ok(ParseResult::Form(Customs::new(hashset!(
+                'a', 'b', 'c', 'x'
+            ))))
+        )
+    }
+
+    #[test]
+    fn test_parseresult_from_raw_divider() {
+        assert_eq!(ParseResult::from_raw(""), Ok(ParseResult::Divider))
+    }
+
+    #[test]
+    fn test_parseresult_merge_single_term_divider() {
+        assert_eq!(
+            ParseResult::merge(vec![
+                ParseResult::Form(Customs::new(hashset!('a'))),
+                ParseResult::Form(Customs::new(hashset!('b'))),
+                ParseResult::Form(Customs::new(hashset!('c'))),
+                ParseResult::Form(Customs::new(hashset!('x'))),
+                ParseResult::Divider
+            ]),
+            vec![CustomsGroup::new(vec![
+                Customs::new(hashset!('a')),
+                Customs::new(hashset!('b')),
+                Customs::new(hashset!('c')),
+                Customs::new(hashset!('x')),
+            ])]
+        )
+    }
+
+    #[test]
+    fn test_parseresult_merge_single_no_term_divider() {
+        assert_eq!(
+            ParseResult::merge(vec![
+                ParseResult::Form(Customs::new(hashset!('a'))),
+                ParseResult::Form(Customs::new(hashset!('b'))),
+                ParseResult::Form(Customs::new(hashset!('c'))),
+                ParseResult::Form(Customs::new(hashset!('x'))),
+            ]),
+            vec![CustomsGroup::new(vec![
+                Customs::new(hashset!('a')),
+                Customs::new(hashset!('b')),
+                Customs::new(hashset!('c')),
+                Customs::new(hashset!('x')),
+            ])]
+        )
+    }
+
+    #[test]
+    fn test_parseresult_merge_multiple() {
+        assert_eq!(
+            ParseResult::merge(vec![
+                ParseResult::Form(Customs::new(hashset!('a'))),
+                ParseResult::Form(Customs::new(hashset!('b'))),
+                ParseResult::Form(Customs::new(hashset!('c'))),
+                ParseResult::Form(Customs::new(hashset!('x'))),
+                ParseResult::Divider,
+                ParseResult::Form(Customs::new(hashset!('q'))),
+                ParseResult::Form(Customs::new(hashset!('w'))),
+                ParseResult::Form(Customs::new(hashset!('e'))),
+                ParseResult::Form(Customs::new(hashset!('r'))),
+                ParseResult::Divider,
+            ]),
+            vec![
+                CustomsGroup::new(vec![
+                    Customs::new(hashset!('a')),
+                    Customs::new(hashset!('b')),
+                    Customs::new(hashset!('c')),
+                    Customs::new(hashset!('x')),
+                ]),
+                CustomsGroup::new(vec![
+                    Customs::new(hashset!('q')),
+                    Customs::new(hashset!('w')),
+                    Customs::new(hashset!('e')),
+                    Customs::new(hashset!('r')),
+                ])
+            ]
+        )
+    }
+}
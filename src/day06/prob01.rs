@@ -0,0 +1,36 @@
+use crate::day06::{Customs, CustomsGroup};
+use std::collections::HashSet;
+
+/// Merge the forms in a group into the questions that anyone in the group answered.
+pub fn merged(group: &CustomsGroup) -> Customs {
+    let mut out: HashSet<char> = HashSet::new();
+    for form in &group.forms {
+        out.extend(&form.questions);
+    }
+    Customs::new(out)
+}
+
+pub fn sum_counts(groups: &[CustomsGroup]) -> usize {
+    groups
+        .iter()
+        .map(|cg| merged(cg).questions.len())
+        .sum::<usize>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_customsgroup_merge() {
+        assert_eq!(
+            merged(&CustomsGroup::new(vec![
+                Customs::new(hashset!('a')),
+                Customs::new(hashset!('b')),
+                Customs::new(hashset!('c')),
+                Customs::new(hashset!('x')),
+            ])),
+            Customs::new(hashset!['a', 'b', 'c', 'x'])
+        )
+    }
+}
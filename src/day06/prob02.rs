@@ -0,0 +1,35 @@
+use crate::day06::{Customs, CustomsGroup};
+use std::collections::HashSet;
+
+/// Merge the forms in a group into the questions that everyone in the group answered.
+pub fn merged(group: &CustomsGroup) -> Customs {
+    let mut out: HashSet<char> = group.forms[0].questions.clone();
+    for form in group.forms.iter().skip(1) {
+        out = out.intersection(&form.questions).copied().collect();
+    }
+    Customs::new(out)
+}
+
+pub fn sum_counts(groups: &[CustomsGroup]) -> usize {
+    groups
+        .iter()
+        .map(|cg| merged(cg).questions.len())
+        .sum::<usize>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_customsgroup_merge() {
+        assert_eq!(
+            merged(&CustomsGroup::new(vec![
+                Customs::new(hashset!('a', 'b', 'c', 'x')),
+                Customs::new(hashset!('a', 'b', 'c', 'y')),
+                Customs::new(hashset!('a', 'b', 'c', 'z')),
+            ])),
+            Customs::new(hashset!['a', 'b', 'c'])
+        )
+    }
+}
@@ -0,0 +1,113 @@
+use crate::parse::{self, ParseError};
+use std::collections::HashMap;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<HashMap<String, Vec<(u8, String)>>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| parse_line(line).map_err(|e| e.on_line(i)))
+        .collect::<Result<HashMap<String, Vec<(u8, String)>>, ParseError>>()
+}
+
+pub fn parse_line(line: &str) -> Result<(String, Vec<(u8, String)>), ParseError> {
+    let parts = line.split(" bags contain ").collect::<Vec<&str>>();
+    if parts.len() != 2 {
+        return Err(ParseError::within(
+            line,
+            line,
+            "expected a rule like '<color> bags contain <contents>.'",
+        ));
+    }
+    let source_color = parts[0].to_string();
+    let contents = parts[1]
+        .trim_end_matches('.')
+        .split(", ")
+        .filter(|p| *p != "no other bags")
+        .map(|p| {
+            let clean = p
+                .trim_end_matches('s')
+                .trim_end_matches(" bag")
+                .split(' ')
+                .collect::<Vec<&str>>();
+            let count = parse::number::<u8>(line, clean[0])?;
+            if clean.len() < 2 {
+                return Err(ParseError::within(line, p, "expected a count and a color"));
+            }
+            let color = clean[1..clean.len()].join(" ");
+            Ok((count, color))
+        })
+        .collect::<Result<Vec<(u8, String)>, ParseError>>()?;
+    Ok((source_color, contents))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_no_bags() {
+        assert_eq!(
+            parse_line("faded blue bags contain no other bags."),
+            Ok(("faded blue".to_string(), vec![]))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_one_singular_bag() {
+        assert_eq!(
+            parse_line("bright white bags contain 1 shiny gold bag."),
+            Ok((
+                "bright white".to_string(),
+                vec![(1, "shiny gold".to_string())]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_one_plural_bag() {
+        assert_eq!(
+            parse_line("bright white bags contain 2 shiny gold bags."),
+            Ok((
+                "bright white".to_string(),
+                vec![(2, "shiny gold".to_string())]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_multiple_bags() {
+        assert_eq!(
+            parse_line("vibrant plum bags contain 5 faded blue bags, 6 dotted black bags."),
+            Ok((
+                "vibrant plum".to_string(),
+                vec![
+                    (5, "faded blue".to_string()),
+                    (6, "dotted black".to_string())
+                ]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_bad_count() {
+        let line = "vibrant plum bags contain five faded blue bags.";
+        assert_eq!(
+            parse_line(line),
+            Err(ParseError::new(1, 27, "five", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_parse_line_missing_contents() {
+        assert_eq!(
+            parse_line("vibrant plum bags"),
+            Err(ParseError::new(
+                1,
+                1,
+                "vibrant plum bags",
+                "expected a rule like '<color> bags contain <contents>.'"
+            ))
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::{HashMap, HashSet, LinkedList, VecDeque};
+
+pub fn find_shiny_gold(bags: HashMap<String, Vec<(u8, String)>>) -> HashSet<String> {
+    let mut queue: VecDeque<LinkedList<String>> = VecDeque::new();
+    bags.keys().for_each(|k| {
+        let mut ll = LinkedList::new();
+        ll.push_front(k.clone());
+        queue.push_back(ll);
+    });
+
+    let mut out: HashSet<String> = HashSet::new();
+    while !queue.is_empty() {
+        let to_process_chain = queue.pop_front().unwrap();
+        for (_, contained_bag) in bags[to_process_chain.front().unwrap()].iter() {
+            if contained_bag == "shiny gold" {
+                out.insert(to_process_chain.back().unwrap().clone());
+            } else {
+                let mut new_chain = to_process_chain.clone();
+                new_chain.push_front(contained_bag.clone());
+                queue.push_back(new_chain);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_shiny_gold_self() {
+        assert_eq!(
+            find_shiny_gold(hashmap!("shiny gold".to_string() => vec![])),
+            hashset!()
+        )
+    }
+
+    #[test]
+    fn test_find_shiny_gold_one_layer() {
+        assert_eq!(
+            find_shiny_gold(
+                hashmap!("light red".to_string() => vec![(4, "shiny gold".to_string())])
+            ),
+            hashset!("light red".to_string())
+        )
+    }
+
+    #[test]
+    fn test_find_shiny_gold_multiple_layers() {
+        assert_eq!(
+            find_shiny_gold(
+                hashmap!("light red".to_string() => vec![(2, "muted yellow".to_string())], "muted yellow".to_string() => vec![(4, "shiny gold".to_string())])
+            ),
+            hashset!("light red".to_string(), "muted yellow".to_string())
+        )
+    }
+
+    #[test]
+    fn test_find_shiny_gold_complex() {
+        assert_eq!(
+            find_shiny_gold(hashmap!(
+                "light red".to_string() => vec![(1, "bright white".to_string()), (2, "muted yellow".to_string())],
+                "dark orange".to_string() => vec![(3, "bright white".to_string()), (4, "muted yellow".to_string())],
+                "bright white".to_string() => vec![(1, "shiny gold".to_string())],
+                "muted yellow".to_string() => vec![(2, "shiny gold".to_string()), (9, "faded blue".to_string())],
+                "shiny gold".to_string() => vec![(1, "dark olive".to_string()), (2, "vibrant plum".to_string())],
+                "dark olive".to_string() => vec![(3, "faded blue".to_string()), (4, "dotted black".to_string())],
+                "vibrant plum".to_string() => vec![(5, "faded blue".to_string()), (6, "dotted black".to_string())],
+                "faded blue".to_string() => vec![],
+                "dotted black".to_string() => vec![]
+            )),
+            hashset!(
+                "bright white".to_string(),
+                "muted yellow".to_string(),
+                "dark orange".to_string(),
+                "light red".to_string()
+            )
+        )
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+/// How many bags a shiny gold bag holds, if the rules say what it holds all the way down.
+pub fn shiny_gold_contains(bags: HashMap<String, Vec<(u8, String)>>) -> Option<u64> {
+    let mut cache: HashMap<String, u64> = HashMap::new();
+    while cache.len() != bags.len() {
+        let mut additions: HashMap<String, u64> = HashMap::new();
+        for bag in bags.keys().filter(|b| !cache.contains_key(*b)) {
+            if bags[bag].iter().all(|(_, b)| cache.contains_key(b)) {
+                let sum = bags[bag]
+                    .iter()
+                    .map(|(count, b)| (*count as u64) * (cache[b] + 1))
+                    .sum::<u64>();
+                additions.insert(bag.clone(), sum);
+            }
+        }
+        if additions.is_empty() {
+            break;
+        }
+        cache.extend(additions);
+    }
+    cache.get("shiny gold").copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shiny_gold_contains_single_layer_single_child() {
+        assert_eq!(
+            shiny_gold_contains(
+                hashmap!("shiny gold".to_string() => vec![(2, "light blue".to_string())], "light blue".to_string() => vec![])
+            ),
+            Some(2)
+        )
+    }
+
+    #[test]
+    fn test_shiny_gold_contains_single_layer_two_children() {
+        assert_eq!(
+            shiny_gold_contains(
+                hashmap!("shiny gold".to_string() => vec![(2, "light blue".to_string()), (3, "dark red".to_string())], "light blue".to_string() => vec![], "dark red".to_string() => vec![])
+            ),
+            Some(5)
+        )
+    }
+
+    #[test]
+    fn test_shiny_gold_contains_two_layers_single_child() {
+        assert_eq!(
+            shiny_gold_contains(
+                hashmap!("shiny gold".to_string() => vec![(2, "muted yellow".to_string())], "muted yellow".to_string() => vec![(4, "light blue".to_string())], "light blue".to_string() => vec![])
+            ),
+            Some(10)
+        )
+    }
+
+    #[test]
+    fn test_shiny_gold_contains_two_layers_two_children() {
+        assert_eq!(
+            shiny_gold_contains(hashmap!(
+                "shiny gold".to_string() => vec![(2, "muted yellow".to_string()), (3, "dark red".to_string())],
+                "muted yellow".to_string() => vec![(4, "light blue".to_string())],
+                "dark red".to_string() => vec![(5, "bright orange".to_string())],
+                "light blue".to_string() => vec![],
+                "bright orange".to_string() => vec![])),
+            Some(28)
+        )
+    }
+
+    #[test]
+    fn test_shiny_gold_contains_nested() {
+        assert_eq!(
+            shiny_gold_contains(hashmap!(
+                "shiny gold".to_string() => vec![(2, "dark red".to_string())],
+                "dark red".to_string() => vec![(2, "dark orange".to_string())],
+                "dark orange".to_string() => vec![(2, "dark yellow".to_string())],
+                "dark yellow".to_string() => vec![(2, "dark green".to_string())],
+                "dark green".to_string() => vec![(2, "dark blue".to_string())],
+                "dark blue".to_string() => vec![(2, "dark violet".to_string())],
+                "dark violet".to_string() => vec![]
+            )),
+            Some(126)
+        )
+    }
+
+    #[test]
+    fn test_shiny_gold_contains_complex() {
+        assert_eq!(
+            shiny_gold_contains(hashmap!(
+                "light red".to_string() => vec![(1, "bright white".to_string()), (2, "muted yellow".to_string())],
+                "dark orange".to_string() => vec![(3, "bright white".to_string()), (4, "muted yellow".to_string())],
+                "bright white".to_string() => vec![(1, "shiny gold".to_string())],
+                "muted yellow".to_string() => vec![(2, "shiny gold".to_string()), (9, "faded blue".to_string())],
+                "shiny gold".to_string() => vec![(1, "dark olive".to_string()), (2, "vibrant plum".to_string())],
+                "dark olive".to_string() => vec![(3, "faded blue".to_string()), (4, "dotted black".to_string())],
+                "vibrant plum".to_string() => vec![(5, "faded blue".to_string()), (6, "dotted black".to_string())],
+                "faded blue".to_string() => vec![],
+                "dotted black".to_string() => vec![]
+            )),
+            Some(32)
+        )
+    }
+}
@@ -0,0 +1,548 @@
+//! Readable assembly and compact bytecode for handheld programs.
+//!
+//! The assembler accepts the puzzle's own `op ±n` lines, plus:
+//!
+//! - comments, from `#` to the end of the line, and blank lines;
+//! - labels, written `name:` on their own line or before an instruction, which mark the next
+//!   instruction (or the end of the program, after the last one);
+//! - label names in place of the offset of a `nop` or any jump, which become the offset from
+//!   that instruction to the label.
+//!
+//! Only the puzzle's instructions are accepted unless the extended instruction set is asked
+//! for.
+//!
+//! The bytecode is the bytes `HB08`, the number of instructions and then each instruction as an
+//! opcode byte (its index in `Instruction::MNEMONICS`, so 0 for `acc`, 1 for `jmp`, 2 for `nop`
+//! and 3 onwards for the extended set) followed by its argument.  Numbers are
+//! LEB128 varints, with arguments zigzag encoded so small negative ones stay small.  Decoding
+//! only accepts the encoding `encode` writes, so both directions round-trip exactly.
+
+use crate::day08::{Instruction, Isa};
+use crate::parse::{self, ParseError};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+pub const MAGIC: &[u8] = b"HB08";
+
+enum Argument<'a> {
+    Number(i32),
+    Label(&'a str),
+}
+
+struct Line<'a> {
+    number: usize,
+    text: &'a str,
+    op: &'a str,
+    argument: Argument<'a>,
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Assemble a program, resolving labels to relative offsets.
+pub fn assemble(source: &str) -> Result<Vec<Instruction>, ParseError> {
+    assemble_with(source, Isa::Strict)
+}
+
+/// Assemble a program that may use the instructions of `isa`.
+pub fn assemble_with(source: &str, isa: Isa) -> Result<Vec<Instruction>, ParseError> {
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    let mut lines = Vec::new();
+    for (i, text) in parse::lines(source) {
+        let mut code = text.split('#').next().unwrap_or("").trim();
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(ParseError::within(text, label, "expected a label name").on_line(i));
+            }
+            if labels.insert(label, lines.len()).is_some() {
+                return Err(
+                    ParseError::within(text, label, "this label is already defined").on_line(i),
+                );
+            }
+            code = rest.trim();
+        }
+        if code.is_empty() {
+            continue;
+        }
+        let words = code.split_whitespace().collect::<Vec<&str>>();
+        if words.len() != 2 {
+            return Err(ParseError::within(
+                text,
+                code,
+                "expected an instruction like 'acc +1' or 'jmp label'",
+            )
+            .on_line(i));
+        }
+        let op = words[0];
+        match Instruction::isa_of(op) {
+            None => return Err(ParseError::within(text, op, "unknown instruction").on_line(i)),
+            Some(needed) if needed > isa => {
+                return Err(ParseError::within(
+                    text,
+                    op,
+                    "this instruction is only in the extended instruction set",
+                )
+                .on_line(i))
+            }
+            Some(_) => {}
+        }
+        let argument = if is_label(words[1]) {
+            let instruction = Instruction::new(op, 0).expect("every mnemonic has an instruction");
+            if !instruction.is_jump() && instruction != Instruction::Nop(0) {
+                let message = format!("{} takes a number, not a label", op);
+                return Err(ParseError::within(text, words[1], &message).on_line(i));
+            }
+            Argument::Label(words[1])
+        } else {
+            Argument::Number(parse::number(text, words[1]).map_err(|e| e.on_line(i))?)
+        };
+        lines.push(Line {
+            number: i,
+            text,
+            op,
+            argument,
+        });
+    }
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(pc, line)| {
+            let argument = match line.argument {
+                Argument::Number(n) => n,
+                Argument::Label(label) => match labels.get(label) {
+                    Some(target) => *target as i32 - pc as i32,
+                    None => {
+                        return Err(ParseError::within(line.text, label, "unknown label")
+                            .on_line(line.number))
+                    }
+                },
+            };
+            Ok(Instruction::new(line.op, argument).expect("every mnemonic has an instruction"))
+        })
+        .collect()
+}
+
+/// Write a program as assembly, labelling every place a jump can land.  Jumps out of the program
+/// and `nop` arguments are left as numbers.
+pub fn disassemble(program: &[Instruction]) -> String {
+    let targets = program
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| instruction.is_jump())
+        .map(|(pc, instruction)| pc as i64 + instruction.argument() as i64)
+        .filter(|target| (0..=program.len() as i64).contains(target))
+        .map(|target| target as usize)
+        .collect::<BTreeSet<usize>>();
+    let labels = targets
+        .iter()
+        .enumerate()
+        .map(|(n, target)| (*target, format!("L{}", n + 1)))
+        .collect::<HashMap<usize, String>>();
+
+    let mut out = String::new();
+    for pc in 0..=program.len() {
+        if let Some(label) = labels.get(&pc) {
+            out.push_str(&format!("{}:\n", label));
+        }
+        let instruction = match program.get(pc) {
+            Some(instruction) => instruction,
+            None => break,
+        };
+        let target = pc as i64 + instruction.argument() as i64;
+        let label = if instruction.is_jump() && target >= 0 {
+            labels.get(&(target as usize))
+        } else {
+            None
+        };
+        match label {
+            Some(label) => out.push_str(&format!("    {} {}\n", instruction.mnemonic(), label)),
+            None => out.push_str(&format!("    {}\n", instruction)),
+        }
+    }
+    out
+}
+
+/// Why some bytes aren't valid bytecode.  Offsets count from the start of the bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    BadMagic,
+    Truncated {
+        offset: usize,
+    },
+    UnknownOpcode {
+        offset: usize,
+        opcode: u8,
+    },
+    /// An opcode from the extended instruction set, when decoding strictly.
+    ExtendedOpcode {
+        offset: usize,
+        opcode: u8,
+    },
+    /// A number too big for its field, or padded with extra bytes.
+    BadNumber {
+        offset: usize,
+    },
+    TrailingBytes {
+        offset: usize,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "not day 08 bytecode (expected it to start HB08)"),
+            DecodeError::Truncated { offset } => {
+                write!(f, "bytecode ends early at byte {}", offset)
+            }
+            DecodeError::UnknownOpcode { offset, opcode } => {
+                write!(f, "unknown opcode {} at byte {}", opcode, offset)
+            }
+            DecodeError::ExtendedOpcode { offset, opcode } => write!(
+                f,
+                "opcode {} at byte {} is only in the extended instruction set",
+                opcode, offset
+            ),
+            DecodeError::BadNumber { offset } => write!(f, "bad number at byte {}", offset),
+            DecodeError::TrailingBytes { offset } => {
+                write!(f, "unexpected bytes after the program at byte {}", offset)
+            }
+        }
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push((n as u8 & 0x7f) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn zigzag(n: i32) -> u32 {
+    ((n << 1) ^ (n >> 31)) as u32
+}
+
+fn unzigzag(n: u32) -> i32 {
+    (n >> 1) as i32 ^ -((n & 1) as i32)
+}
+
+pub fn encode(program: &[Instruction]) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+    write_varint(&mut out, program.len() as u64);
+    for instruction in program {
+        let opcode = Instruction::MNEMONICS
+            .iter()
+            .position(|(name, _)| *name == instruction.mnemonic())
+            .expect("every instruction has a mnemonic");
+        out.push(opcode as u8);
+        write_varint(&mut out, zigzag(instruction.argument()) as u64);
+    }
+    out
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self.bytes.get(self.offset).ok_or(DecodeError::Truncated {
+            offset: self.offset,
+        })?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    /// A varint of at most `max`, in the fewest bytes possible.
+    fn varint(&mut self, max: u64) -> Result<u64, DecodeError> {
+        let start = self.offset;
+        let mut n: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            if shift > 0 && byte == 0 || shift > 35 {
+                return Err(DecodeError::BadNumber { offset: start });
+            }
+            n |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        if n > max {
+            return Err(DecodeError::BadNumber { offset: start });
+        }
+        Ok(n)
+    }
+}
+
+/// Decode a program using only the puzzle's instructions.
+pub fn decode(bytes: &[u8]) -> Result<Vec<Instruction>, DecodeError> {
+    decode_with(bytes, Isa::Strict)
+}
+
+/// Decode a program that may use the instructions of `isa`.
+pub fn decode_with(bytes: &[u8], isa: Isa) -> Result<Vec<Instruction>, DecodeError> {
+    if !bytes.starts_with(MAGIC) {
+        return Err(DecodeError::BadMagic);
+    }
+    let mut reader = Reader {
+        bytes,
+        offset: MAGIC.len(),
+    };
+    let count = reader.varint(usize::MAX as u64)? as usize;
+    let mut program = Vec::new();
+    for _ in 0..count {
+        let offset = reader.offset;
+        let opcode = reader.byte()?;
+        let argument = unzigzag(reader.varint(u32::MAX as u64)? as u32);
+        match Instruction::MNEMONICS.get(opcode as usize) {
+            Some((name, needed)) if *needed <= isa => program
+                .push(Instruction::new(name, argument).expect("every mnemonic has an instruction")),
+            Some(_) => return Err(DecodeError::ExtendedOpcode { offset, opcode }),
+            None => return Err(DecodeError::UnknownOpcode { offset, opcode }),
+        }
+    }
+    if reader.offset != bytes.len() {
+        return Err(DecodeError::TrailingBytes {
+            offset: reader.offset,
+        });
+    }
+    Ok(program)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Instruction> {
+        vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ]
+    }
+
+    #[test]
+    fn test_assemble_plain() {
+        assert_eq!(
+            assemble("nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n"),
+            Ok(example())
+        )
+    }
+
+    #[test]
+    fn test_assemble_labels() {
+        assert_eq!(
+            assemble(
+                "# the example from the puzzle\n\
+                 \n\
+                 start: nop +0\n\
+                 again:\n\
+                 \x20   acc +1      # counted twice\n\
+                 \x20   jmp skip\n\
+                 back: acc +3\n\
+                 \x20   jmp again\n\
+                 \x20   acc -99\n\
+                 skip:\n\
+                 \x20   acc +1\n\
+                 \x20   jmp back\n\
+                 \x20   acc +6\n"
+            ),
+            Ok(example())
+        )
+    }
+
+    #[test]
+    fn test_assemble_label_at_end() {
+        assert_eq!(
+            assemble("jmp end\nacc +1\nend:\n"),
+            Ok(vec![Instruction::Jmp(2), Instruction::Acc(1)])
+        )
+    }
+
+    #[test]
+    fn test_assemble_unknown_label() {
+        assert_eq!(
+            assemble("acc +1\n  jmp nowhere\n"),
+            Err(ParseError::new(2, 7, "nowhere", "unknown label"))
+        )
+    }
+
+    #[test]
+    fn test_assemble_duplicate_label() {
+        assert_eq!(
+            assemble("a: acc +1\na: acc +1\n"),
+            Err(ParseError::new(2, 1, "a", "this label is already defined"))
+        )
+    }
+
+    #[test]
+    fn test_assemble_acc_label() {
+        assert_eq!(
+            assemble("a: acc a\n"),
+            Err(ParseError::new(
+                1,
+                8,
+                "a",
+                "acc takes a number, not a label"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_assemble_unknown_instruction() {
+        assert_eq!(
+            assemble("acc +1\nhlt +0\n"),
+            Err(ParseError::new(2, 1, "hlt", "unknown instruction"))
+        )
+    }
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            disassemble(&example()),
+            "    nop +0\n\
+             L1:\n\
+             \x20   acc +1\n\
+             \x20   jmp L3\n\
+             L2:\n\
+             \x20   acc +3\n\
+             \x20   jmp L1\n\
+             \x20   acc -99\n\
+             L3:\n\
+             \x20   acc +1\n\
+             \x20   jmp L2\n\
+             \x20   acc +6\n"
+        )
+    }
+
+    #[test]
+    fn test_disassemble_round_trip() {
+        let program = vec![
+            Instruction::Jmp(3),
+            Instruction::Jmp(-5),
+            Instruction::Nop(-1),
+            Instruction::Jmp(0),
+        ];
+        assert_eq!(assemble(&disassemble(&program)), Ok(program))
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            encode(&[Instruction::Acc(-1), Instruction::Jmp(64)]),
+            b"HB08\x02\x00\x01\x01\x80\x01".to_vec()
+        )
+    }
+
+    #[test]
+    fn test_bytecode_round_trip() {
+        let mut program = example();
+        program.push(Instruction::Nop(i32::MIN));
+        program.push(Instruction::Jmp(i32::MAX));
+        assert_eq!(decode(&encode(&program)), Ok(program))
+    }
+
+    #[test]
+    fn test_decode_bad_magic() {
+        assert_eq!(decode(b"acc +1"), Err(DecodeError::BadMagic))
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        assert_eq!(
+            decode(b"HB08\x02\x00\x01"),
+            Err(DecodeError::Truncated { offset: 7 })
+        )
+    }
+
+    #[test]
+    fn test_decode_unknown_opcode() {
+        assert_eq!(
+            decode_with(b"HB08\x01\x17\x00", Isa::Extended),
+            Err(DecodeError::UnknownOpcode {
+                offset: 5,
+                opcode: 23
+            })
+        )
+    }
+
+    #[test]
+    fn test_decode_extended_in_strict() {
+        assert_eq!(
+            decode(b"HB08\x01\x07\x00"),
+            Err(DecodeError::ExtendedOpcode {
+                offset: 5,
+                opcode: 7
+            })
+        )
+    }
+
+    #[test]
+    fn test_extended_round_trip() {
+        let program = Instruction::MNEMONICS
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| Instruction::new(name, i as i32 - 5).unwrap())
+            .collect::<Vec<Instruction>>();
+        assert_eq!(
+            (
+                decode_with(&encode(&program), Isa::Extended),
+                assemble_with(&disassemble(&program), Isa::Extended)
+            ),
+            (Ok(program.clone()), Ok(program))
+        )
+    }
+
+    #[test]
+    fn test_assemble_extended_in_strict() {
+        assert_eq!(
+            assemble("loop: jnz loop\n"),
+            Err(ParseError::new(
+                1,
+                7,
+                "jnz",
+                "this instruction is only in the extended instruction set"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_assemble_extended_labels() {
+        assert_eq!(
+            assemble_with("loop: acc -1\njpos loop\n", Isa::Extended),
+            Ok(vec![Instruction::Acc(-1), Instruction::Jpos(-1)])
+        )
+    }
+
+    #[test]
+    fn test_decode_padded_number() {
+        assert_eq!(
+            decode(b"HB08\x01\x00\x81\x00"),
+            Err(DecodeError::BadNumber { offset: 6 })
+        )
+    }
+
+    #[test]
+    fn test_decode_trailing_bytes() {
+        assert_eq!(
+            decode(b"HB08\x00\x00"),
+            Err(DecodeError::TrailingBytes { offset: 5 })
+        )
+    }
+}
@@ -0,0 +1,404 @@
+//! Control-flow graphs of handheld programs, for drawing with Graphviz.
+
+use crate::day08::prob02::{repair, Repair};
+use crate::day08::vm::{ExecutionOutcome, Vm};
+use crate::day08::Instruction;
+use std::collections::{BTreeSet, HashSet};
+
+/// A run of instructions, from `start` up to but not including `end`, that always execute
+/// together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Block {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Node {
+    /// The index of a block.
+    Block(usize),
+    /// Just after the last instruction, where the program terminates.
+    End,
+    /// Somewhere a jump leaves the program.
+    OutOfBounds(i64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EdgeKind {
+    FallThrough,
+    Jump,
+    /// Where the block would go if its last instruction were swapped for this one, which makes
+    /// the program terminate.
+    Fix(Instruction),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: Node,
+    pub kind: EdgeKind,
+    /// Whether running the program follows this edge round its infinite loop.
+    pub looping: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cfg<'a> {
+    pub program: &'a [Instruction],
+    pub blocks: Vec<Block>,
+    pub edges: Vec<Edge>,
+    /// The blocks in the loop the program runs into, if it does.
+    pub looping: HashSet<usize>,
+}
+
+impl Cfg<'_> {
+    /// Split a program into basic blocks.  Blocks start at the beginning of the program, after
+    /// every `jmp` and at every place one lands; instructions whose swap would fix the program
+    /// also end a block, so that the fix can be drawn as an edge.  Conditional jumps end a block
+    /// like `jmp` does, with an edge for each way they can go.  Only programs in the puzzle's
+    /// instruction set can be fixed, so other programs have no fix edges.
+    pub fn new(program: &[Instruction]) -> Cfg<'_> {
+        let fixes = match repair(program) {
+            Repair::Fixed(fix) => vec![fix],
+            Repair::Ambiguous(fixes) => fixes,
+            Repair::NotNeeded { .. }
+            | Repair::Unfixable
+            | Repair::Unsupported
+            | Repair::Overflow { .. } => vec![],
+        };
+
+        let in_program = |target: i64| (0..program.len() as i64).contains(&target);
+        let mut leaders = BTreeSet::new();
+        leaders.insert(0);
+        for (pc, instruction) in program.iter().enumerate() {
+            if instruction.is_jump() {
+                leaders.insert(pc + 1);
+                if in_program(jump_target(instruction, pc)) {
+                    leaders.insert(jump_target(instruction, pc) as usize);
+                }
+            }
+        }
+        for fix in &fixes {
+            leaders.insert(fix.pc + 1);
+            if in_program(fix.instruction.next(fix.pc)) {
+                leaders.insert(fix.instruction.next(fix.pc) as usize);
+            }
+        }
+        let starts = leaders
+            .into_iter()
+            .filter(|&pc| pc < program.len())
+            .collect::<Vec<usize>>();
+        let blocks = starts
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| Block {
+                start,
+                end: starts.get(i + 1).copied().unwrap_or(program.len()),
+            })
+            .collect::<Vec<Block>>();
+
+        let node = |target: i64| {
+            if target == program.len() as i64 {
+                Node::End
+            } else if in_program(target) {
+                Node::Block(starts.binary_search(&(target as usize)).unwrap())
+            } else {
+                Node::OutOfBounds(target)
+            }
+        };
+
+        // The pcs run on each trip round the loop, and each step from one pc to the next.
+        let (loop_pcs, loop_steps) = match Vm::new(program.to_vec()).run() {
+            ExecutionOutcome::Looped {
+                pc, trace, cycle, ..
+            } => {
+                let trip = &trace[trace.len() - cycle..];
+                let steps = trip
+                    .iter()
+                    .copied()
+                    .zip(trip.iter().skip(1).chain(std::iter::once(&pc)).copied())
+                    .collect::<HashSet<(usize, usize)>>();
+                (trip.iter().copied().collect::<HashSet<usize>>(), steps)
+            }
+            _ => (HashSet::new(), HashSet::new()),
+        };
+        let looping = blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| loop_pcs.contains(&block.start))
+            .map(|(i, _)| i)
+            .collect::<HashSet<usize>>();
+
+        let mut edges = blocks
+            .iter()
+            .enumerate()
+            .flat_map(|(i, block)| {
+                let last = block.end - 1;
+                let instruction = &program[last];
+                // Conditional jumps can go either way, so have an edge for each.
+                let mut successors = Vec::new();
+                if instruction.is_jump() {
+                    successors.push((jump_target(instruction, last), EdgeKind::Jump));
+                }
+                if !matches!(instruction, Instruction::Jmp(_)) {
+                    successors.push((last as i64 + 1, EdgeKind::FallThrough));
+                }
+                successors
+                    .into_iter()
+                    .map(|(target, kind)| Edge {
+                        from: i,
+                        to: node(target),
+                        kind,
+                        looping: target >= 0 && loop_steps.contains(&(last, target as usize)),
+                    })
+                    .collect::<Vec<Edge>>()
+            })
+            .collect::<Vec<Edge>>();
+        for fix in fixes {
+            edges.push(Edge {
+                from: starts.binary_search(&fix.pc).unwrap_or_else(|i| i - 1),
+                to: node(fix.instruction.next(fix.pc)),
+                kind: EdgeKind::Fix(fix.instruction),
+                looping: false,
+            });
+        }
+
+        Cfg {
+            program,
+            blocks,
+            edges,
+            looping,
+        }
+    }
+
+    /// The graph in Graphviz DOT.  The loop is drawn in red and fixes as dashed green edges.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph program {\n");
+        out.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+        for (i, block) in self.blocks.iter().enumerate() {
+            let label = (block.start..block.end)
+                .map(|pc| format!("{}: {}\\l", pc, self.program[pc]))
+                .collect::<String>();
+            let colour = if self.looping.contains(&i) {
+                ", color=red"
+            } else {
+                ""
+            };
+            out.push_str(&format!("    b{} [label=\"{}\"{}];\n", i, label, colour));
+        }
+        if self.edges.iter().any(|edge| edge.to == Node::End) {
+            out.push_str("    end [label=\"end\", shape=doublecircle];\n");
+        }
+        let mut out_of_bounds = self
+            .edges
+            .iter()
+            .filter_map(|edge| match edge.to {
+                Node::OutOfBounds(target) => Some(target),
+                _ => None,
+            })
+            .collect::<Vec<i64>>();
+        out_of_bounds.sort_unstable();
+        out_of_bounds.dedup();
+        for target in out_of_bounds {
+            out.push_str(&format!(
+                "    {} [label=\"out of bounds ({})\", shape=octagon];\n",
+                node_name(Node::OutOfBounds(target)),
+                target
+            ));
+        }
+        for edge in &self.edges {
+            let mut attributes = Vec::new();
+            if let EdgeKind::Fix(instruction) = &edge.kind {
+                let pc = self.blocks[edge.from].end - 1;
+                attributes.push(format!("label=\"{} to {}\"", pc, instruction));
+                attributes.push("style=dashed".to_string());
+                attributes.push("color=darkgreen".to_string());
+            } else if edge.looping {
+                attributes.push("color=red".to_string());
+            }
+            let attributes = if attributes.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attributes.join(", "))
+            };
+            out.push_str(&format!(
+                "    b{} -> {}{};\n",
+                edge.from,
+                node_name(edge.to),
+                attributes
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Where a jump at `pc` goes if it is taken.
+fn jump_target(instruction: &Instruction, pc: usize) -> i64 {
+    pc as i64 + instruction.argument() as i64
+}
+
+fn node_name(node: Node) -> String {
+    match node {
+        Node::Block(i) => format!("b{}", i),
+        Node::End => "end".to_string(),
+        Node::OutOfBounds(target) if target < 0 => format!("oob_minus_{}", -target),
+        Node::OutOfBounds(target) => format!("oob_{}", target),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Instruction> {
+        vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ]
+    }
+
+    #[test]
+    fn test_blocks() {
+        let program = example();
+        assert_eq!(
+            Cfg::new(&program)
+                .blocks
+                .iter()
+                .map(|block| (block.start, block.end))
+                .collect::<Vec<(usize, usize)>>(),
+            vec![(0, 1), (1, 3), (3, 5), (5, 6), (6, 8), (8, 9)]
+        )
+    }
+
+    #[test]
+    fn test_looping() {
+        let program = example();
+        assert_eq!(Cfg::new(&program).looping, hashset!(1, 2, 4))
+    }
+
+    #[test]
+    fn test_edges() {
+        let program = example();
+        assert_eq!(
+            Cfg::new(&program)
+                .edges
+                .iter()
+                .map(|edge| (edge.from, edge.to, edge.looping))
+                .collect::<Vec<(usize, Node, bool)>>(),
+            vec![
+                (0, Node::Block(1), false),
+                (1, Node::Block(4), true),
+                (2, Node::Block(1), true),
+                (3, Node::Block(4), false),
+                (4, Node::Block(2), true),
+                (5, Node::End, false),
+                (4, Node::Block(5), false)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_fix_edge() {
+        let program = example();
+        assert_eq!(
+            Cfg::new(&program).edges[6].kind,
+            EdgeKind::Fix(Instruction::Nop(-4))
+        )
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let program = vec![Instruction::Jmp(-2), Instruction::Acc(1)];
+        assert_eq!(Cfg::new(&program).edges[0].to, Node::OutOfBounds(-2))
+    }
+
+    #[test]
+    fn test_conditional_jump() {
+        // Count down from 2, then stop.
+        let program = vec![
+            Instruction::Acc(2),
+            Instruction::Acc(-1),
+            Instruction::Jnz(-1),
+            Instruction::Out(0),
+        ];
+        assert_eq!(
+            Cfg::new(&program)
+                .edges
+                .iter()
+                .map(|edge| (edge.from, edge.to, edge.kind.clone()))
+                .collect::<Vec<(usize, Node, EdgeKind)>>(),
+            vec![
+                (0, Node::Block(1), EdgeKind::FallThrough),
+                (1, Node::Block(1), EdgeKind::Jump),
+                (1, Node::Block(2), EdgeKind::FallThrough),
+                (2, Node::End, EdgeKind::FallThrough)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_conditional_loop() {
+        let program = vec![Instruction::Jz(0), Instruction::Acc(1)];
+        assert_eq!(
+            Cfg::new(&program)
+                .edges
+                .iter()
+                .map(|edge| (edge.to, edge.looping))
+                .collect::<Vec<(Node, bool)>>(),
+            vec![
+                (Node::Block(0), true),
+                (Node::Block(1), false),
+                (Node::End, false)
+            ]
+        )
+    }
+
+    #[test]
+    fn test_loop_after_earlier_visit() {
+        // Pc 1 first runs with acc 1 and jumps over the loop; the loop starts on the next visit.
+        let program = vec![
+            Instruction::Acc(1),
+            Instruction::Jnz(2),
+            Instruction::Jmp(-1),
+            Instruction::Acc(-1),
+            Instruction::Jmp(-3),
+        ];
+        assert_eq!(Cfg::new(&program).looping, hashset!(1, 2))
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let program = vec![
+            Instruction::Acc(1),
+            Instruction::Jmp(-1),
+            Instruction::Acc(2),
+        ];
+        assert_eq!(
+            Cfg::new(&program).to_dot(),
+            "digraph program {\n\
+             \x20   node [shape=box, fontname=\"monospace\"];\n\
+             \x20   b0 [label=\"0: acc +1\\l1: jmp -1\\l\", color=red];\n\
+             \x20   b1 [label=\"2: acc +2\\l\"];\n\
+             \x20   end [label=\"end\", shape=doublecircle];\n\
+             \x20   b0 -> b0 [color=red];\n\
+             \x20   b1 -> end;\n\
+             \x20   b0 -> b1 [label=\"1 to nop -1\", style=dashed, color=darkgreen];\n\
+             }\n"
+        )
+    }
+
+    #[test]
+    fn test_to_dot_out_of_bounds() {
+        let program = vec![Instruction::Jmp(-2), Instruction::Acc(1)];
+        assert!(Cfg::new(&program)
+            .to_dot()
+            .contains("    oob_minus_2 [label=\"out of bounds (-2)\", shape=octagon];\n"))
+    }
+}
@@ -0,0 +1,447 @@
+//! An interactive step debugger for handheld programs, driven one command line at a time.
+
+use crate::day08::vm::{ExecutionOutcome, LoopDetector, Vm, STEP_LIMIT};
+use crate::day08::{Instruction, Isa};
+
+pub const HELP: &str = "\
+commands:
+    s, step [n]          execute n instructions (default 1)
+    c, continue          run until a breakpoint, a watchpoint, a loop or the end
+    b, break <pc>        stop before executing the instruction at pc
+    b, break <op>        stop before executing any instruction called op
+    w, watch [value]     stop when acc changes, or when it becomes value
+    d, delete            remove all breakpoints and watchpoints
+    l, list [n]          show n instructions either side of pc (default 3)
+    p, print             show pc and acc
+    patch <pc>           swap the jmp or nop at pc
+    patch <pc> <op> <n>  replace the instruction at pc
+    r, reset             start the (patched) program again
+    h, help              show this message
+    q, quit              leave the debugger";
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Breakpoint {
+    Pc(usize),
+    Mnemonic(String),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Watch {
+    Change,
+    Value(i32),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    Watch(Watch),
+    Delete,
+    List(usize),
+    Print,
+    Flip(usize),
+    Patch(usize, Instruction),
+    Reset,
+    Help,
+    Quit,
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Command, String> {
+        Command::parse_with(line, Isa::Strict)
+    }
+
+    /// Parse a command, accepting instructions from `isa` in patches.
+    pub fn parse_with(line: &str, isa: Isa) -> Result<Command, String> {
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        let (name, args) = match words.split_first() {
+            Some((name, args)) => (*name, args),
+            None => return Err("expected a command; try 'help'".to_string()),
+        };
+        let command = match (name, args) {
+            ("s" | "step", []) => Command::Step(1),
+            ("s" | "step", [n]) => Command::Step(number(n)?),
+            ("c" | "continue", []) => Command::Continue,
+            ("b" | "break", [op]) if Instruction::isa_of(op).is_some() => {
+                Command::Break(Breakpoint::Mnemonic(op.to_string()))
+            }
+            ("b" | "break", [pc]) => Command::Break(Breakpoint::Pc(number(pc)?)),
+            ("w" | "watch", []) => Command::Watch(Watch::Change),
+            ("w" | "watch", [value]) => Command::Watch(Watch::Value(number(value)?)),
+            ("d" | "delete", []) => Command::Delete,
+            ("l" | "list", []) => Command::List(3),
+            ("l" | "list", [n]) => Command::List(number(n)?),
+            ("p" | "print", []) => Command::Print,
+            ("patch", [pc]) => Command::Flip(number(pc)?),
+            ("patch", [pc, op, arg]) => Command::Patch(
+                number(pc)?,
+                Instruction::from_asm_with(&format!("{} {}", op, arg), isa)
+                    .map_err(|e| e.to_string())?,
+            ),
+            ("r" | "reset", []) => Command::Reset,
+            ("h" | "help", []) => Command::Help,
+            ("q" | "quit", []) => Command::Quit,
+            _ => return Err(format!("can't understand '{}'; try 'help'", line.trim())),
+        };
+        Ok(command)
+    }
+}
+
+fn number<T: std::str::FromStr>(word: &str) -> Result<T, String> {
+    word.parse()
+        .map_err(|_| format!("expected a number, not '{}'", word))
+}
+
+pub struct Debugger {
+    vm: Vm,
+    breakpoints: Vec<Breakpoint>,
+    watch: Option<Watch>,
+    /// How the program ended, once it has.
+    outcome: Option<ExecutionOutcome>,
+}
+
+impl Debugger {
+    pub fn new(program: Vec<Instruction>) -> Debugger {
+        Debugger {
+            vm: Vm::new(program),
+            breakpoints: Vec::new(),
+            watch: None,
+            outcome: None,
+        }
+    }
+
+    pub fn vm(&self) -> &Vm {
+        &self.vm
+    }
+
+    /// Carry out a command, returning what to show for it.  `Quit` is left to the caller.
+    pub fn execute(&mut self, command: Command) -> String {
+        match command {
+            Command::Step(n) => self.resume(Some(n)),
+            Command::Continue => self.resume(None),
+            Command::Break(breakpoint) => {
+                if let Breakpoint::Pc(pc) = breakpoint {
+                    if pc >= self.vm.program().len() {
+                        return format!("no instruction at {}", pc);
+                    }
+                }
+                let message = format!("breakpoint {}", self.breakpoints.len() + 1);
+                self.breakpoints.push(breakpoint);
+                message
+            }
+            Command::Watch(watch) => {
+                self.watch = Some(watch);
+                match watch {
+                    Watch::Change => "watching acc".to_string(),
+                    Watch::Value(value) => format!("watching for acc = {}", value),
+                }
+            }
+            Command::Delete => {
+                self.breakpoints.clear();
+                self.watch = None;
+                "deleted all breakpoints and watchpoints".to_string()
+            }
+            Command::List(n) => self.list(n),
+            Command::Print => self.location(),
+            Command::Flip(pc) => match self.vm.program().get(pc).map(Instruction::flipped) {
+                None => format!("no instruction at {}", pc),
+                Some(None) => format!("can't swap the acc at {}", pc),
+                Some(Some(flipped)) => self.patch(pc, flipped),
+            },
+            Command::Patch(pc, instruction) => {
+                if pc >= self.vm.program().len() {
+                    return format!("no instruction at {}", pc);
+                }
+                self.patch(pc, instruction)
+            }
+            Command::Reset => {
+                self.vm = Vm::new(self.vm.program().to_vec());
+                self.outcome = None;
+                self.location()
+            }
+            Command::Help => HELP.to_string(),
+            Command::Quit => String::new(),
+        }
+    }
+
+    fn patch(&mut self, pc: usize, instruction: Instruction) -> String {
+        let old = self.vm.patch(pc, instruction.clone());
+        format!("{:>4}: {} -> {}", pc, old, instruction)
+    }
+
+    /// Run `steps` instructions, or until something stops the program if `None`.  Breakpoints
+    /// only stop the program before instructions after the first.
+    fn resume(&mut self, steps: Option<usize>) -> String {
+        if let Some(outcome) = &self.outcome {
+            return format!("{}; reset to start again", describe(outcome));
+        }
+        let mut detector = LoopDetector::new(self.vm.program());
+        let mut count = 0;
+        while steps.is_none_or(|steps| count < steps) {
+            if count > 0 {
+                if let Some(n) = self.breakpoint() {
+                    return format!("hit breakpoint {}\n{}", n + 1, self.location());
+                }
+            }
+            if steps.is_none() && detector.out_of_steps() {
+                return format!(
+                    "still running after {} instructions\n{}",
+                    STEP_LIMIT,
+                    self.location()
+                );
+            }
+            if steps.is_none() && detector.revisits(&self.vm).is_some() {
+                return format!(
+                    "loop: {} is about to run again\n{}",
+                    self.vm.pc(),
+                    self.location()
+                );
+            }
+            let acc = self.vm.acc();
+            let outcome = self.vm.step();
+            count += 1;
+            if let Some(outcome) = outcome {
+                let message = describe(&outcome);
+                self.outcome = Some(outcome);
+                return message;
+            }
+            let watched = match self.watch {
+                Some(Watch::Change) => self.vm.acc() != acc,
+                Some(Watch::Value(value)) => self.vm.acc() == value && acc != value,
+                None => false,
+            };
+            if watched {
+                return format!(
+                    "acc changed from {} to {}\n{}",
+                    acc,
+                    self.vm.acc(),
+                    self.location()
+                );
+            }
+        }
+        self.location()
+    }
+
+    /// The index of the first breakpoint set on the next instruction.
+    fn breakpoint(&self) -> Option<usize> {
+        let pc = self.vm.pc();
+        let instruction = self.vm.program().get(pc)?;
+        self.breakpoints
+            .iter()
+            .position(|breakpoint| match breakpoint {
+                Breakpoint::Pc(at) => *at == pc,
+                Breakpoint::Mnemonic(op) => op == instruction.mnemonic(),
+            })
+    }
+
+    fn location(&self) -> String {
+        match &self.outcome {
+            Some(outcome) => describe(outcome),
+            None => match self.vm.program().get(self.vm.pc()) {
+                Some(instruction) => {
+                    format!("pc {} acc {}: {}", self.vm.pc(), self.vm.acc(), instruction)
+                }
+                None => format!("pc {} acc {}: end of program", self.vm.pc(), self.vm.acc()),
+            },
+        }
+    }
+
+    /// The instructions within `n` of pc, marking pc with `>` and breakpoints with `*`.
+    fn list(&self, n: usize) -> String {
+        let program = self.vm.program();
+        let pc = self.vm.pc();
+        let start = pc.saturating_sub(n);
+        let end = (pc + n + 1).min(program.len());
+        (start..end)
+            .map(|i| {
+                let marker = if i == pc && self.outcome.is_none() {
+                    '>'
+                } else if self.breakpoints.contains(&Breakpoint::Pc(i)) {
+                    '*'
+                } else {
+                    ' '
+                };
+                format!("{} {:>4}: {}", marker, i, program[i])
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn describe(outcome: &ExecutionOutcome) -> String {
+    match outcome {
+        ExecutionOutcome::Terminated { acc } => format!("terminated with acc {}", acc),
+        ExecutionOutcome::Looped { acc, pc, .. } => {
+            format!("looped at {} with acc {}", pc, acc)
+        }
+        ExecutionOutcome::OutOfBounds { pc } => format!("jumped out of bounds to {}", pc),
+        ExecutionOutcome::Overflow {
+            pc,
+            register,
+            value,
+        } => format!("overflowed at {} with {} {}", pc, register, value),
+        ExecutionOutcome::StepLimit { pc, acc } => format!(
+            "gave up at {} with acc {} after {} instructions",
+            pc, acc, STEP_LIMIT
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn debugger() -> Debugger {
+        Debugger::new(vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ])
+    }
+
+    #[test]
+    fn test_parse_break_pc() {
+        assert_eq!(Command::parse("b 7"), Ok(Command::Break(Breakpoint::Pc(7))))
+    }
+
+    #[test]
+    fn test_parse_break_mnemonic() {
+        assert_eq!(
+            Command::parse("break jmp"),
+            Ok(Command::Break(Breakpoint::Mnemonic("jmp".to_string())))
+        )
+    }
+
+    #[test]
+    fn test_parse_patch() {
+        assert_eq!(
+            Command::parse("patch 7 nop -4"),
+            Ok(Command::Patch(7, Instruction::Nop(-4)))
+        )
+    }
+
+    #[test]
+    fn test_parse_patch_extended() {
+        assert_eq!(
+            Command::parse_with("patch 2 jz +3", Isa::Extended),
+            Ok(Command::Patch(2, Instruction::Jz(3)))
+        )
+    }
+
+    #[test]
+    fn test_parse_patch_extended_strict() {
+        assert!(Command::parse("patch 2 jz +3").is_err())
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert_eq!(
+            Command::parse("jump 3"),
+            Err("can't understand 'jump 3'; try 'help'".to_string())
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_number() {
+        assert_eq!(
+            Command::parse("step x"),
+            Err("expected a number, not 'x'".to_string())
+        )
+    }
+
+    #[test]
+    fn test_step() {
+        let mut debugger = debugger();
+        assert_eq!(debugger.execute(Command::Step(3)), "pc 6 acc 1: acc +1")
+    }
+
+    #[test]
+    fn test_continue_to_loop() {
+        let mut debugger = debugger();
+        assert_eq!(
+            debugger.execute(Command::Continue),
+            "loop: 1 is about to run again\npc 1 acc 5: acc +1"
+        )
+    }
+
+    #[test]
+    fn test_continue_to_breakpoint() {
+        let mut debugger = debugger();
+        debugger.execute(Command::Break(Breakpoint::Mnemonic("jmp".to_string())));
+        debugger.execute(Command::Continue);
+        assert_eq!(
+            debugger.execute(Command::Continue),
+            "hit breakpoint 1\npc 7 acc 2: jmp -4"
+        )
+    }
+
+    #[test]
+    fn test_watch_value() {
+        let mut debugger = debugger();
+        debugger.execute(Command::Watch(Watch::Value(2)));
+        assert_eq!(
+            debugger.execute(Command::Continue),
+            "acc changed from 1 to 2\npc 7 acc 2: jmp -4"
+        )
+    }
+
+    #[test]
+    fn test_patch_and_continue() {
+        let mut debugger = debugger();
+        debugger.execute(Command::Flip(7));
+        assert_eq!(debugger.execute(Command::Continue), "terminated with acc 8")
+    }
+
+    #[test]
+    fn test_flip_acc() {
+        assert_eq!(
+            debugger().execute(Command::Flip(1)),
+            "can't swap the acc at 1"
+        )
+    }
+
+    #[test]
+    fn test_step_after_end() {
+        let mut debugger = debugger();
+        debugger.execute(Command::Patch(0, Instruction::Jmp(9)));
+        debugger.execute(Command::Step(1));
+        assert_eq!(
+            debugger.execute(Command::Step(1)),
+            "terminated with acc 0; reset to start again"
+        )
+    }
+
+    #[test]
+    fn test_empty_program() {
+        let mut debugger = Debugger::new(vec![]);
+        debugger.execute(Command::Break(Breakpoint::Mnemonic("acc".to_string())));
+        assert_eq!(
+            (
+                debugger.execute(Command::Print),
+                debugger.execute(Command::Continue)
+            ),
+            (
+                "pc 0 acc 0: end of program".to_string(),
+                "terminated with acc 0".to_string()
+            )
+        )
+    }
+
+    #[test]
+    fn test_list() {
+        let mut debugger = debugger();
+        debugger.execute(Command::Break(Breakpoint::Pc(3)));
+        debugger.execute(Command::Step(1));
+        assert_eq!(
+            debugger.execute(Command::List(2)),
+            "     0: nop +0\n>    1: acc +1\n     2: jmp +4\n*    3: acc +3"
+        )
+    }
+}
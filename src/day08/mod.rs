@@ -0,0 +1,451 @@
+use crate::parse::{self, ParseError};
+use std::fmt;
+
+pub mod asm;
+pub mod cfg;
+pub mod debugger;
+pub mod prob01;
+pub mod prob02;
+pub mod profile;
+pub mod vm;
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_with(input, Isa::Strict)
+}
+
+/// Parse a program that may use the instructions of `isa`.
+pub fn parse_with(input: &str, isa: Isa) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| Instruction::from_asm_with(line, isa).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Instruction>, ParseError>>()
+}
+
+/// What the day 08 tools were asked to load: the program's path and the instructions it may use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    pub path: String,
+    pub isa: Isa,
+}
+
+/// Read the day 08 tools' arguments: an optional path to the program, `src/bin/day08.txt` if
+/// there isn't one, and `--extended` to allow the extended instruction set.  Verbosity flags are
+/// skipped, as `trace::init_from_args` handles them.
+pub fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut path = None;
+    let mut isa = Isa::Strict;
+    for arg in args {
+        if arg == "--extended" {
+            isa = Isa::Extended;
+        } else if crate::trace::parse_flag(arg).is_some() {
+            continue;
+        } else if arg.starts_with('-') {
+            return Err(format!("unknown flag '{}'", arg));
+        } else if path.replace(arg.clone()).is_some() {
+            return Err("expected a single program".to_string());
+        }
+    }
+    Ok(Args {
+        path: path.unwrap_or_else(|| "src/bin/day08.txt".to_string()),
+        isa,
+    })
+}
+
+/// The arguments `tool` was run with, exiting with its usage if they're wrong.
+pub fn args(tool: &str) -> Args {
+    parse_args(&std::env::args().skip(1).collect::<Vec<String>>()).unwrap_or_else(|message| {
+        eprintln!(
+            "error: {}
+
+usage: {} [--extended] [-v | -vv] [program]",
+            message, tool
+        );
+        std::process::exit(1);
+    })
+}
+
+/// Which instructions a program may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Isa {
+    /// Only `acc`, `jmp` and `nop`, as in the puzzle.
+    Strict,
+    /// The strict instructions plus conditional jumps, `mul`, a second register and output.
+    Extended,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+    /// Jump if the accumulator is zero.
+    Jz(i32),
+    /// Jump if the accumulator isn't zero.
+    Jnz(i32),
+    /// Jump if the accumulator is negative.
+    Jneg(i32),
+    /// Jump if the accumulator is positive.
+    Jpos(i32),
+    /// Multiply the accumulator.
+    Mul(i32),
+    /// Add to the second register, `b`.
+    AccB(i32),
+    /// Swap the accumulator and `b`.  The argument is ignored, as for `nop`.
+    Swap(i32),
+    /// Output the accumulator.  The argument is ignored, as for `nop`.
+    Out(i32),
+}
+
+impl Instruction {
+    /// Every mnemonic, with the instruction set it first appears in.
+    pub const MNEMONICS: [(&'static str, Isa); 11] = [
+        ("acc", Isa::Strict),
+        ("jmp", Isa::Strict),
+        ("nop", Isa::Strict),
+        ("jz", Isa::Extended),
+        ("jnz", Isa::Extended),
+        ("jneg", Isa::Extended),
+        ("jpos", Isa::Extended),
+        ("mul", Isa::Extended),
+        ("accb", Isa::Extended),
+        ("swp", Isa::Extended),
+        ("out", Isa::Extended),
+    ];
+
+    /// Read an instruction from the puzzle's instruction set.
+    pub fn from_asm(line: &str) -> Result<Instruction, ParseError> {
+        Instruction::from_asm_with(line, Isa::Strict)
+    }
+
+    pub fn from_asm_with(line: &str, isa: Isa) -> Result<Instruction, ParseError> {
+        let pieces = line.split(' ').collect::<Vec<&str>>();
+        if pieces.len() != 2 {
+            return Err(ParseError::within(
+                line,
+                line,
+                "expected an instruction like 'acc +1'",
+            ));
+        }
+        let cmd = pieces[0];
+        match Instruction::isa_of(cmd) {
+            None => Err(ParseError::within(line, cmd, "unknown instruction")),
+            Some(needed) if needed > isa => Err(ParseError::within(
+                line,
+                cmd,
+                "this instruction is only in the extended instruction set",
+            )),
+            Some(_) => {
+                let val = parse::number(line, pieces[1])?;
+                Ok(Instruction::new(cmd, val).expect("every mnemonic has an instruction"))
+            }
+        }
+    }
+
+    /// The instruction called `mnemonic`, from any instruction set.
+    pub fn new(mnemonic: &str, val: i32) -> Option<Instruction> {
+        Some(match mnemonic {
+            "acc" => Instruction::Acc(val),
+            "jmp" => Instruction::Jmp(val),
+            "nop" => Instruction::Nop(val),
+            "jz" => Instruction::Jz(val),
+            "jnz" => Instruction::Jnz(val),
+            "jneg" => Instruction::Jneg(val),
+            "jpos" => Instruction::Jpos(val),
+            "mul" => Instruction::Mul(val),
+            "accb" => Instruction::AccB(val),
+            "swp" => Instruction::Swap(val),
+            "out" => Instruction::Out(val),
+            _ => return None,
+        })
+    }
+
+    /// The instruction set that `mnemonic` first appears in, if it's an instruction at all.
+    pub fn isa_of(mnemonic: &str) -> Option<Isa> {
+        Instruction::MNEMONICS
+            .iter()
+            .find(|(name, _)| *name == mnemonic)
+            .map(|(_, isa)| *isa)
+    }
+
+    /// The smallest instruction set that includes this instruction.
+    pub fn isa(&self) -> Isa {
+        match self {
+            Instruction::Acc(_) | Instruction::Jmp(_) | Instruction::Nop(_) => Isa::Strict,
+            _ => Isa::Extended,
+        }
+    }
+
+    /// The instruction's name in assembly.
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Acc(_) => "acc",
+            Instruction::Jmp(_) => "jmp",
+            Instruction::Nop(_) => "nop",
+            Instruction::Jz(_) => "jz",
+            Instruction::Jnz(_) => "jnz",
+            Instruction::Jneg(_) => "jneg",
+            Instruction::Jpos(_) => "jpos",
+            Instruction::Mul(_) => "mul",
+            Instruction::AccB(_) => "accb",
+            Instruction::Swap(_) => "swp",
+            Instruction::Out(_) => "out",
+        }
+    }
+
+    pub fn argument(&self) -> i32 {
+        match *self {
+            Instruction::Acc(val)
+            | Instruction::Jmp(val)
+            | Instruction::Nop(val)
+            | Instruction::Jz(val)
+            | Instruction::Jnz(val)
+            | Instruction::Jneg(val)
+            | Instruction::Jpos(val)
+            | Instruction::Mul(val)
+            | Instruction::AccB(val)
+            | Instruction::Swap(val)
+            | Instruction::Out(val) => val,
+        }
+    }
+
+    /// Whether the argument is an offset to jump by, possibly.
+    pub fn is_jump(&self) -> bool {
+        matches!(
+            self,
+            Instruction::Jmp(_)
+                | Instruction::Jz(_)
+                | Instruction::Jnz(_)
+                | Instruction::Jneg(_)
+                | Instruction::Jpos(_)
+        )
+    }
+
+    /// Where execution goes after running this instruction at `pc`, which may be outside the
+    /// program.  Conditional jumps are taken to fall through; only `Vm` knows whether they jump.
+    pub fn next(&self, pc: usize) -> i64 {
+        match *self {
+            Instruction::Jmp(val) => pc as i64 + val as i64,
+            _ => pc as i64 + 1,
+        }
+    }
+
+    /// The instruction with `jmp` and `nop` swapped, or `None` for any other, which can't be.
+    pub fn flipped(&self) -> Option<Instruction> {
+        match *self {
+            Instruction::Jmp(val) => Some(Instruction::Nop(val)),
+            Instruction::Nop(val) => Some(Instruction::Jmp(val)),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the instruction as `from_asm` reads it.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:+}", self.mnemonic(), self.argument())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_instruction_from_asm_acc_positive() {
+        assert_eq!(Instruction::from_asm("acc +3"), Ok(Instruction::Acc(3)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_acc_zero() {
+        assert_eq!(Instruction::from_asm("acc +0"), Ok(Instruction::Acc(0)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_acc_negative() {
+        assert_eq!(Instruction::from_asm("acc -3"), Ok(Instruction::Acc(-3)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_jmp_positive() {
+        assert_eq!(Instruction::from_asm("jmp +3"), Ok(Instruction::Jmp(3)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_jmp_zero() {
+        assert_eq!(Instruction::from_asm("jmp +0"), Ok(Instruction::Jmp(0)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_jmp_negative() {
+        assert_eq!(Instruction::from_asm("jmp -3"), Ok(Instruction::Jmp(-3)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_nop_positive() {
+        assert_eq!(Instruction::from_asm("nop +3"), Ok(Instruction::Nop(3)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_nop_zero() {
+        assert_eq!(Instruction::from_asm("nop +0"), Ok(Instruction::Nop(0)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_nop_negative() {
+        assert_eq!(Instruction::from_asm("nop -3"), Ok(Instruction::Nop(-3)))
+    }
+
+    #[test]
+    fn test_instruction_from_asm_unknown() {
+        assert_eq!(
+            Instruction::from_asm("hlt +0"),
+            Err(ParseError::new(1, 1, "hlt", "unknown instruction"))
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_asm_bad_argument() {
+        assert_eq!(
+            Instruction::from_asm("jmp x3"),
+            Err(ParseError::new(1, 5, "x3", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_asm_missing_argument() {
+        assert_eq!(
+            Instruction::from_asm("nop"),
+            Err(ParseError::new(
+                1,
+                1,
+                "nop",
+                "expected an instruction like 'acc +1'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            [
+                Instruction::Acc(0),
+                Instruction::Jmp(-4),
+                Instruction::Nop(12)
+            ]
+            .iter()
+            .map(Instruction::to_string)
+            .collect::<Vec<String>>(),
+            vec!["acc +0", "jmp -4", "nop +12"]
+        )
+    }
+
+    #[test]
+    fn test_flipped() {
+        assert_eq!(
+            vec![
+                Instruction::Acc(1).flipped(),
+                Instruction::Jmp(2).flipped(),
+                Instruction::Nop(3).flipped()
+            ],
+            vec![None, Some(Instruction::Nop(2)), Some(Instruction::Jmp(3))]
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_asm_extended_in_strict() {
+        assert_eq!(
+            Instruction::from_asm("mul +2"),
+            Err(ParseError::new(
+                1,
+                1,
+                "mul",
+                "this instruction is only in the extended instruction set"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_asm_extended() {
+        assert_eq!(
+            Instruction::from_asm_with("jneg -4", Isa::Extended),
+            Ok(Instruction::Jneg(-4))
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_asm_extended_unknown() {
+        assert_eq!(
+            Instruction::from_asm_with("hlt +0", Isa::Extended),
+            Err(ParseError::new(1, 1, "hlt", "unknown instruction"))
+        )
+    }
+
+    #[test]
+    fn test_mnemonics_round_trip() {
+        assert!(Instruction::MNEMONICS.iter().all(|(name, isa)| {
+            let instruction = Instruction::new(name, 1).unwrap();
+            instruction.mnemonic() == *name && instruction.isa() == *isa
+        }))
+    }
+
+    #[test]
+    fn test_parse_with_extended() {
+        assert_eq!(
+            parse_with("accb +2\nswp +0\nout +0\n", Isa::Extended),
+            Ok(vec![
+                Instruction::AccB(2),
+                Instruction::Swap(0),
+                Instruction::Out(0)
+            ])
+        )
+    }
+
+    #[test]
+    fn test_parse_error_line() {
+        assert_eq!(
+            parse("nop +0\nacc +1\njmp ?4\n").map_err(|e| (e.line, e.column)),
+            Err((3, 5))
+        )
+    }
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(
+            &args
+                .iter()
+                .map(|arg| arg.to_string())
+                .collect::<Vec<String>>(),
+        )
+    }
+
+    #[test]
+    fn test_parse_args_default() {
+        assert_eq!(
+            args(&["-v"]),
+            Ok(Args {
+                path: "src/bin/day08.txt".to_string(),
+                isa: Isa::Strict
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args(&["prog.txt", "--extended"]),
+            Ok(Args {
+                path: "prog.txt".to_string(),
+                isa: Isa::Extended
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert_eq!(
+            (args(&["--isa"]), args(&["a.txt", "b.txt"])),
+            (
+                Err("unknown flag '--isa'".to_string()),
+                Err("expected a single program".to_string())
+            )
+        )
+    }
+}
@@ -0,0 +1,34 @@
+use crate::day08::vm::{ExecutionOutcome, Vm};
+use crate::day08::Instruction;
+
+/// The accumulator just before any instruction runs a second time, or how the program ended if
+/// it never loops.
+pub fn run_program(program: Vec<Instruction>) -> Result<i32, ExecutionOutcome> {
+    match Vm::new(program).run() {
+        ExecutionOutcome::Looped { acc, .. } => Ok(acc),
+        outcome => Err(outcome),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_run_program() {
+        assert_eq!(
+            run_program(vec![
+                Instruction::Nop(0),
+                Instruction::Acc(1),
+                Instruction::Jmp(4),
+                Instruction::Acc(3),
+                Instruction::Jmp(-3),
+                Instruction::Acc(-99),
+                Instruction::Acc(1),
+                Instruction::Jmp(-4),
+                Instruction::Acc(6)
+            ]),
+            Ok(5)
+        )
+    }
+}
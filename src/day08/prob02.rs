@@ -0,0 +1,379 @@
+use crate::day08::{Instruction, Isa};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+/// Swapping the `jmp` or `nop` at `pc` for `instruction` makes the program terminate, leaving
+/// `acc` in the accumulator.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fix {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub acc: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Repair {
+    /// The program terminates as it is.
+    NotNeeded {
+        acc: i32,
+    },
+    Fixed(Fix),
+    /// No single swap makes the program terminate.
+    Unfixable,
+    /// More than one swap makes the program terminate.
+    Ambiguous(Vec<Fix>),
+    /// The program uses instructions beyond the puzzle's, so can't be repaired.
+    Unsupported,
+    /// No swap makes the program terminate, and as it is the instruction at `pc` would take the
+    /// accumulator past what it can hold, just as the VM reports.
+    Overflow {
+        pc: usize,
+        acc: i32,
+    },
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Repair::NotNeeded { acc } => {
+                write!(f, "the program already terminates, with acc {}", acc)
+            }
+            Repair::Fixed(fix) => write!(
+                f,
+                "changing {} to {} terminates with acc {}",
+                fix.pc, fix.instruction, fix.acc
+            ),
+            Repair::Unfixable => write!(f, "no single jmp/nop swap makes the program terminate"),
+            Repair::Ambiguous(fixes) => {
+                write!(
+                    f,
+                    "{} different swaps make the program terminate:",
+                    fixes.len()
+                )?;
+                for fix in fixes {
+                    write!(
+                        f,
+                        "\n  changing {} to {} (acc {})",
+                        fix.pc, fix.instruction, fix.acc
+                    )?;
+                }
+                Ok(())
+            }
+            Repair::Unsupported => {
+                write!(f, "only programs using acc, jmp and nop can be repaired")
+            }
+            Repair::Overflow { pc, acc } => write!(
+                f,
+                "no single jmp/nop swap makes the program terminate, and the accumulator \
+                 overflows at {} (acc {})",
+                pc, acc
+            ),
+        }
+    }
+}
+
+/// The accumulator once the program's single bug is fixed, or the repair found if there isn't
+/// exactly one fix.
+pub fn fix_bug(program: Vec<Instruction>) -> Result<i32, Repair> {
+    match repair(&program) {
+        Repair::Fixed(fix) => Ok(fix.acc),
+        repair => Err(repair),
+    }
+}
+
+/// Find every jmp/nop swap that makes the program terminate, in time linear in its length.
+/// Only programs in the puzzle's instruction set can be repaired.
+pub fn repair(program: &[Instruction]) -> Repair {
+    if program
+        .iter()
+        .any(|instruction| instruction.isa() != Isa::Strict)
+    {
+        return Repair::Unsupported;
+    }
+    let to_end = terminating(program);
+
+    // Only a swap on the path the program takes now can change where it goes, and only up to
+    // where the accumulator overflows, if it does.
+    let mut fixes = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pc = 0;
+    let mut acc: i32 = 0;
+    let mut overflow = None;
+    while pc < program.len() && visited.insert(pc) {
+        // A program that would end but for overflowing carries on to where it overflows.
+        if let Some(acc) = to_end[pc].and_then(|rest| rest.end_acc(acc)) {
+            return Repair::NotNeeded { acc };
+        }
+        let instruction = &program[pc];
+        if let Some(flipped) = instruction.flipped() {
+            let target = flipped.next(pc);
+            if (0..=program.len() as i64).contains(&target) {
+                match to_end[target as usize].map(|rest| rest.end_acc(acc)) {
+                    Some(Some(end_acc)) => {
+                        trace!("swapping {} at {} reaches the end", instruction, pc);
+                        fixes.push(Fix {
+                            pc,
+                            acc: end_acc,
+                            instruction: flipped,
+                        });
+                    }
+                    Some(None) => trace!("swapping {} at {} overflows", instruction, pc),
+                    None => (),
+                }
+            }
+        }
+        if let Instruction::Acc(val) = instruction {
+            match acc.checked_add(*val) {
+                Some(sum) => acc = sum,
+                None => {
+                    overflow = Some(Repair::Overflow { pc, acc });
+                    break;
+                }
+            }
+        }
+        match instruction.next(pc) {
+            next if next < 0 => break,
+            next => pc = next as usize,
+        }
+    }
+    if pc == program.len() {
+        return Repair::NotNeeded { acc };
+    }
+
+    match fixes.len() {
+        0 => overflow.unwrap_or(Repair::Unfixable),
+        1 => Repair::Fixed(fixes.remove(0)),
+        _ => Repair::Ambiguous(fixes),
+    }
+}
+
+/// How running from some pc to the end of the program changes the accumulator: by `acc` in
+/// all, having gone at most `max` above and `min` below where it started on the way.
+#[derive(Debug, Clone, Copy, Default)]
+struct ToEnd {
+    acc: i64,
+    min: i64,
+    max: i64,
+}
+
+impl ToEnd {
+    /// Running `instruction` and then the rest of the way to the end.
+    fn after(self, instruction: &Instruction) -> ToEnd {
+        let val = match instruction {
+            Instruction::Acc(val) => *val as i64,
+            _ => 0,
+        };
+        ToEnd {
+            acc: val + self.acc,
+            min: (val + self.min).min(0),
+            max: (val + self.max).max(0),
+        }
+    }
+
+    /// The accumulator at the end for a run arriving with `acc`, unless it overflows on the
+    /// way.
+    fn end_acc(self, acc: i32) -> Option<i32> {
+        let acc = acc as i64;
+        if acc + self.min < i32::MIN as i64 || acc + self.max > i32::MAX as i64 {
+            None
+        } else {
+            Some((acc + self.acc) as i32)
+        }
+    }
+}
+
+/// Which pcs run to the end of the program unchanged, found by walking the control flow
+/// backwards from the end, and what each does to the accumulator on the way.
+fn terminating(program: &[Instruction]) -> Vec<Option<ToEnd>> {
+    let end = program.len();
+    let mut predecessors = vec![Vec::new(); end + 1];
+    for (pc, instruction) in program.iter().enumerate() {
+        let next = instruction.next(pc);
+        if (0..=end as i64).contains(&next) {
+            predecessors[next as usize].push(pc);
+        }
+    }
+
+    let mut to_end = vec![None; end + 1];
+    to_end[end] = Some(ToEnd::default());
+    let mut queue = VecDeque::from(vec![(end, ToEnd::default())]);
+    while let Some((pc, rest)) = queue.pop_front() {
+        for &prev in &predecessors[pc] {
+            let from_prev = rest.after(&program[prev]);
+            to_end[prev] = Some(from_prev);
+            queue.push_back((prev, from_prev));
+        }
+    }
+    to_end
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fix_bug() {
+        assert_eq!(
+            fix_bug(vec![
+                Instruction::Nop(0),
+                Instruction::Acc(1),
+                Instruction::Jmp(4),
+                Instruction::Acc(3),
+                Instruction::Jmp(-3),
+                Instruction::Acc(-99),
+                Instruction::Acc(1),
+                Instruction::Jmp(-4),
+                Instruction::Acc(6)
+            ]),
+            Ok(8)
+        )
+    }
+
+    #[test]
+    fn test_fix_bug_unfixable() {
+        assert_eq!(
+            fix_bug(vec![Instruction::Jmp(0), Instruction::Jmp(0)]),
+            Err(Repair::Unfixable)
+        )
+    }
+
+    #[test]
+    fn test_repair_extended() {
+        assert_eq!(
+            repair(&[Instruction::Acc(1), Instruction::Jnz(-1)]),
+            Repair::Unsupported
+        )
+    }
+
+    #[test]
+    fn test_repair() {
+        assert_eq!(
+            repair(&[
+                Instruction::Nop(0),
+                Instruction::Acc(1),
+                Instruction::Jmp(4),
+                Instruction::Acc(3),
+                Instruction::Jmp(-3),
+                Instruction::Acc(-99),
+                Instruction::Acc(1),
+                Instruction::Jmp(-4),
+                Instruction::Acc(6)
+            ]),
+            Repair::Fixed(Fix {
+                pc: 7,
+                instruction: Instruction::Nop(-4),
+                acc: 8
+            })
+        )
+    }
+
+    #[test]
+    fn test_repair_not_needed() {
+        assert_eq!(
+            repair(&[
+                Instruction::Acc(2),
+                Instruction::Jmp(2),
+                Instruction::Acc(5)
+            ]),
+            Repair::NotNeeded { acc: 2 }
+        )
+    }
+
+    #[test]
+    fn test_repair_unfixable() {
+        assert_eq!(
+            repair(&[
+                Instruction::Jmp(2),
+                Instruction::Acc(1),
+                Instruction::Jmp(-2),
+                Instruction::Jmp(0)
+            ]),
+            Repair::Unfixable
+        )
+    }
+
+    #[test]
+    fn test_repair_out_of_bounds() {
+        assert_eq!(
+            repair(&[Instruction::Jmp(5), Instruction::Acc(1)]),
+            Repair::Fixed(Fix {
+                pc: 0,
+                instruction: Instruction::Nop(5),
+                acc: 1
+            })
+        )
+    }
+
+    #[test]
+    fn test_repair_ambiguous() {
+        assert_eq!(
+            repair(&[
+                Instruction::Nop(2),
+                Instruction::Jmp(0),
+                Instruction::Acc(1)
+            ]),
+            Repair::Ambiguous(vec![
+                Fix {
+                    pc: 0,
+                    instruction: Instruction::Jmp(2),
+                    acc: 1
+                },
+                Fix {
+                    pc: 1,
+                    instruction: Instruction::Nop(0),
+                    acc: 1
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn test_display_ambiguous() {
+        assert_eq!(
+            Repair::Ambiguous(vec![
+                Fix {
+                    pc: 0,
+                    instruction: Instruction::Jmp(2),
+                    acc: 1
+                },
+                Fix {
+                    pc: 1,
+                    instruction: Instruction::Nop(0),
+                    acc: 1
+                }
+            ])
+            .to_string(),
+            "2 different swaps make the program terminate:\n  changing 0 to jmp +2 (acc 1)\n  changing 1 to nop +0 (acc 1)"
+        )
+    }
+
+    #[test]
+    fn test_repair_overflow() {
+        assert_eq!(
+            repair(&[
+                Instruction::Acc(i32::MAX),
+                Instruction::Acc(1),
+                Instruction::Jmp(-2)
+            ]),
+            Repair::Overflow {
+                pc: 1,
+                acc: i32::MAX
+            }
+        )
+    }
+
+    #[test]
+    fn test_repair_overflow_before_the_end() {
+        assert_eq!(
+            repair(&[
+                Instruction::Acc(i32::MAX),
+                Instruction::Acc(1),
+                Instruction::Acc(-5)
+            ]),
+            Repair::Overflow {
+                pc: 1,
+                acc: i32::MAX
+            }
+        )
+    }
+}
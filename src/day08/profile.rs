@@ -0,0 +1,193 @@
+//! Profiling runs of handheld programs: how often each instruction runs and the shape of the
+//! loop the program gets stuck in.
+
+use crate::day08::vm::{ExecutionOutcome, Vm};
+use crate::day08::Instruction;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Profile {
+    pub outcome: ExecutionOutcome,
+    /// How many times the instruction at each pc ran.
+    pub hits: Vec<usize>,
+}
+
+impl Profile {
+    /// The pcs run on each trip round the loop, in order, or none if the program doesn't loop.
+    pub fn cycle_sequence(&self) -> &[usize] {
+        match &self.outcome {
+            ExecutionOutcome::Looped { trace, cycle, .. } => &trace[trace.len() - cycle..],
+            _ => &[],
+        }
+    }
+
+    /// How many instructions ran in all.
+    pub fn executed(&self) -> usize {
+        self.hits.iter().sum()
+    }
+}
+
+/// Run a program with `Vm::run`, counting how often each instruction runs along the way.
+pub fn profile(program: Vec<Instruction>) -> Profile {
+    let mut hits = vec![0; program.len()];
+    let outcome = Vm::new(program).run_with(|vm| hits[vm.pc()] += 1);
+    Profile { outcome, hits }
+}
+
+/// The program with each instruction's hit count, instructions in the loop marked with `*`,
+/// followed by a summary of the run.
+pub fn listing(program: &[Instruction], profile: &Profile) -> String {
+    let cycle = profile.cycle_sequence();
+    let mut in_cycle = vec![false; program.len()];
+    for &pc in cycle {
+        in_cycle[pc] = true;
+    }
+    let width = program.len().saturating_sub(1).to_string().len();
+    let mut lines = vec![format!(
+        "{:>6}   {:>width$}  instruction",
+        "hits",
+        "pc",
+        width = width
+    )];
+    for (pc, instruction) in program.iter().enumerate() {
+        let marker = if in_cycle[pc] { '*' } else { ' ' };
+        lines.push(format!(
+            "{:>6} {} {:>width$}  {}",
+            profile.hits[pc],
+            marker,
+            pc,
+            instruction,
+            width = width
+        ));
+    }
+    let executed = profile.executed();
+    lines.push(String::new());
+    lines.push(match &profile.outcome {
+        ExecutionOutcome::Terminated { acc } => {
+            format!(
+                "terminated with acc {} after {} instructions",
+                acc, executed
+            )
+        }
+        ExecutionOutcome::OutOfBounds { pc } => {
+            format!(
+                "jumped out of bounds to {} after {} instructions",
+                pc, executed
+            )
+        }
+        ExecutionOutcome::Overflow {
+            pc,
+            register,
+            value,
+        } => format!(
+            "overflowed at {} with {} {} after {} instructions",
+            pc, register, value, executed
+        ),
+        ExecutionOutcome::StepLimit { pc, acc } => format!(
+            "gave up at {} with acc {} after {} instructions without ending or looping",
+            pc, acc, executed
+        ),
+        ExecutionOutcome::Looped { acc, pc, .. } => format!(
+            "looped back to {} with acc {} after {} instructions; each trip round runs {}:\n  {}",
+            pc,
+            acc,
+            executed,
+            cycle.len(),
+            cycle
+                .iter()
+                .chain(std::iter::once(pc))
+                .map(|pc| pc.to_string())
+                .collect::<Vec<String>>()
+                .join(" -> ")
+        ),
+    });
+    let unreached = profile.hits.iter().filter(|hits| **hits == 0).count();
+    lines.push(format!(
+        "{} of {} instructions never ran",
+        unreached,
+        program.len()
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Instruction> {
+        vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ]
+    }
+
+    #[test]
+    fn test_profile_loop() {
+        let profile = profile(example());
+        assert_eq!(
+            (profile.hits.clone(), profile.cycle_sequence()),
+            (vec![1, 1, 1, 1, 1, 0, 1, 1, 0], &[1, 2, 6, 7, 3, 4][..])
+        )
+    }
+
+    #[test]
+    fn test_profile_outcome() {
+        assert_eq!(
+            profile(example()).outcome,
+            ExecutionOutcome::Looped {
+                acc: 5,
+                pc: 1,
+                trace: vec![0, 1, 2, 6, 7, 3, 4],
+                cycle: 6
+            }
+        )
+    }
+
+    #[test]
+    fn test_profile_extended_hits() {
+        let profile = profile(vec![
+            Instruction::Acc(3),
+            Instruction::Acc(-1),
+            Instruction::Jpos(-1),
+        ]);
+        assert_eq!(
+            (profile.hits.clone(), profile.cycle_sequence()),
+            (vec![1, 3, 3], &[][..])
+        )
+    }
+
+    #[test]
+    fn test_listing() {
+        let program = example();
+        assert_eq!(
+            listing(&program, &profile(program.clone())),
+            "  hits   pc  instruction\n\
+             \x20    1   0  nop +0\n\
+             \x20    1 * 1  acc +1\n\
+             \x20    1 * 2  jmp +4\n\
+             \x20    1 * 3  acc +3\n\
+             \x20    1 * 4  jmp -3\n\
+             \x20    0   5  acc -99\n\
+             \x20    1 * 6  acc +1\n\
+             \x20    1 * 7  jmp -4\n\
+             \x20    0   8  acc +6\n\
+             \n\
+             looped back to 1 with acc 5 after 7 instructions; each trip round runs 6:\n\
+             \x20 1 -> 2 -> 6 -> 7 -> 3 -> 4 -> 1\n\
+             2 of 9 instructions never ran"
+        )
+    }
+
+    #[test]
+    fn test_listing_terminated() {
+        let program = vec![Instruction::Acc(2), Instruction::Nop(0)];
+        assert!(listing(&program, &profile(program.clone()))
+            .ends_with("terminated with acc 2 after 2 instructions\n0 of 2 instructions never ran"))
+    }
+}
@@ -0,0 +1,413 @@
+use crate::day08::{Instruction, Isa};
+use std::collections::HashMap;
+use std::fmt;
+
+/// How a run of a program came to an end.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ExecutionOutcome {
+    /// The program tried to execute the instruction just after its last one.
+    Terminated { acc: i32 },
+    /// The instruction at `pc` was about to run for a second time in the same state.  `trace`
+    /// holds the pc of every instruction executed before that, in order, and `cycle` how many of
+    /// them run on each trip round the loop.
+    Looped {
+        acc: i32,
+        pc: usize,
+        trace: Vec<usize>,
+        cycle: usize,
+    },
+    /// A jump went somewhere other than an instruction or the end of the program.
+    OutOfBounds { pc: i64 },
+    /// The instruction at `pc` would have taken `register`, holding `value`, past what it can
+    /// hold.
+    Overflow {
+        pc: usize,
+        register: Register,
+        value: i32,
+    },
+    /// The program ran for `STEP_LIMIT` instructions without ending or repeating a state, and
+    /// was about to run the one at `pc`.
+    StepLimit { pc: usize, acc: i32 },
+}
+
+/// The VM's registers: the accumulator, and the second register of the extended instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    Acc,
+    B,
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Register::Acc => write!(f, "acc"),
+            Register::B => write!(f, "b"),
+        }
+    }
+}
+
+/// How many instructions a program may run before giving up on it.  Programs using only the
+/// puzzle's instructions end or loop within one step per instruction, but the registers of
+/// other programs can keep changing for much longer without ever repeating.
+pub const STEP_LIMIT: usize = 1_000_000;
+
+/// Remembers the states a program has been in, to spot it going round a loop.  Control flow in
+/// the puzzle's instruction set only depends on pc, so for those programs pc alone is enough;
+/// otherwise the registers count too.
+pub struct LoopDetector {
+    strict: bool,
+    /// The step each state was first seen at.
+    seen: HashMap<(usize, i32, i32), usize>,
+    steps: usize,
+}
+
+impl LoopDetector {
+    pub fn new(program: &[Instruction]) -> LoopDetector {
+        LoopDetector {
+            strict: program
+                .iter()
+                .all(|instruction| instruction.isa() == Isa::Strict),
+            seen: HashMap::new(),
+            steps: 0,
+        }
+    }
+
+    /// Record the state `vm` is in before its next step.  If it has been in that state before,
+    /// returns how many steps ago, which is the length of the loop it's in.
+    pub fn revisits(&mut self, vm: &Vm) -> Option<usize> {
+        let state = if self.strict {
+            (vm.pc, 0, 0)
+        } else {
+            (vm.pc, vm.acc, vm.b)
+        };
+        let step = self.steps;
+        self.steps += 1;
+        self.seen.insert(state, step).map(|first| step - first)
+    }
+
+    /// Whether `STEP_LIMIT` states have been recorded already.
+    pub fn out_of_steps(&self) -> bool {
+        self.steps >= STEP_LIMIT
+    }
+}
+
+/// The handheld game console: a program, its program counter and its accumulator.  Programs
+/// using the extended instruction set also have a second register, `b`, and an output.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Vm {
+    program: Vec<Instruction>,
+    pc: usize,
+    acc: i32,
+    b: i32,
+    output: Vec<i32>,
+}
+
+impl Vm {
+    pub fn new(program: Vec<Instruction>) -> Vm {
+        Vm {
+            program,
+            pc: 0,
+            acc: 0,
+            b: 0,
+            output: Vec::new(),
+        }
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn acc(&self) -> i32 {
+        self.acc
+    }
+
+    pub fn b(&self) -> i32 {
+        self.b
+    }
+
+    /// Everything `out` has written, in order.
+    pub fn output(&self) -> &[i32] {
+        &self.output
+    }
+
+    /// Replace the instruction at `pc`, returning the one that was there.
+    pub fn patch(&mut self, pc: usize, instruction: Instruction) -> Instruction {
+        std::mem::replace(&mut self.program[pc], instruction)
+    }
+
+    /// Execute the instruction at the program counter.  Returns how the program ended if it can't
+    /// go any further, either before or because of this instruction.
+    pub fn step(&mut self) -> Option<ExecutionOutcome> {
+        let instruction = match self.program.get(self.pc) {
+            Some(instruction) => instruction,
+            None => return Some(ExecutionOutcome::Terminated { acc: self.acc }),
+        };
+        trace!("pc:{:?} acc:{:?} {:?}", self.pc, self.acc, instruction);
+        let jump = match *instruction {
+            Instruction::Acc(val) => match self.acc.checked_add(val) {
+                Some(acc) => {
+                    self.acc = acc;
+                    false
+                }
+                None => return Some(self.overflow(Register::Acc)),
+            },
+            Instruction::Jmp(_) => true,
+            Instruction::Nop(_) => false,
+            Instruction::Jz(_) => self.acc == 0,
+            Instruction::Jnz(_) => self.acc != 0,
+            Instruction::Jneg(_) => self.acc < 0,
+            Instruction::Jpos(_) => self.acc > 0,
+            Instruction::Mul(val) => match self.acc.checked_mul(val) {
+                Some(acc) => {
+                    self.acc = acc;
+                    false
+                }
+                None => return Some(self.overflow(Register::Acc)),
+            },
+            Instruction::AccB(val) => match self.b.checked_add(val) {
+                Some(b) => {
+                    self.b = b;
+                    false
+                }
+                None => return Some(self.overflow(Register::B)),
+            },
+            Instruction::Swap(_) => {
+                std::mem::swap(&mut self.acc, &mut self.b);
+                false
+            }
+            Instruction::Out(_) => {
+                self.output.push(self.acc);
+                false
+            }
+        };
+        let next = if jump {
+            self.pc as i64 + instruction.argument() as i64
+        } else {
+            self.pc as i64 + 1
+        };
+        if next < 0 || next > self.program.len() as i64 {
+            return Some(ExecutionOutcome::OutOfBounds { pc: next });
+        }
+        self.pc = next as usize;
+        if self.pc == self.program.len() {
+            return Some(ExecutionOutcome::Terminated { acc: self.acc });
+        }
+        None
+    }
+
+    fn overflow(&self, register: Register) -> ExecutionOutcome {
+        ExecutionOutcome::Overflow {
+            pc: self.pc,
+            register,
+            value: match register {
+                Register::Acc => self.acc,
+                Register::B => self.b,
+            },
+        }
+    }
+
+    /// Run until the program terminates, jumps out of bounds, overflows, is about to execute an
+    /// instruction for the second time in the same state or runs out of steps.
+    pub fn run(&mut self) -> ExecutionOutcome {
+        self.run_with(|_| ())
+    }
+
+    /// Run as `run` does, showing `visit` the machine before each instruction it executes.
+    pub fn run_with(&mut self, mut visit: impl FnMut(&Vm)) -> ExecutionOutcome {
+        let mut detector = LoopDetector::new(&self.program);
+        let mut trace = Vec::new();
+        loop {
+            if detector.out_of_steps() {
+                return ExecutionOutcome::StepLimit {
+                    pc: self.pc,
+                    acc: self.acc,
+                };
+            }
+            if let Some(cycle) = detector.revisits(self) {
+                return ExecutionOutcome::Looped {
+                    acc: self.acc,
+                    pc: self.pc,
+                    trace,
+                    cycle,
+                };
+            }
+            visit(self);
+            trace.push(self.pc);
+            if let Some(outcome) = self.step() {
+                return outcome;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Instruction> {
+        vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ]
+    }
+
+    #[test]
+    fn test_run_looped() {
+        assert_eq!(
+            Vm::new(example()).run(),
+            ExecutionOutcome::Looped {
+                acc: 5,
+                pc: 1,
+                trace: vec![0, 1, 2, 6, 7, 3, 4],
+                cycle: 6
+            }
+        )
+    }
+
+    #[test]
+    fn test_run_terminated() {
+        let mut program = example();
+        program[7] = Instruction::Nop(-4);
+        assert_eq!(
+            Vm::new(program).run(),
+            ExecutionOutcome::Terminated { acc: 8 }
+        )
+    }
+
+    #[test]
+    fn test_run_jump_before_start() {
+        assert_eq!(
+            Vm::new(vec![Instruction::Acc(1), Instruction::Jmp(-2)]).run(),
+            ExecutionOutcome::OutOfBounds { pc: -1 }
+        )
+    }
+
+    #[test]
+    fn test_run_jump_past_end() {
+        assert_eq!(
+            Vm::new(vec![Instruction::Jmp(3), Instruction::Nop(0)]).run(),
+            ExecutionOutcome::OutOfBounds { pc: 3 }
+        )
+    }
+
+    #[test]
+    fn test_run_empty() {
+        assert_eq!(
+            Vm::new(vec![]).run(),
+            ExecutionOutcome::Terminated { acc: 0 }
+        )
+    }
+
+    #[test]
+    fn test_run_extended() {
+        // Count down from 3, writing out each value, with b counting the steps.
+        let mut vm = Vm::new(vec![
+            Instruction::Acc(3),
+            Instruction::Out(0),
+            Instruction::Acc(-1),
+            Instruction::AccB(1),
+            Instruction::Jpos(-3),
+            Instruction::Mul(10),
+            Instruction::Jz(2),
+            Instruction::Out(0),
+        ]);
+        assert_eq!(
+            (vm.run(), vm.output().to_vec(), vm.b()),
+            (ExecutionOutcome::Terminated { acc: 0 }, vec![3, 2, 1], 3)
+        )
+    }
+
+    #[test]
+    fn test_run_extended_loop() {
+        assert_eq!(
+            Vm::new(vec![Instruction::Swap(0), Instruction::Jmp(-1)]).run(),
+            ExecutionOutcome::Looped {
+                acc: 0,
+                pc: 0,
+                trace: vec![0, 1],
+                cycle: 2
+            }
+        )
+    }
+
+    #[test]
+    fn test_run_overflow() {
+        assert_eq!(
+            Vm::new(vec![
+                Instruction::Acc(3),
+                Instruction::Mul(3),
+                Instruction::Jmp(-1)
+            ])
+            .run(),
+            ExecutionOutcome::Overflow {
+                pc: 1,
+                register: Register::Acc,
+                value: 1_162_261_467
+            }
+        )
+    }
+
+    #[test]
+    fn test_run_overflow_b() {
+        assert_eq!(
+            Vm::new(vec![
+                Instruction::Acc(7),
+                Instruction::AccB(i32::MAX),
+                Instruction::AccB(1)
+            ])
+            .run(),
+            ExecutionOutcome::Overflow {
+                pc: 2,
+                register: Register::B,
+                value: i32::MAX
+            }
+        )
+    }
+
+    #[test]
+    fn test_run_step_limit() {
+        // Never repeats a state, since acc keeps growing.
+        assert_eq!(
+            Vm::new(vec![
+                Instruction::AccB(0),
+                Instruction::Acc(1),
+                Instruction::Jmp(-1)
+            ])
+            .run(),
+            ExecutionOutcome::StepLimit {
+                pc: 2,
+                acc: 500_000
+            }
+        )
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut vm = Vm::new(vec![
+            Instruction::Acc(5),
+            Instruction::Swap(0),
+            Instruction::Out(0),
+        ]);
+        vm.run();
+        assert_eq!((vm.output().to_vec(), vm.b()), (vec![0], 5))
+    }
+
+    #[test]
+    fn test_step() {
+        let mut vm = Vm::new(example());
+        vm.step();
+        vm.step();
+        assert_eq!((vm.pc(), vm.acc()), (2, 1))
+    }
+}
@@ -0,0 +1,40 @@
+pub fn xmas_corruption(cypher_text: &[u64], preamble_length: usize) -> Option<u64> {
+    for target_i in preamble_length..cypher_text.len() {
+        let target = cypher_text[target_i];
+        let mut found = false;
+        for i1 in (target_i - preamble_length)..target_i {
+            for i2 in i1 + 1..target_i {
+                if cypher_text[i1] + cypher_text[i2] == target {
+                    found = true;
+                    break;
+                }
+            }
+            if found {
+                break;
+            }
+        }
+        if !found {
+            return Some(target);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_xmas_corruption() {
+        assert_eq!(
+            xmas_corruption(
+                &vec![
+                    35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277,
+                    309, 576
+                ],
+                5
+            ),
+            Some(127)
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use std::cmp;
+
+pub fn xmas_fix(cypher_text: &[u64], corrupted_value: u64) -> Option<u64> {
+    for start_i in 0..cypher_text.len() {
+        let mut sum = cypher_text[start_i];
+        let mut min = cypher_text[start_i];
+        let mut max = cypher_text[start_i];
+        for value in &cypher_text[start_i + 1..] {
+            sum += value;
+            min = cmp::min(min, *value);
+            max = cmp::max(max, *value);
+            if sum == corrupted_value {
+                return Some(min + max);
+            } else if sum > corrupted_value {
+                break;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_xmas_fix() {
+        assert_eq!(
+            xmas_fix(
+                &vec![
+                    35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277,
+                    309, 576
+                ],
+                127
+            ),
+            Some(62)
+        )
+    }
+}
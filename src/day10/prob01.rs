@@ -0,0 +1,43 @@
+pub fn chain_adapters(mut adapters: Vec<u8>) -> Result<u32, String> {
+    adapters.sort();
+    let device = adapters.last().ok_or_else(|| "no adapters".to_string())? + 3;
+    adapters.push(device);
+    let mut counts = [0u32; 4];
+    let mut current = 0;
+    for adapter in adapters {
+        let diff = adapter - current;
+        if !(1..=3).contains(&diff) {
+            return Err(format!(
+                "unable to complete chain, adapter {} is too large",
+                adapter
+            ));
+        }
+        counts[diff as usize] += 1;
+        current = adapter;
+    }
+    Ok(counts[1] * counts[3])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chain_adapters1() {
+        assert_eq!(
+            chain_adapters(vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4]),
+            Ok(35)
+        )
+    }
+
+    #[test]
+    fn test_chain_adapters2() {
+        assert_eq!(
+            chain_adapters(vec![
+                28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25,
+                35, 8, 17, 7, 9, 4, 2, 34, 10, 3
+            ]),
+            Ok(220)
+        )
+    }
+}
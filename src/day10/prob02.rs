@@ -0,0 +1,50 @@
+pub fn chain_adapters(mut adapters: Vec<u8>) -> Option<u64> {
+    adapters.sort();
+    let max_adapter = adapters.last()? + 3;
+    adapters.insert(0, 0);
+    adapters.push(max_adapter);
+
+    let adapters_bool = {
+        let mut adapters_bool: Vec<bool> = vec![false; *adapters.last().unwrap() as usize + 1];
+        for adapter in adapters.iter() {
+            adapters_bool[*adapter as usize] = true;
+        }
+        adapters_bool
+    };
+
+    let mut chain: Vec<u64> = vec![0; max_adapter as usize + 1];
+    chain[0] = 1;
+    for adapter in adapters {
+        let adapter = adapter as usize;
+        for next_adapter in adapter + 1..adapter + 3 + 1 {
+            if next_adapter < adapters_bool.len() && adapters_bool[next_adapter] {
+                chain[next_adapter] += chain[adapter];
+            }
+        }
+    }
+    chain.last().copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chain_adapters1() {
+        assert_eq!(
+            chain_adapters(vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4]),
+            Some(8)
+        )
+    }
+
+    #[test]
+    fn test_chain_adapters2() {
+        assert_eq!(
+            chain_adapters(vec![
+                28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25,
+                35, 8, 17, 7, 9, 4, 2, 34, 10, 3
+            ]),
+            Some(19208)
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use crate::automaton::Cell;
+use crate::grid::{Glyph, Grid};
+use crate::parse::ParseError;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Grid<Space>, ParseError> {
+    Grid::parse(input)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Space {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl Glyph for Space {
+    const GLYPHS: &'static str = "'.', 'L' or '#'";
+
+    fn from_glyph(c: char) -> Option<Space> {
+        match c {
+            '.' => Some(Space::Floor),
+            'L' => Some(Space::EmptySeat),
+            '#' => Some(Space::OccupiedSeat),
+            _ => None,
+        }
+    }
+
+    fn glyph(&self) -> char {
+        match self {
+            Space::Floor => '.',
+            Space::EmptySeat => 'L',
+            Space::OccupiedSeat => '#',
+        }
+    }
+}
+
+impl Cell for Space {
+    fn is_live(&self) -> bool {
+        *self == Space::OccupiedSeat
+    }
+}
+
+/// What a space becomes given how many occupied seats it can see: floor never changes, an empty
+/// seat is taken if none are occupied, and an occupied seat is left once `tolerance` or more
+/// are.
+pub fn next_space(space: &Space, occupied: usize, tolerance: usize) -> Space {
+    match space {
+        Space::Floor => Space::Floor,
+        Space::EmptySeat if occupied == 0 => Space::OccupiedSeat,
+        Space::OccupiedSeat if occupied >= tolerance => Space::EmptySeat,
+        Space::EmptySeat => Space::EmptySeat,
+        Space::OccupiedSeat => Space::OccupiedSeat,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("#.LL.L#.##\n"),
+            Ok(Grid::from_rows(vec![vec![
+                Space::OccupiedSeat,
+                Space::Floor,
+                Space::EmptySeat,
+                Space::EmptySeat,
+                Space::Floor,
+                Space::EmptySeat,
+                Space::OccupiedSeat,
+                Space::Floor,
+                Space::OccupiedSeat,
+                Space::OccupiedSeat
+            ]]))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_char() {
+        assert_eq!(
+            parse("#.L?"),
+            Err(ParseError::new(1, 4, "?", "expected '.', 'L' or '#'"))
+        )
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(parse("#.L\nL.#\n").unwrap().to_string(), "#.L\nL.#")
+    }
+}
@@ -0,0 +1,106 @@
+use crate::automaton::{Adjacent, Automaton, Storage};
+use crate::day11::{next_space, Space};
+use crate::grid::Grid;
+
+/// Seats are left once four of the adjacent ones are occupied.
+fn rule(space: &Space, occupied: usize) -> Space {
+    next_space(space, occupied, 4)
+}
+
+pub fn find_stable(spaces: Grid<Space>) -> usize {
+    let automaton = Automaton::new(Adjacent::of(&spaces), rule);
+    automaton.run_until_stable(spaces).0.population()
+}
+
+pub fn step(spaces: &Grid<Space>) -> Grid<Space> {
+    Automaton::new(Adjacent::of(spaces), rule).step(spaces)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn grid(rows: &[&str]) -> Grid<Space> {
+        Grid::parse(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_step1() {
+        assert_eq!(
+            step(&grid(&[
+                "L.LL.LL.LL",
+                "LLLLLLL.LL",
+                "L.L.L..L..",
+                "LLLL.LL.LL",
+                "L.LL.LL.LL",
+                "L.LLLLL.LL",
+                "..L.L.....",
+                "LLLLLLLLLL",
+                "L.LLLLLL.L",
+                "L.LLLLL.LL"
+            ])),
+            grid(&[
+                "#.##.##.##",
+                "#######.##",
+                "#.#.#..#..",
+                "####.##.##",
+                "#.##.##.##",
+                "#.#####.##",
+                "..#.#.....",
+                "##########",
+                "#.######.#",
+                "#.#####.##"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_step2() {
+        assert_eq!(
+            step(&grid(&[
+                "#.##.##.##",
+                "#######.##",
+                "#.#.#..#..",
+                "####.##.##",
+                "#.##.##.##",
+                "#.#####.##",
+                "..#.#.....",
+                "##########",
+                "#.######.#",
+                "#.#####.##"
+            ])),
+            grid(&[
+                "#.LL.L#.##",
+                "#LLLLLL.L#",
+                "L.L.L..L..",
+                "#LLL.LL.L#",
+                "#.LL.LL.LL",
+                "#.LLLL#.##",
+                "..L.L.....",
+                "#LLLLLLLL#",
+                "#.LLLLLL.L",
+                "#.#LLLL.##"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_find_stable() {
+        assert_eq!(
+            find_stable(grid(&[
+                "L.LL.LL.LL",
+                "LLLLLLL.LL",
+                "L.L.L..L..",
+                "LLLL.LL.LL",
+                "L.LL.LL.LL",
+                "L.LLLLL.LL",
+                "..L.L.....",
+                "LLLLLLLLLL",
+                "L.LLLLLL.L",
+                "L.LLLLL.LL"
+            ])),
+            37
+        )
+    }
+}
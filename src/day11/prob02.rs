@@ -0,0 +1,222 @@
+use crate::automaton::{Automaton, Storage, Topology};
+use crate::day11::{next_space, Space};
+use crate::grid::{Grid, Pos, NEIGHBOURS8};
+
+/// Seats are left once five of the visible ones are occupied.
+fn rule(space: &Space, occupied: usize) -> Space {
+    next_space(space, occupied, 5)
+}
+
+/// Each space's neighbours are the seats visible from it.  Floor never changes, so neither do
+/// these.
+struct Sightlines(Grid<Vec<Pos>>);
+
+impl Sightlines {
+    fn of(spaces: &Grid<Space>) -> Sightlines {
+        Sightlines(spaces.map(|pos, _| visible_seats(spaces, pos)))
+    }
+}
+
+impl Topology<Pos> for Sightlines {
+    fn neighbours(&self, pos: &Pos) -> Vec<Pos> {
+        self.0[*pos].clone()
+    }
+}
+
+pub fn find_stable(spaces: Grid<Space>) -> usize {
+    Automaton::new(Sightlines::of(&spaces), rule)
+        .run_until_stable(spaces)
+        .0
+        .population()
+}
+
+/// The first seat seen in each direction from `pos`, looking past any floor.
+fn visible_seats(spaces: &Grid<Space>, pos: Pos) -> Vec<Pos> {
+    NEIGHBOURS8
+        .iter()
+        .filter_map(|&direction| {
+            let seen = spaces
+                .ray(pos, direction)
+                .find(|pos| spaces[*pos] != Space::Floor);
+            trace!("  {:?} -> {:?}", direction, seen.map(|pos| &spaces[pos]));
+            seen
+        })
+        .collect()
+}
+
+pub fn step(spaces: &Grid<Space>) -> Grid<Space> {
+    Automaton::new(Sightlines::of(spaces), rule).step(spaces)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn grid(rows: &[&str]) -> Grid<Space> {
+        Grid::parse(&rows.join("\n")).unwrap()
+    }
+
+    /// How many occupied seats the automaton sees from `pos`.
+    fn occupied_neighbours(spaces: &Grid<Space>, pos: Pos) -> usize {
+        Sightlines::of(spaces)
+            .neighbours(&pos)
+            .into_iter()
+            .filter(|pos| spaces[*pos] == Space::OccupiedSeat)
+            .count()
+    }
+
+    #[test]
+    fn test_occupied_neighbours1() {
+        assert_eq!(
+            occupied_neighbours(
+                &grid(&[
+                    ".......#.",
+                    "...#.....",
+                    ".#.......",
+                    ".........",
+                    "..#L....#",
+                    "....#....",
+                    ".........",
+                    "#........",
+                    "...#....."
+                ]),
+                (4, 3)
+            ),
+            8
+        )
+    }
+
+    #[test]
+    fn test_occupied_neighbours2() {
+        assert_eq!(
+            occupied_neighbours(
+                &grid(&[".............", ".L.L.#.#.#.#.", "............."]),
+                (1, 1)
+            ),
+            0
+        )
+    }
+
+    #[test]
+    fn test_occupied_neighbours3() {
+        assert_eq!(
+            occupied_neighbours(
+                &grid(&[
+                    ".##.##.", "#.#.#.#", "##...##", "...L...", "##...##", "#.#.#.#", ".##.##."
+                ]),
+                (3, 3)
+            ),
+            0
+        )
+    }
+
+    #[test]
+    fn test_step1() {
+        assert_eq!(
+            step(&grid(&[
+                "L.LL.LL.LL",
+                "LLLLLLL.LL",
+                "L.L.L..L..",
+                "LLLL.LL.LL",
+                "L.LL.LL.LL",
+                "L.LLLLL.LL",
+                "..L.L.....",
+                "LLLLLLLLLL",
+                "L.LLLLLL.L",
+                "L.LLLLL.LL"
+            ])),
+            grid(&[
+                "#.##.##.##",
+                "#######.##",
+                "#.#.#..#..",
+                "####.##.##",
+                "#.##.##.##",
+                "#.#####.##",
+                "..#.#.....",
+                "##########",
+                "#.######.#",
+                "#.#####.##"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_step2() {
+        assert_eq!(
+            step(&grid(&[
+                "#.##.##.##",
+                "#######.##",
+                "#.#.#..#..",
+                "####.##.##",
+                "#.##.##.##",
+                "#.#####.##",
+                "..#.#.....",
+                "##########",
+                "#.######.#",
+                "#.#####.##"
+            ])),
+            grid(&[
+                "#.LL.LL.L#",
+                "#LLLLLL.LL",
+                "L.L.L..L..",
+                "LLLL.LL.LL",
+                "L.LL.LL.LL",
+                "L.LLLLL.LL",
+                "..L.L.....",
+                "LLLLLLLLL#",
+                "#.LLLLLL.L",
+                "#.LLLLL.L#"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_step3() {
+        assert_eq!(
+            step(&grid(&[
+                "#.LL.LL.L#",
+                "#LLLLLL.LL",
+                "L.L.L..L..",
+                "LLLL.LL.LL",
+                "L.LL.LL.LL",
+                "L.LLLLL.LL",
+                "..L.L.....",
+                "LLLLLLLLL#",
+                "#.LLLLLL.L",
+                "#.LLLLL.L#"
+            ])),
+            grid(&[
+                "#.L#.##.L#",
+                "#L#####.LL",
+                "L.#.#..#..",
+                "##L#.##.##",
+                "#.##.#L.##",
+                "#.#####.#L",
+                "..#.#.....",
+                "LLL####LL#",
+                "#.L#####.L",
+                "#.L####.L#"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_find_stable() {
+        assert_eq!(
+            find_stable(grid(&[
+                "L.LL.LL.LL",
+                "LLLLLLL.LL",
+                "L.L.L..L..",
+                "LLLL.LL.LL",
+                "L.LL.LL.LL",
+                "L.LLLLL.LL",
+                "..L.L.....",
+                "LLLLLLLLLL",
+                "L.LLLLLL.L",
+                "L.LLLLL.LL"
+            ])),
+            26
+        )
+    }
+}
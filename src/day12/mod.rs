@@ -0,0 +1,194 @@
+use crate::parse::{self, ParseError};
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| Command::from_line(line).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Command>, ParseError>>()
+}
+
+pub fn substring(value: &str, start: usize, end: usize) -> String {
+    value
+        .chars()
+        .skip(start)
+        .take(end - start)
+        .collect::<String>()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Command {
+    Move(Direction, u16),
+    MoveForward(u16),
+    Turn(Turn, u16),
+}
+
+impl Command {
+    pub fn from_line(line: &str) -> Result<Command, ParseError> {
+        let (raw_command, raw_amount) =
+            line.split_at(line.chars().next().map_or(0, char::len_utf8));
+        let amount = parse::number::<u16>(line, raw_amount)?;
+        match raw_command {
+            "N" => Ok(Command::Move(Direction::North, amount)),
+            "S" => Ok(Command::Move(Direction::South, amount)),
+            "E" => Ok(Command::Move(Direction::East, amount)),
+            "W" => Ok(Command::Move(Direction::West, amount)),
+            "L" | "R" if !amount.is_multiple_of(90) => Err(ParseError::within(
+                line,
+                raw_amount,
+                "expected a turn in multiples of 90 degrees",
+            )),
+            "L" => Ok(Command::Turn(Turn::Left, amount)),
+            "R" => Ok(Command::Turn(Turn::Right, amount)),
+            "F" => Ok(Command::MoveForward(amount)),
+            _ => Err(ParseError::within(
+                line,
+                raw_command,
+                "expected one of 'N', 'S', 'E', 'W', 'L', 'R' or 'F'",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    /// The direction after turning clockwise by `amt_deg` degrees, which should be a multiple
+    /// of 90; any part of a quarter turn left over is ignored.
+    pub fn turn(self, amt_deg: i32) -> Direction {
+        let curr = match self {
+            Direction::North => 0,
+            Direction::East => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+        };
+        match (curr + amt_deg / 90).rem_euclid(4) {
+            0 => Direction::North,
+            1 => Direction::East,
+            2 => Direction::South,
+            _ => Direction::West,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_command_from_line_move_north() {
+        assert_eq!(
+            Command::from_line("N21"),
+            Ok(Command::Move(Direction::North, 21))
+        )
+    }
+
+    #[test]
+    fn test_command_from_line_move_south() {
+        assert_eq!(
+            Command::from_line("S42"),
+            Ok(Command::Move(Direction::South, 42))
+        )
+    }
+
+    #[test]
+    fn test_command_from_line_move_east() {
+        assert_eq!(
+            Command::from_line("E4"),
+            Ok(Command::Move(Direction::East, 4))
+        )
+    }
+
+    #[test]
+    fn test_command_from_line_move_west() {
+        assert_eq!(
+            Command::from_line("W51"),
+            Ok(Command::Move(Direction::West, 51))
+        )
+    }
+
+    #[test]
+    fn test_command_from_line_move_forward() {
+        assert_eq!(Command::from_line("F4"), Ok(Command::MoveForward(4)))
+    }
+
+    #[test]
+    fn test_command_from_line_turn_left() {
+        assert_eq!(Command::from_line("L90"), Ok(Command::Turn(Turn::Left, 90)))
+    }
+
+    #[test]
+    fn test_command_from_line_turn_right() {
+        assert_eq!(
+            Command::from_line("R270"),
+            Ok(Command::Turn(Turn::Right, 270))
+        )
+    }
+
+    #[test]
+    fn test_command_from_line_unknown() {
+        assert_eq!(
+            Command::from_line("X10"),
+            Err(ParseError::new(
+                1,
+                1,
+                "X",
+                "expected one of 'N', 'S', 'E', 'W', 'L', 'R' or 'F'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_command_from_line_bad_amount() {
+        assert_eq!(
+            Command::from_line("F1.5"),
+            Err(ParseError::new(1, 2, "1.5", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_direction_turn() {
+        assert_eq!(Direction::North.turn(90), Direction::East)
+    }
+
+    #[test]
+    fn test_direction_turn_negative_deg() {
+        assert_eq!(Direction::North.turn(-90), Direction::West)
+    }
+
+    #[test]
+    fn test_direction_turn_positive_wrap() {
+        assert_eq!(Direction::West.turn(90), Direction::North)
+    }
+
+    #[test]
+    fn test_command_from_line_bad_turn() {
+        assert_eq!(
+            Command::from_line("R45"),
+            Err(ParseError::new(
+                1,
+                2,
+                "45",
+                "expected a turn in multiples of 90 degrees"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_direction_turn_full_circle() {
+        assert_eq!(Direction::South.turn(-720), Direction::South)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::day12::{Command, Direction, Turn};
+
+pub fn navigate(commands: Vec<Command>) -> u32 {
+    let mut heading = Direction::East;
+    let mut x: i32 = 0;
+    let mut y: i32 = 0;
+    for command in commands {
+        trace!("h:{:?} ({}, {}) <= {:?}", heading, x, y, command);
+        match command {
+            Command::Turn(turn, deg) => match turn {
+                Turn::Left => heading = heading.turn(-(deg as i32)),
+                Turn::Right => heading = heading.turn(deg as i32),
+            },
+            Command::Move(dir, amount) => match dir {
+                Direction::North => y += amount as i32,
+                Direction::East => x += amount as i32,
+                Direction::South => y -= amount as i32,
+                Direction::West => x -= amount as i32,
+            },
+            Command::MoveForward(amount) => match heading {
+                Direction::North => y += amount as i32,
+                Direction::East => x += amount as i32,
+                Direction::South => y -= amount as i32,
+                Direction::West => x -= amount as i32,
+            },
+        }
+    }
+    (x.abs() + y.abs()) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_navigate() {
+        assert_eq!(
+            navigate(vec![
+                Command::MoveForward(10),
+                Command::Move(Direction::North, 3),
+                Command::MoveForward(7),
+                Command::Turn(Turn::Right, 90),
+                Command::MoveForward(11)
+            ]),
+            25
+        )
+    }
+}
@@ -0,0 +1,83 @@
+use crate::day12::{Command, Direction, Turn};
+
+/// Rotate the point `(x, y)` clockwise about the origin by `deg` degrees, which should be a
+/// multiple of 90; any part of a quarter turn left over is ignored.
+pub fn turn(x: i32, y: i32, deg: i32) -> (i32, i32) {
+    match (deg / 90).rem_euclid(4) {
+        0 => (x, y),
+        1 => (y, -x),
+        2 => (-x, -y),
+        _ => (-y, x),
+    }
+}
+
+pub fn navigate(commands: Vec<Command>) -> u32 {
+    let mut waypoint_x: i32 = 10;
+    let mut waypoint_y: i32 = 1;
+    let mut ship_x: i64 = 0;
+    let mut ship_y: i64 = 0;
+    for command in commands {
+        trace!(
+            "w:({}, {}) s:({}, {}) <= {:?}",
+            waypoint_x,
+            waypoint_y,
+            ship_x,
+            ship_y,
+            command
+        );
+        match command {
+            Command::Turn(t, deg) => match t {
+                Turn::Left => {
+                    let (x, y) = turn(waypoint_x, waypoint_y, -(deg as i32));
+                    waypoint_x = x;
+                    waypoint_y = y;
+                }
+                Turn::Right => {
+                    let (x, y) = turn(waypoint_x, waypoint_y, deg as i32);
+                    waypoint_x = x;
+                    waypoint_y = y;
+                }
+            },
+            Command::Move(dir, amount) => match dir {
+                Direction::North => waypoint_y += amount as i32,
+                Direction::East => waypoint_x += amount as i32,
+                Direction::South => waypoint_y -= amount as i32,
+                Direction::West => waypoint_x -= amount as i32,
+            },
+            Command::MoveForward(amount) => {
+                ship_x += (waypoint_x * amount as i32) as i64;
+                ship_y += (waypoint_y * amount as i32) as i64;
+            }
+        }
+    }
+    (ship_x.abs() + ship_y.abs()) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_direction_turn() {
+        assert_eq!(turn(4, 10, 90), (10, -4))
+    }
+
+    #[test]
+    fn test_navigate() {
+        assert_eq!(
+            navigate(vec![
+                Command::MoveForward(10),
+                Command::Move(Direction::North, 3),
+                Command::MoveForward(7),
+                Command::Turn(Turn::Right, 90),
+                Command::MoveForward(11)
+            ]),
+            286
+        )
+    }
+
+    #[test]
+    fn test_turn_full_circle() {
+        assert_eq!(turn(4, 10, 360), (4, 10))
+    }
+}
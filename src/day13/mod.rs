@@ -0,0 +1,96 @@
+use crate::parse::{self, ParseError};
+
+pub mod prob01;
+pub mod prob02;
+
+#[derive(Debug, PartialEq)]
+pub struct Notes {
+    pub earliest: u32,
+    pub ids: Vec<Option<u16>>,
+}
+
+impl Notes {
+    pub fn new(earliest: u32, ids: Vec<Option<u16>>) -> Notes {
+        Notes { earliest, ids }
+    }
+
+    /// Get the IDs of the shuttles that are in service, skipping any that are marked 'x'.
+    pub fn in_service(&self) -> Vec<u16> {
+        self.ids.iter().filter_map(|id| *id).collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    let mut lines = parse::lines(input);
+    let (line_no, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected the earliest timestamp"))?;
+    let earliest = parse::number::<u32>(line, line).map_err(|e| e.on_line(line_no))?;
+    let (line_no, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(line_no + 1, 1, "", "expected a list of bus ids"))?;
+    let ids = line
+        .split(',')
+        .map(|id| {
+            if id == "x" {
+                Ok(None)
+            } else {
+                match parse::number::<u16>(line, id)? {
+                    0 => Err(ParseError::within(line, id, "a bus id can't be 0")),
+                    id => Ok(Some(id)),
+                }
+            }
+        })
+        .collect::<Result<Vec<Option<u16>>, ParseError>>()
+        .map_err(|e| e.on_line(line_no))?;
+    Ok(Notes::new(earliest, ids))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("939\n7,13,x,x,59,x,31,19\n"),
+            Ok(Notes::new(
+                939,
+                vec![
+                    Some(7),
+                    Some(13),
+                    None,
+                    None,
+                    Some(59),
+                    None,
+                    Some(31),
+                    Some(19)
+                ]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_id() {
+        assert_eq!(
+            parse("939\n7,13,?,59\n"),
+            Err(ParseError::new(2, 6, "?", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_parse_zero_id() {
+        assert_eq!(
+            parse("939\n7,x,0\n"),
+            Err(ParseError::new(2, 5, "0", "a bus id can't be 0"))
+        )
+    }
+
+    #[test]
+    fn test_parse_missing_ids() {
+        assert_eq!(
+            parse("939\n"),
+            Err(ParseError::new(2, 1, "", "expected a list of bus ids"))
+        )
+    }
+}
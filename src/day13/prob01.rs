@@ -0,0 +1,27 @@
+pub fn find_first_shuttle(earliest: u32, ids: Vec<u16>) -> Result<u32, String> {
+    let (pickup_time, first_shuttle) = ids
+        .into_iter()
+        .map(|id| ((earliest / id as u32) * id as u32 + id as u32, id))
+        .min()
+        .ok_or_else(|| "no buses are in service".to_string())?;
+    debug!("shuttle {} picks up at {}", first_shuttle, pickup_time);
+    Ok(first_shuttle as u32 * (pickup_time - earliest))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_first_shuttle() {
+        assert_eq!(find_first_shuttle(939, vec![7, 13, 59, 31, 19]), Ok(295))
+    }
+
+    #[test]
+    fn test_find_first_shuttle_none_in_service() {
+        assert_eq!(
+            find_first_shuttle(939, vec![]),
+            Err("no buses are in service".to_string())
+        )
+    }
+}
@@ -0,0 +1,168 @@
+use crate::parse::{self, ParseError};
+use regex::Regex;
+
+pub mod prob01;
+pub mod prob02;
+
+/// Parse the initialization program, decoding each mask with the provided decoder chip.
+pub fn parse<M>(
+    input: &str,
+    parse_mask: impl Fn(&str) -> Result<M, ParseError>,
+) -> Result<Vec<Instruction<M>>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| Instruction::from_raw(line, &parse_mask).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Instruction<M>>, ParseError>>()
+}
+
+/// The 36 bits of a `mask = ...` line, most significant first.  Every bit is checked to be one
+/// of '0', '1' or 'X' so the decoder chips only have to interpret them.
+pub fn mask_bits(line: &str) -> Result<&str, ParseError> {
+    let raw_mask = line
+        .strip_prefix("mask = ")
+        .ok_or_else(|| ParseError::within(line, line, "expected 'mask = ' or 'mem[...] = '"))?;
+    if let Some((i, c)) = raw_mask
+        .char_indices()
+        .find(|(_, c)| !matches!(c, '0' | '1' | 'X'))
+    {
+        return Err(ParseError::within(
+            line,
+            &raw_mask[i..i + c.len_utf8()],
+            "expected '0', '1' or 'X'",
+        ));
+    }
+    if raw_mask.len() != 36 {
+        return Err(ParseError::within(line, raw_mask, "expected 36 bits"));
+    }
+    Ok(raw_mask)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction<M> {
+    MemSet(MemSet),
+    Mask(M),
+}
+
+impl<M> Instruction<M> {
+    pub fn from_raw(
+        line: &str,
+        parse_mask: impl Fn(&str) -> Result<M, ParseError>,
+    ) -> Result<Instruction<M>, ParseError> {
+        if line.starts_with("mem") {
+            MemSet::from_raw(line).map(Instruction::MemSet)
+        } else {
+            parse_mask(line).map(Instruction::Mask)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MemSet {
+    pub addr: usize,
+    pub val: u64,
+}
+
+impl MemSet {
+    pub fn new(addr: usize, val: u64) -> MemSet {
+        MemSet { addr, val }
+    }
+
+    pub fn from_raw(line: &str) -> Result<MemSet, ParseError> {
+        let pattern = Regex::new(r"^mem\[([0-9]+)\] = ([0-9]+)$").expect("Invalid regex");
+        let captures = pattern.captures(line).ok_or_else(|| {
+            ParseError::within(line, line, "expected an assignment like 'mem[8] = 11'")
+        })?;
+        let addr = parse::number::<usize>(line, captures.get(1).unwrap().as_str())?;
+        let val = parse::number::<u64>(line, captures.get(2).unwrap().as_str())?;
+        Ok(MemSet::new(addr, val))
+    }
+}
+
+#[derive(PartialEq)]
+pub struct Mask {
+    pub set_mask: u64,
+    pub unset_mask: u64,
+}
+
+impl std::fmt::Debug for Mask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mask")
+            .field("set_mask", &format_args!("{:b}", self.set_mask))
+            .field("unset_mask", &format_args!("{:b}", self.unset_mask))
+            .finish()
+    }
+}
+
+impl Mask {
+    pub fn new(set_mask: u64, unset_mask: u64) -> Mask {
+        Mask {
+            set_mask,
+            unset_mask,
+        }
+    }
+
+    pub fn apply(&self, val: u64) -> u64 {
+        val & self.unset_mask | self.set_mask
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memset_from_raw() {
+        assert_eq!(MemSet::from_raw("mem[6] = 11"), Ok(MemSet::new(6, 11)))
+    }
+
+    #[test]
+    fn test_memset_from_raw_bad_value() {
+        assert_eq!(
+            MemSet::from_raw("mem[6] = 11x"),
+            Err(ParseError::new(
+                1,
+                1,
+                "mem[6] = 11x",
+                "expected an assignment like 'mem[8] = 11'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_memset_from_raw_value_too_large() {
+        assert_eq!(
+            MemSet::from_raw("mem[6] = 99999999999999999999"),
+            Err(ParseError::new(
+                1,
+                10,
+                "99999999999999999999",
+                "expected a number"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_mask_bits_bad_char() {
+        assert_eq!(
+            mask_bits("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X"),
+            Err(ParseError::new(1, 42, "2", "expected '0', '1' or 'X'"))
+        )
+    }
+
+    #[test]
+    fn test_mask_bits_too_short() {
+        assert_eq!(
+            mask_bits("mask = X1X"),
+            Err(ParseError::new(1, 8, "X1X", "expected 36 bits"))
+        )
+    }
+
+    #[test]
+    fn test_mask_apply1() {
+        assert_eq!(Mask::new(0b1000000u64, !0b10u64).apply(11), 73)
+    }
+
+    #[test]
+    fn test_mask_apply2() {
+        assert_eq!(Mask::new(0b1000000u64, !0b10u64).apply(101), 101)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::day14::{mask_bits, Instruction, Mask};
+use crate::parse::ParseError;
+use std::collections::HashMap;
+
+/// Decode a mask line as a version 1 decoder chip, which masks the values being written.
+pub fn parse_mask(line: &str) -> Result<Mask, ParseError> {
+    let raw_mask = mask_bits(line)?;
+
+    let mut set_mask = 0u64;
+    let mut unset_mask = u64::MAX;
+
+    for (i, val) in raw_mask.chars().rev().enumerate() {
+        match val {
+            '0' => unset_mask &= !(2u64.pow(i as u32)),
+            '1' => set_mask |= 2u64.pow(i as u32),
+            'X' => (),
+            a => unreachable!("mask_bits accepted '{}'", a),
+        }
+    }
+
+    Ok(Mask::new(set_mask, unset_mask))
+}
+
+pub fn run(instructions: Vec<Instruction<Mask>>) -> u64 {
+    let mut mask = Mask::new(0, u64::MAX);
+    let mut mem: HashMap<usize, u64> = HashMap::new();
+
+    for inst in instructions {
+        match inst {
+            Instruction::Mask(inst_mask) => mask = inst_mask,
+            Instruction::MemSet(inst_memset) => {
+                mem.insert(inst_memset.addr, mask.apply(inst_memset.val));
+            }
+        }
+    }
+
+    mem.values().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day14::MemSet;
+
+    #[test]
+    fn test_instruction_from_raw_memset() {
+        assert_eq!(
+            Instruction::from_raw("mem[6] = 11", parse_mask),
+            Ok(Instruction::MemSet(MemSet::new(6, 11)))
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_raw_mask() {
+        assert_eq!(
+            Instruction::from_raw("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X", parse_mask),
+            Ok(Instruction::Mask(Mask::new(0b1000000u64, !0b10u64)))
+        )
+    }
+
+    #[test]
+    fn test_instruction_run() {
+        assert_eq!(
+            run(vec![
+                Instruction::Mask(Mask::new(0b1000000u64, !0b010u64)),
+                Instruction::MemSet(MemSet::new(8, 11)),
+                Instruction::MemSet(MemSet::new(7, 101)),
+                Instruction::MemSet(MemSet::new(8, 0))
+            ]),
+            165
+        )
+    }
+}
@@ -0,0 +1,130 @@
+use crate::day14::{mask_bits, Instruction, Mask};
+use crate::parse::ParseError;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// Decode a mask line as a version 2 decoder chip, which masks the memory addresses being written
+/// to.  Each floating bit doubles the number of masks produced.
+pub fn parse_mask(line: &str) -> Result<Vec<Mask>, ParseError> {
+    let raw_mask = mask_bits(line)?;
+
+    let mut set_mask = 0u64;
+    let mut flaky_bits: Vec<usize> = Vec::new();
+
+    for (i, val) in raw_mask.chars().rev().enumerate() {
+        match val {
+            '0' => (),
+            '1' => set_mask |= 2u64.pow(i as u32),
+            'X' => flaky_bits.push(i),
+            a => unreachable!("mask_bits accepted '{}'", a),
+        }
+    }
+
+    let flaky_bits: HashSet<usize> = {
+        let mut flaky_bits_set: HashSet<usize> = HashSet::new();
+        flaky_bits_set.extend(flaky_bits);
+        flaky_bits_set
+    };
+    let mut flaky_masks: Vec<Mask> = Vec::new();
+    for len in 0..=flaky_bits.len() {
+        for comb in flaky_bits.iter().combinations(len) {
+            let mut set_mask_local = set_mask;
+            let mut unset_mask_local = u64::MAX;
+
+            let to_set = comb.into_iter().copied().collect::<HashSet<usize>>();
+            flaky_bits
+                .difference(&to_set)
+                .sorted()
+                .for_each(|i| unset_mask_local &= !(2u64.pow(*i as u32)));
+            to_set
+                .into_iter()
+                .sorted()
+                .for_each(|i| set_mask_local |= 2u64.pow(i as u32));
+
+            flaky_masks.push(Mask::new(set_mask_local, unset_mask_local));
+        }
+    }
+
+    Ok(flaky_masks
+        .into_iter()
+        .sorted_by_key(|mask| mask.set_mask)
+        .collect())
+}
+
+pub fn run(instructions: Vec<Instruction<Vec<Mask>>>) -> u64 {
+    let mut masks = vec![Mask::new(0, u64::MAX)];
+    let mut mem: HashMap<usize, u64> = HashMap::new();
+
+    for inst in instructions {
+        match inst {
+            Instruction::Mask(inst_masks) => masks = inst_masks,
+            Instruction::MemSet(inst_memset) => {
+                masks.iter().for_each(|mask| {
+                    mem.insert(
+                        mask.apply(inst_memset.addr as u64) as usize,
+                        inst_memset.val,
+                    );
+                });
+            }
+        }
+    }
+
+    mem.values().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day14::MemSet;
+
+    #[test]
+    fn test_instruction_from_raw_memset() {
+        assert_eq!(
+            Instruction::from_raw("mem[6] = 11", parse_mask),
+            Ok(Instruction::MemSet(MemSet::new(6, 11)))
+        )
+    }
+
+    #[test]
+    fn test_instruction_from_raw_mask() {
+        assert_eq!(
+            Instruction::from_raw("mask = 000000000000000000000000000000X1001X", parse_mask),
+            Ok(Instruction::Mask(vec![
+                Mask::new(0b010010u64, !0b100001u64),
+                Mask::new(0b010011u64, !0b100000u64),
+                Mask::new(0b110010u64, !0b000001u64),
+                Mask::new(0b110011u64, !0b000000u64)
+            ]))
+        )
+    }
+
+    #[test]
+    fn test_instruction_run() {
+        assert_eq!(
+            run(vec![
+                Instruction::Mask(vec![
+                    Mask::new(0b010010u64, !0b100001u64),
+                    Mask::new(0b010011u64, !0b100000u64),
+                    Mask::new(0b110010u64, !0b000001u64),
+                    Mask::new(0b110011u64, !0b000000u64)
+                ]),
+                Instruction::MemSet(MemSet::new(42, 100)),
+                Instruction::from_raw("mem[42] = 100", parse_mask).unwrap(),
+                Instruction::Mask(vec![
+                    Mask::new(0b0000, !0b1011),
+                    Mask::new(0b0001, !0b1010),
+                    Mask::new(0b0010, !0b1001),
+                    Mask::new(0b0011, !0b1000),
+                    Mask::new(0b1000, !0b0011),
+                    Mask::new(0b1001, !0b0010),
+                    Mask::new(0b1010, !0b0001),
+                    Mask::new(0b1011, !0b0000)
+                ]),
+                Instruction::from_raw("mask = 00000000000000000000000000000000X0XX", parse_mask)
+                    .unwrap(),
+                Instruction::MemSet(MemSet::new(26, 1))
+            ]),
+            208
+        )
+    }
+}
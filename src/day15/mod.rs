@@ -0,0 +1,186 @@
+use crate::parse::{self, ParseError};
+use std::collections::HashMap;
+
+pub mod prob01;
+pub mod prob02;
+
+/// Parse the comma separated starting numbers, which sit on the first line of the input.
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let (i, line) = parse::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected the starting numbers"))?;
+    line.trim()
+        .split(',')
+        .map(|num| parse::number::<usize>(line, num.trim()))
+        .collect::<Result<Vec<usize>, ParseError>>()
+        .map_err(|e| e.on_line(i))
+}
+
+#[derive(Debug)]
+pub struct Diff {
+    pub prev: Option<usize>,
+    pub next: Option<usize>,
+}
+
+impl Diff {
+    pub fn new(curr: usize) -> Diff {
+        Diff {
+            prev: None,
+            next: Some(curr),
+        }
+    }
+
+    pub fn push(&mut self, i: usize) {
+        self.prev = self.next;
+        self.next = Some(i);
+    }
+
+    pub fn diff(&self) -> Option<usize> {
+        match (self.prev, self.next) {
+            (Some(prev), Some(next)) => Some(next - prev),
+            _ => None,
+        }
+    }
+}
+
+pub fn chain(starter_numbers: Vec<usize>, n: usize) -> usize {
+    if n <= starter_numbers.len() {
+        starter_numbers[n - 1]
+    } else {
+        let mut last_seen: HashMap<usize, Diff> = HashMap::new();
+        last_seen.extend(
+            starter_numbers
+                .iter()
+                .enumerate()
+                .map(|(i, num)| (*num, Diff::new(i))),
+        );
+        let mut most_recent = *starter_numbers.last().unwrap();
+        for i in starter_numbers.len()..n {
+            let next_num = last_seen.get(&most_recent).unwrap().diff().unwrap_or(0);
+            trace!("turn {}: {} -> {}", i + 1, most_recent, next_num);
+
+            if let Some(next_diff) = last_seen.get_mut(&next_num) {
+                next_diff.push(i);
+            } else {
+                last_seen.insert(next_num, Diff::new(i));
+            }
+
+            most_recent = next_num;
+        }
+        most_recent
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("0,3,6\n"), Ok(vec![0, 3, 6]))
+    }
+
+    #[test]
+    fn test_parse_bad_number() {
+        assert_eq!(
+            parse("0,3,x\n"),
+            Err(ParseError::new(1, 5, "x", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(
+            parse("\n"),
+            Err(ParseError::new(1, 1, "", "expected the starting numbers"))
+        )
+    }
+
+    #[test]
+    fn test_chain1() {
+        assert_eq!(chain(vec![0, 3, 6], 1), 0)
+    }
+
+    #[test]
+    fn test_chain2() {
+        assert_eq!(chain(vec![0, 3, 6], 2), 3)
+    }
+
+    #[test]
+    fn test_chain3() {
+        assert_eq!(chain(vec![0, 3, 6], 3), 6)
+    }
+
+    #[test]
+    fn test_chain4() {
+        assert_eq!(chain(vec![0, 3, 6], 4), 0)
+    }
+
+    #[test]
+    fn test_chain5() {
+        assert_eq!(chain(vec![0, 3, 6], 5), 3)
+    }
+
+    #[test]
+    fn test_chain6() {
+        assert_eq!(chain(vec![0, 3, 6], 6), 3)
+    }
+
+    #[test]
+    fn test_chain7() {
+        assert_eq!(chain(vec![0, 3, 6], 7), 1)
+    }
+
+    #[test]
+    fn test_chain8() {
+        assert_eq!(chain(vec![0, 3, 6], 8), 0)
+    }
+
+    #[test]
+    fn test_chain9() {
+        assert_eq!(chain(vec![0, 3, 6], 9), 4)
+    }
+
+    #[test]
+    fn test_chain10() {
+        assert_eq!(chain(vec![0, 3, 6], 10), 0)
+    }
+
+    // These tests all pass, but are slow (and I don't want to slow down test iterations on future
+    // solutions
+
+    // #[test]
+    // fn test_chain_30000000_1() {
+    //     assert_eq!(chain(vec![0, 3, 6], 30000000), 175594)
+    // }
+    //
+    // #[test]
+    // fn test_chain_30000000_2() {
+    //     assert_eq!(chain(vec![1, 3, 2], 30000000), 2578)
+    // }
+    //
+    // #[test]
+    // fn test_chain_30000000_3() {
+    //     assert_eq!(chain(vec![2, 1, 3], 30000000), 3544142)
+    // }
+    //
+    // #[test]
+    // fn test_chain_30000000_4() {
+    //     assert_eq!(chain(vec![1, 2, 3], 30000000), 261214)
+    // }
+    //
+    // #[test]
+    // fn test_chain_30000000_5() {
+    //     assert_eq!(chain(vec![2, 3, 1], 30000000), 6895259)
+    // }
+    //
+    // #[test]
+    // fn test_chain_30000000_6() {
+    //     assert_eq!(chain(vec![3, 2, 1], 30000000), 18)
+    // }
+    //
+    // #[test]
+    // fn test_chain_30000000_7() {
+    //     assert_eq!(chain(vec![3, 1, 2], 30000000), 362)
+    // }
+}
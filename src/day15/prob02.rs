@@ -0,0 +1,5 @@
+use crate::day15::chain;
+
+pub fn play(starter_numbers: Vec<usize>) -> usize {
+    chain(starter_numbers, 30000000)
+}
@@ -0,0 +1,350 @@
+use crate::parse::{self, ParseError};
+use std::ops::RangeInclusive;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Input::from_raw(input.lines().map(|line| line.to_string()).collect())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    pub fields: Vec<Field>,
+    pub my_ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+impl Input {
+    pub fn new(fields: Vec<Field>, my_ticket: Ticket, nearby_tickets: Vec<Ticket>) -> Input {
+        Input {
+            fields,
+            my_ticket,
+            nearby_tickets,
+        }
+    }
+
+    pub fn from_raw(lines: Vec<String>) -> Result<Input, ParseError> {
+        let mut in_fields = true;
+        let mut in_my_ticket = false;
+        let mut in_nearby_tickets = false;
+        let mut skip_next = false;
+
+        let mut fields: Vec<Field> = Vec::new();
+        let mut my_ticket: Option<Ticket> = None;
+        let mut nearby_tickets: Vec<Ticket> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            trace!(
+                "{}\n  in_fields: {}, in_my_ticket: {}, in_nearby_tickets: {}, skip_next: {}",
+                line,
+                in_fields,
+                in_my_ticket,
+                in_nearby_tickets,
+                skip_next
+            );
+            if skip_next {
+                skip_next = false;
+                continue;
+            }
+
+            if line.is_empty() {
+                if in_fields {
+                    in_fields = false;
+                    in_my_ticket = true;
+                    skip_next = true;
+                } else if in_my_ticket {
+                    in_my_ticket = false;
+                    in_nearby_tickets = true;
+                    skip_next = true;
+                }
+            } else if in_fields {
+                fields.push(Field::from_line(line).map_err(|e| e.on_line(i + 1))?);
+            } else if in_my_ticket {
+                my_ticket =
+                    Some(Ticket::from_fields_line(line, &fields).map_err(|e| e.on_line(i + 1))?);
+            } else if in_nearby_tickets {
+                nearby_tickets
+                    .push(Ticket::from_fields_line(line, &fields).map_err(|e| e.on_line(i + 1))?);
+            }
+        }
+
+        // missing sections are reported just past the end of the input
+        let end = ParseError::new(lines.len() + 1, 1, "", "");
+        if fields.is_empty() {
+            return Err(ParseError {
+                message: "expected ticket field rules".to_string(),
+                ..end
+            });
+        }
+        let my_ticket = my_ticket.ok_or_else(|| ParseError {
+            message: "expected a 'your ticket:' section".to_string(),
+            ..end.clone()
+        })?;
+        if nearby_tickets.is_empty() {
+            return Err(ParseError {
+                message: "expected a 'nearby tickets:' section".to_string(),
+                ..end
+            });
+        }
+
+        Ok(Input::new(fields, my_ticket, nearby_tickets))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Field {
+    pub name: String,
+    pub ranges: Vec<RangeInclusive<u16>>,
+}
+
+impl Field {
+    pub fn new(name: String, ranges: Vec<RangeInclusive<u16>>) -> Field {
+        Field { name, ranges }
+    }
+
+    /// Parse a rule like `class: 1-3 or 5-7`.
+    pub fn from_line(line: &str) -> Result<Field, ParseError> {
+        let pieces = line.splitn(2, ": ").collect::<Vec<&str>>();
+        if pieces.len() != 2 {
+            return Err(ParseError::within(
+                line,
+                line,
+                "expected a rule like 'class: 1-3 or 5-7'",
+            ));
+        }
+        let ranges = pieces[1]
+            .split(" or ")
+            .map(|range_raw| {
+                let bounds = range_raw.split('-').collect::<Vec<&str>>();
+                if bounds.len() != 2 {
+                    return Err(ParseError::within(
+                        line,
+                        range_raw,
+                        "expected a range like '1-3'",
+                    ));
+                }
+                Ok(parse::number::<u16>(line, bounds[0])?..=parse::number::<u16>(line, bounds[1])?)
+            })
+            .collect::<Result<Vec<RangeInclusive<u16>>, ParseError>>()?;
+        Ok(Field::new(pieces[0].to_string(), ranges))
+    }
+
+    pub fn contains(&self, val: &u16) -> bool {
+        self.ranges.iter().any(|range| range.contains(val))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ticket {
+    pub values: Vec<u16>,
+}
+
+impl Ticket {
+    pub fn new(values: Vec<u16>) -> Ticket {
+        Ticket { values }
+    }
+
+    /// Parse a comma separated list of ticket values.
+    pub fn from_line(line: &str) -> Result<Ticket, ParseError> {
+        line.split(',')
+            .map(|val| parse::number::<u16>(line, val))
+            .collect::<Result<Vec<u16>, ParseError>>()
+            .map(Ticket::new)
+    }
+
+    /// Parse a ticket that must have one value for each of `fields`.
+    pub fn from_fields_line(line: &str, fields: &[Field]) -> Result<Ticket, ParseError> {
+        let ticket = Ticket::from_line(line)?;
+        if ticket.values.len() != fields.len() {
+            return Err(ParseError::within(
+                line,
+                line,
+                &format!("expected {} values, one for each field", fields.len()),
+            ));
+        }
+        Ok(ticket)
+    }
+
+    pub fn invalid_values(&self, fields: &[Field]) -> Vec<u16> {
+        self.values
+            .iter()
+            .filter_map(|value| {
+                if !fields.iter().any(|field| field.contains(value)) {
+                    Some(*value)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<u16>>()
+    }
+
+    pub fn is_valid(&self, fields: &[Field]) -> bool {
+        self.values
+            .iter()
+            .all(|value| fields.iter().any(|field| field.contains(value)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_from_raw() {
+        let input = vec![
+            "class: 1-3 or 5-7",
+            "row: 6-11 or 33-44",
+            "seat: 13-40 or 45-50",
+            "",
+            "your ticket:",
+            "7,1,14",
+            "",
+            "nearby tickets:",
+            "7,3,47",
+            "40,4,50",
+            "55,2,20",
+            "38,6,12",
+        ];
+        assert_eq!(
+            Input::from_raw(
+                input
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect::<Vec<String>>()
+            ),
+            Ok(Input::new(
+                vec![
+                    Field::new("class".to_string(), vec![1..=3, 5..=7]),
+                    Field::new("row".to_string(), vec![6..=11, 33..=44]),
+                    Field::new("seat".to_string(), vec![13..=40, 45..=50])
+                ],
+                Ticket::new(vec![7, 1, 14]),
+                vec![
+                    Ticket::new(vec![7, 3, 47]),
+                    Ticket::new(vec![40, 4, 50]),
+                    Ticket::new(vec![55, 2, 20]),
+                    Ticket::new(vec![38, 6, 12])
+                ]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_input_from_raw_bad_ticket() {
+        let input = [
+            "class: 1-3 or 5-7",
+            "row: 6-11 or 33-44",
+            "seat: 13-40 or 45-50",
+            "",
+            "your ticket:",
+            "7,1,14",
+            "",
+            "nearby tickets:",
+            "7,3,47",
+            "40,four,50",
+        ];
+        assert_eq!(
+            Input::from_raw(input.iter().map(|line| line.to_string()).collect()),
+            Err(ParseError::new(10, 4, "four", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_input_from_raw_wrong_width() {
+        let input = [
+            "class: 1-3 or 5-7",
+            "row: 6-11 or 33-44",
+            "",
+            "your ticket:",
+            "7,1",
+            "",
+            "nearby tickets:",
+            "7,3",
+            "40,4,50",
+        ];
+        assert_eq!(
+            Input::from_raw(input.iter().map(|line| line.to_string()).collect()),
+            Err(ParseError::new(
+                9,
+                1,
+                "40,4,50",
+                "expected 2 values, one for each field"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_input_from_raw_missing_nearby() {
+        let input = ["class: 1-3 or 5-7", "", "your ticket:", "7"];
+        assert_eq!(
+            Input::from_raw(input.iter().map(|line| line.to_string()).collect()),
+            Err(ParseError::new(
+                5,
+                1,
+                "",
+                "expected a 'nearby tickets:' section"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_field_from_line_bad_range() {
+        assert_eq!(
+            Field::from_line("row: 6-11 or 33"),
+            Err(ParseError::new(1, 14, "33", "expected a range like '1-3'"))
+        )
+    }
+
+    #[test]
+    fn test_field_contains_true1() {
+        assert!(Field::new("".to_string(), vec![1..=6, 8..=10]).contains(&4))
+    }
+
+    #[test]
+    fn test_field_contains_true2() {
+        assert!(Field::new("".to_string(), vec![1..=6, 8..=10]).contains(&10))
+    }
+
+    #[test]
+    fn test_field_contains_false1() {
+        assert!(!Field::new("".to_string(), vec![1..=6, 8..=10]).contains(&0))
+    }
+
+    #[test]
+    fn test_field_contains_false2() {
+        assert!(!Field::new("".to_string(), vec![1..=6, 8..=10]).contains(&7))
+    }
+
+    #[test]
+    fn test_field_contains_false3() {
+        assert!(!Field::new("".to_string(), vec![1..=6, 8..=10]).contains(&11))
+    }
+
+    #[test]
+    fn test_ticket_is_valid_true() {
+        assert!(Ticket::new(vec![4, 10]).is_valid(&[
+            Field::new("".to_string(), vec![1..=6]),
+            Field::new("".to_string(), vec![8..=10])
+        ]))
+    }
+
+    #[test]
+    fn test_ticket_is_valid_false() {
+        assert!(!Ticket::new(vec![4, 7, 10]).is_valid(&[
+            Field::new("".to_string(), vec![1..=6]),
+            Field::new("".to_string(), vec![8..=10])
+        ]))
+    }
+
+    #[test]
+    fn test_ticket_invalid_fields() {
+        assert_eq!(
+            Ticket::new(vec![4, 7, 10]).invalid_values(&[
+                Field::new("".to_string(), vec![1..=6]),
+                Field::new("".to_string(), vec![8..=10])
+            ]),
+            vec![7]
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use crate::day16::Input;
+
+pub fn nearby_error_rate(input: &Input) -> u16 {
+    input
+        .nearby_tickets
+        .iter()
+        .map(|ticket| ticket.invalid_values(&input.fields).iter().sum::<u16>())
+        .sum::<u16>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day16::{Field, Ticket};
+
+    #[test]
+    fn test_input_nearby_error_rate() {
+        assert_eq!(
+            nearby_error_rate(&Input::new(
+                vec![
+                    Field::new("class".to_string(), vec![1..=3, 5..=7]),
+                    Field::new("row".to_string(), vec![6..=11, 33..=44]),
+                    Field::new("seat".to_string(), vec![13..=40, 45..=50])
+                ],
+                Ticket::new(vec![7, 1, 14]),
+                vec![
+                    Ticket::new(vec![7, 3, 47]),
+                    Ticket::new(vec![40, 4, 50]),
+                    Ticket::new(vec![55, 2, 20]),
+                    Ticket::new(vec![38, 6, 12])
+                ]
+            )),
+            71
+        )
+    }
+}
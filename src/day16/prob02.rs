@@ -0,0 +1,111 @@
+use crate::day16::{Field, Input, Ticket};
+use std::collections::HashSet;
+
+/// The field at each position on a ticket, if the nearby tickets pin every one of them down.
+pub fn associate_fields(input: &Input) -> Option<Vec<Field>> {
+    let valid_tickets = input
+        .nearby_tickets
+        .iter()
+        .filter_map(|ticket| {
+            if ticket.is_valid(&input.fields) {
+                Some(ticket.clone())
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<Ticket>>();
+
+    let mut matching_fields = valid_tickets
+        .iter()
+        .map(|ticket| {
+            ticket
+                .values
+                .iter()
+                .map(|val| {
+                    input
+                        .fields
+                        .iter()
+                        .map(|field| field.contains(val))
+                        .collect::<Vec<bool>>()
+                })
+                .collect::<Vec<Vec<bool>>>()
+        })
+        .collect::<Vec<Vec<Vec<bool>>>>();
+
+    let mut remaining_is: HashSet<u16> = (0..input.fields.len()).map(|i| i as u16).collect();
+    let mut ordered_fields: Vec<Option<Field>> = vec![None; input.fields.len()];
+    for _ in 0..input.fields.len() {
+        for field_i in 0..input.fields.len() {
+            // find the values in the ticket with all tickets matching the current filter
+            let val_checks = (0..input.fields.len())
+                .map(|val_i| {
+                    (0..valid_tickets.len())
+                        .all(|ticket_i| matching_fields[ticket_i][val_i][field_i])
+                })
+                .collect::<Vec<bool>>();
+
+            // if this field has a match, add it to the final set
+            if val_checks.iter().filter(|c| **c).count() == 1 {
+                // figure out the index of the value that can be matched to a filter
+                let found_val_i = val_checks
+                    .iter()
+                    .enumerate()
+                    .find(|(_, val)| **val)
+                    .unwrap()
+                    .0 as u16;
+                remaining_is.remove(&found_val_i);
+                ordered_fields[found_val_i as usize] = Some(input.fields[field_i].clone());
+
+                // update all tickets to mark the found value index as invalid
+                for ticket in matching_fields.iter_mut() {
+                    for matches in ticket[found_val_i as usize].iter_mut() {
+                        *matches = false;
+                    }
+                }
+            }
+        }
+    }
+
+    ordered_fields.into_iter().collect()
+}
+
+/// Multiply together the values on my ticket for every field whose name starts with "departure".
+pub fn departure_product(input: &Input) -> Option<u64> {
+    Some(
+        associate_fields(input)?
+            .into_iter()
+            .enumerate()
+            .filter(|(_, field)| field.name.starts_with("departure"))
+            .map(|(i, _)| input.my_ticket.values[i] as u64)
+            .product::<u64>(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_associate_fields() {
+        assert_eq!(
+            associate_fields(&Input::new(
+                vec![
+                    Field::new("class".to_string(), vec![0..=1, 4..=19]),
+                    Field::new("row".to_string(), vec![0..=5, 8..=19]),
+                    Field::new("seat".to_string(), vec![0..=13, 16..=19])
+                ],
+                Ticket::new(vec![11, 12, 13]),
+                vec![
+                    Ticket::new(vec![3, 9, 18]),
+                    Ticket::new(vec![15, 1, 5]),
+                    Ticket::new(vec![4, 15, 9])
+                ]
+            )),
+            Some(vec![
+                Field::new("row".to_string(), vec![0..=5, 8..=19]),
+                Field::new("class".to_string(), vec![0..=1, 4..=19]),
+                Field::new("seat".to_string(), vec![0..=13, 16..=19])
+            ])
+        )
+    }
+}
@@ -0,0 +1,10 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+
+pub mod prob01;
+pub mod prob02;
+
+/// Parse the initial 2D slice of the pocket dimension, where `true` marks an active cube.
+pub fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input)
+}
@@ -0,0 +1,330 @@
+use crate::automaton::{life, Automaton};
+use crate::grid::Grid;
+use std::collections::HashSet;
+
+/// Place the initial slice into the pocket dimension, with all other coordinates set to 0.
+pub fn initial_state(slice: &Grid<bool>) -> HashSet<(i32, i32, i32, i32)> {
+    slice
+        .iter()
+        .filter(|(_, active)| **active)
+        .map(|((x, y), _)| (x as i32, y as i32, 0, 0))
+        .collect()
+}
+
+pub fn boot(init_state: HashSet<(i32, i32, i32, i32)>) -> usize {
+    Automaton::new(get_neighbors, life).run(init_state, 6).len()
+}
+
+pub fn run_cycle(state: &HashSet<(i32, i32, i32, i32)>) -> HashSet<(i32, i32, i32, i32)> {
+    Automaton::new(get_neighbors, life).step(state)
+}
+
+pub fn get_neighbors(point: &(i32, i32, i32, i32)) -> Vec<(i32, i32, i32, i32)> {
+    let (x, y, z, w) = point;
+    let mut out: Vec<(i32, i32, i32, i32)> = Vec::new();
+    for x_off in -1..=1 {
+        for y_off in -1..=1 {
+            for z_off in -1..=1 {
+                for w_off in -1..=1 {
+                    if !(x_off == 0 && y_off == 0 && z_off == 0 && w_off == 0) {
+                        out.push((*x + x_off, *y + y_off, *z + z_off, *w + w_off));
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_run_cycle_active_true() {
+        assert!(
+            run_cycle(&hashset!((0, 0, 0, 0), (1, 0, 0, 0), (0, 1, 0, 0))).contains(&(0, 0, 0, 0))
+        )
+    }
+
+    #[test]
+    fn test_run_cycle_active_false() {
+        assert!(!run_cycle(&hashset!((0, 0, 0, 0), (1, 0, 0, 0))).contains(&(0, 0, 0, 0)))
+    }
+
+    #[test]
+    fn test_run_cycle_inactive_true() {
+        assert!(
+            run_cycle(&hashset!((1, 0, 0, 0), (0, 1, 0, 0), (1, 1, 0, 0))).contains(&(0, 0, 0, 0))
+        )
+    }
+
+    #[test]
+    fn test_run_cycle_inactive_false() {
+        assert!(!run_cycle(&hashset!((1, 0, 0, 0), (0, 1, 0, 0))).contains(&(0, 0, 0, 0)))
+    }
+
+    #[test]
+    fn test_run_cycle1() {
+        assert_eq!(
+            run_cycle(&hashset!(
+                (1, -1, 0, 0),
+                (2, 0, 0, 0),
+                (0, 1, 0, 0),
+                (1, 1, 0, 0),
+                (2, 1, 0, 0)
+            )),
+            hashset!(
+                (0, 0, -1, -1),
+                (2, 1, -1, -1),
+                (1, 2, -1, -1),
+                (0, 0, 0, -1),
+                (2, 1, 0, -1),
+                (1, 2, 0, -1),
+                (0, 0, 1, -1),
+                (2, 1, 1, -1),
+                (1, 2, 1, -1),
+                (0, 0, -1, 0),
+                (2, 1, -1, 0),
+                (1, 2, -1, 0),
+                (0, 0, 0, 0),
+                (2, 0, 0, 0),
+                (1, 1, 0, 0),
+                (2, 1, 0, 0),
+                (1, 2, 0, 0),
+                (0, 0, 1, 0),
+                (2, 1, 1, 0),
+                (1, 2, 1, 0),
+                (0, 0, -1, 1),
+                (2, 1, -1, 1),
+                (1, 2, -1, 1),
+                (0, 0, 0, 1),
+                (2, 1, 0, 1),
+                (1, 2, 0, 1),
+                (0, 0, 1, 1),
+                (2, 1, 1, 1),
+                (1, 2, 1, 1),
+            )
+        )
+    }
+
+    #[test]
+    fn test_run_cycle2() {
+        println!(
+            "{:?}",
+            run_cycle(&hashset!(
+                (0, 0, -1, -1),
+                (2, 1, -1, -1),
+                (1, 2, -1, -1),
+                (0, 0, 0, -1),
+                (2, 1, 0, -1),
+                (1, 2, 0, -1),
+                (0, 0, 1, -1),
+                (2, 1, 1, -1),
+                (1, 2, 1, -1),
+                (0, 0, -1, 0),
+                (2, 1, -1, 0),
+                (1, 2, -1, 0),
+                (0, 0, 0, 0),
+                (2, 0, 0, 0),
+                (1, 1, 0, 0),
+                (2, 1, 0, 0),
+                (1, 2, 0, 0),
+                (0, 0, 1, 0),
+                (2, 1, 1, 0),
+                (1, 2, 1, 0),
+                (0, 0, -1, 1),
+                (2, 1, -1, 1),
+                (1, 2, -1, 1),
+                (0, 0, 0, 1),
+                (2, 1, 0, 1),
+                (1, 2, 0, 1),
+                (0, 0, 1, 1),
+                (2, 1, 1, 1),
+                (1, 2, 1, 1),
+            ))
+            .iter()
+            .sorted()
+            .collect::<Vec<_>>()
+        );
+        println!(
+            "{:?}",
+            hashset!(
+                (1, 1, -2, -2),
+                (-1, -1, 0, -2),
+                (0, -1, 0, -2),
+                (1, -1, 0, -2),
+                (-1, 0, 0, -2),
+                (0, 0, 0, -2),
+                (2, 0, 0, -2),
+                (3, 0, 0, -2),
+                (-1, 1, 0, -2),
+                (3, 1, 0, -2),
+                (0, 2, 0, -2),
+                (3, 2, 0, -2),
+                (0, 3, 0, -2),
+                (1, 3, 0, -2),
+                (2, 3, 0, -2),
+                (1, 1, 2, -2),
+                (-1, -1, -2, 0),
+                (0, -1, -2, 0),
+                (1, -1, -2, 0),
+                (-1, 0, -2, 0),
+                (0, 0, -2, 0),
+                (2, 0, -2, 0),
+                (3, 0, -2, 0),
+                (-1, 1, -2, 0),
+                (3, 1, -2, 0),
+                (0, 2, -2, 0),
+                (3, 2, -2, 0),
+                (0, 3, -2, 0),
+                (1, 3, -2, 0),
+                (2, 3, -2, 0),
+                (-1, -1, 2, 0),
+                (0, -1, 2, 0),
+                (1, -1, 2, 0),
+                (-1, 0, 2, 0),
+                (0, 0, 2, 0),
+                (2, 0, 2, 0),
+                (3, 0, 2, 0),
+                (-1, 1, 2, 0),
+                (3, 1, 2, 0),
+                (0, 2, 2, 0),
+                (3, 2, 2, 0),
+                (0, 3, 2, 0),
+                (1, 3, 2, 0),
+                (2, 3, 2, 0),
+                (1, 1, -2, 2),
+                (-1, -1, 0, 2),
+                (0, -1, 0, 2),
+                (1, -1, 0, 2),
+                (-1, 0, 0, 2),
+                (0, 0, 0, 2),
+                (2, 0, 0, 2),
+                (3, 0, 0, 2),
+                (-1, 1, 0, 2),
+                (3, 1, 0, 2),
+                (0, 2, 0, 2),
+                (3, 2, 0, 2),
+                (0, 3, 0, 2),
+                (1, 3, 0, 2),
+                (2, 3, 0, 2),
+                (1, 1, 2, 2)
+            )
+            .iter()
+            .sorted()
+            .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            run_cycle(&hashset!(
+                (0, 0, -1, -1),
+                (2, 1, -1, -1),
+                (1, 2, -1, -1),
+                (0, 0, 0, -1),
+                (2, 1, 0, -1),
+                (1, 2, 0, -1),
+                (0, 0, 1, -1),
+                (2, 1, 1, -1),
+                (1, 2, 1, -1),
+                (0, 0, -1, 0),
+                (2, 1, -1, 0),
+                (1, 2, -1, 0),
+                (0, 0, 0, 0),
+                (2, 0, 0, 0),
+                (1, 1, 0, 0),
+                (2, 1, 0, 0),
+                (1, 2, 0, 0),
+                (0, 0, 1, 0),
+                (2, 1, 1, 0),
+                (1, 2, 1, 0),
+                (0, 0, -1, 1),
+                (2, 1, -1, 1),
+                (1, 2, -1, 1),
+                (0, 0, 0, 1),
+                (2, 1, 0, 1),
+                (1, 2, 0, 1),
+                (0, 0, 1, 1),
+                (2, 1, 1, 1),
+                (1, 2, 1, 1),
+            )),
+            hashset!(
+                (1, 1, -2, -2),
+                (-1, -1, 0, -2),
+                (0, -1, 0, -2),
+                (1, -1, 0, -2),
+                (-1, 0, 0, -2),
+                (0, 0, 0, -2),
+                (2, 0, 0, -2),
+                (3, 0, 0, -2),
+                (-1, 1, 0, -2),
+                (3, 1, 0, -2),
+                (0, 2, 0, -2),
+                (3, 2, 0, -2),
+                (0, 3, 0, -2),
+                (1, 3, 0, -2),
+                (2, 3, 0, -2),
+                (1, 1, 2, -2),
+                (-1, -1, -2, 0),
+                (0, -1, -2, 0),
+                (1, -1, -2, 0),
+                (-1, 0, -2, 0),
+                (0, 0, -2, 0),
+                (2, 0, -2, 0),
+                (3, 0, -2, 0),
+                (-1, 1, -2, 0),
+                (3, 1, -2, 0),
+                (0, 2, -2, 0),
+                (3, 2, -2, 0),
+                (0, 3, -2, 0),
+                (1, 3, -2, 0),
+                (2, 3, -2, 0),
+                (-1, -1, 2, 0),
+                (0, -1, 2, 0),
+                (1, -1, 2, 0),
+                (-1, 0, 2, 0),
+                (0, 0, 2, 0),
+                (2, 0, 2, 0),
+                (3, 0, 2, 0),
+                (-1, 1, 2, 0),
+                (3, 1, 2, 0),
+                (0, 2, 2, 0),
+                (3, 2, 2, 0),
+                (0, 3, 2, 0),
+                (1, 3, 2, 0),
+                (2, 3, 2, 0),
+                (1, 1, -2, 2),
+                (-1, -1, 0, 2),
+                (0, -1, 0, 2),
+                (1, -1, 0, 2),
+                (-1, 0, 0, 2),
+                (0, 0, 0, 2),
+                (2, 0, 0, 2),
+                (3, 0, 0, 2),
+                (-1, 1, 0, 2),
+                (3, 1, 0, 2),
+                (0, 2, 0, 2),
+                (3, 2, 0, 2),
+                (0, 3, 0, 2),
+                (1, 3, 0, 2),
+                (2, 3, 0, 2),
+                (1, 1, 2, 2)
+            )
+        )
+    }
+
+    // long-running test that passes, but is disabled to improve overall test runtime
+    // #[test]
+    // fn test_boot() {
+    //     assert_eq!(
+    //         boot(hashset!(
+    //             (1, -1, 0, 0),
+    //             (2, 0, 0, 0),
+    //             (0, 1, 0, 0),
+    //             (1, 1, 0, 0),
+    //             (2, 1, 0, 0)
+    //         )),
+    //         848
+    //     )
+    // }
+}
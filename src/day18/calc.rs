@@ -0,0 +1,291 @@
+//! An interactive calculator for homework expressions, driven one line at a time, for trying out
+//! the different precedence rules by hand.
+
+use crate::day18::{Env, Precedence, Term, Token};
+
+pub const HELP: &str = "\
+enter an expression to see how it groups and what it comes to, or a command.  expressions
+can use + - * / (integer division), unary -, parentheses, variables and let x = ... in ...
+    :let <name> = <expr> set a variable for the rest of the session
+    :vars                show the session's variables
+    :mode                show the current precedence rules
+    :mode <name>         switch precedence rules; <name> is one of
+                             equal (or part1): + and * bind equally, left to right
+                             addition-first (or part2): + binds before *
+                             standard: * binds before +
+    :help                show this message
+    :quit                leave the calculator";
+
+/// Which precedence rules expressions are read with.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Equal,
+    AdditionFirst,
+    Standard,
+}
+
+impl Mode {
+    pub fn from_name(name: &str) -> Option<Mode> {
+        match name {
+            "equal" | "part1" => Some(Mode::Equal),
+            "addition-first" | "part2" => Some(Mode::AdditionFirst),
+            "standard" => Some(Mode::Standard),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mode::Equal => "equal",
+            Mode::AdditionFirst => "addition-first",
+            Mode::Standard => "standard",
+        }
+    }
+
+    pub fn precedence(&self) -> Precedence<'static> {
+        match self {
+            Mode::Equal => Precedence::EQUAL,
+            Mode::AdditionFirst => Precedence::ADDITION_FIRST,
+            Mode::Standard => Precedence::STANDARD,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Command {
+    Eval(String),
+    Let(String, String),
+    Vars,
+    ShowMode,
+    SetMode(Mode),
+    Help,
+    Quit,
+}
+
+impl Command {
+    /// Lines starting with `:` are commands; anything else is an expression.
+    pub fn parse(line: &str) -> Result<Command, String> {
+        let line = line.trim();
+        let command = match line.strip_prefix(':') {
+            Some(command) => command,
+            None => return Ok(Command::Eval(line.to_string())),
+        };
+        if let Some(binding) = command.strip_prefix("let ") {
+            return match binding.split_once('=') {
+                Some((name, expr)) if is_name(name.trim()) => Ok(Command::Let(
+                    name.trim().to_string(),
+                    expr.trim().to_string(),
+                )),
+                _ => Err("expected ':let <name> = <expr>'".to_string()),
+            };
+        }
+        let words = command.split_whitespace().collect::<Vec<&str>>();
+        let command = match words.as_slice() {
+            ["v" | "vars"] => Command::Vars,
+            ["m" | "mode"] => Command::ShowMode,
+            ["m" | "mode", name] => Command::SetMode(
+                Mode::from_name(name).ok_or_else(|| format!("no mode called '{}'", name))?,
+            ),
+            ["h" | "help"] => Command::Help,
+            ["q" | "quit"] => Command::Quit,
+            _ => return Err(format!("can't understand '{}'; try ':help'", line)),
+        };
+        Ok(command)
+    }
+}
+
+/// Whether `name` can be used as a variable.
+fn is_name(name: &str) -> bool {
+    matches!(Term::parse(name).as_deref(), Ok([(_, Token::Ident(_))]))
+}
+
+pub struct Calculator {
+    mode: Mode,
+    env: Env,
+}
+
+impl Calculator {
+    pub fn new(mode: Mode) -> Calculator {
+        Calculator {
+            mode,
+            env: Env::new(),
+        }
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Carry out a command, returning what to show for it.  `Quit` is left to the caller.
+    pub fn execute(&mut self, command: Command) -> String {
+        match command {
+            Command::Eval(line) => self.eval(&line),
+            Command::Let(name, line) => {
+                let value = Term::read(&line, &self.mode.precedence())
+                    .map_err(|e| e.render(&line))
+                    .and_then(|term| term.eval(&self.env).map_err(|e| e.to_string()));
+                match value {
+                    Ok(value) => {
+                        self.env.insert(name.clone(), value);
+                        format!("{} = {}", name, value)
+                    }
+                    Err(message) => message,
+                }
+            }
+            Command::Vars => {
+                let mut vars = self
+                    .env
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect::<Vec<String>>();
+                vars.sort();
+                if vars.is_empty() {
+                    "no variables".to_string()
+                } else {
+                    vars.join("\n")
+                }
+            }
+            Command::ShowMode => format!("mode: {}", self.mode.name()),
+            Command::SetMode(mode) => {
+                self.mode = mode;
+                format!("mode: {}", mode.name())
+            }
+            Command::Help => HELP.to_string(),
+            Command::Quit => String::new(),
+        }
+    }
+
+    /// The value of an expression, with how it was grouped both inline and as a tree.
+    fn eval(&self, line: &str) -> String {
+        match Term::read(line, &self.mode.precedence()) {
+            Ok(term) => match term.eval(&self.env) {
+                Ok(value) => format!("= {}\n{}\n{}", value, term, term.tree().trim_end()),
+                Err(e) => format!("{}\n{}", e, term),
+            },
+            Err(e) => e.render(line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_eval() {
+        assert_eq!(
+            Command::parse(" 1 + 2 "),
+            Ok(Command::Eval("1 + 2".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!(
+            Command::parse(":mode part2"),
+            Ok(Command::SetMode(Mode::AdditionFirst))
+        )
+    }
+
+    #[test]
+    fn test_parse_unknown_mode() {
+        assert_eq!(
+            Command::parse(":mode backwards"),
+            Err("no mode called 'backwards'".to_string())
+        )
+    }
+
+    #[test]
+    fn test_parse_unknown_command() {
+        assert_eq!(
+            Command::parse(":frobnicate"),
+            Err("can't understand ':frobnicate'; try ':help'".to_string())
+        )
+    }
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(
+            Calculator::new(Mode::Equal).execute(Command::Eval("1 + 2 * 3".to_string())),
+            "= 9\n\
+             ((1 + 2) * 3)\n\
+             *\n\
+             ├── +\n\
+             │   ├── 1\n\
+             │   └── 2\n\
+             └── 3"
+        )
+    }
+
+    #[test]
+    fn test_switch_mode() {
+        let mut calculator = Calculator::new(Mode::Equal);
+        calculator.execute(Command::SetMode(Mode::Standard));
+        assert_eq!(
+            calculator
+                .execute(Command::Eval("1 + 2 * 3".to_string()))
+                .lines()
+                .take(2)
+                .collect::<Vec<&str>>(),
+            vec!["= 7", "(1 + (2 * 3))"]
+        )
+    }
+
+    #[test]
+    fn test_eval_error() {
+        assert_eq!(
+            Calculator::new(Mode::Equal).execute(Command::Eval("1 +".to_string())),
+            "line 1, column 3: expected a number after this operator (found '+')\n    1 +\n      ^"
+        )
+    }
+
+    #[test]
+    fn test_eval_deeply_nested() {
+        let line = format!("{}1", "(".repeat(200_000));
+        assert!(Calculator::new(Mode::Equal)
+            .execute(Command::Eval(line))
+            .contains("expression nested too deeply"))
+    }
+
+    #[test]
+    fn test_parse_let() {
+        assert_eq!(
+            Command::parse(":let x = 1 + 2"),
+            Ok(Command::Let("x".to_string(), "1 + 2".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_parse_let_bad_name() {
+        assert_eq!(
+            Command::parse(":let 2 = 3"),
+            Err("expected ':let <name> = <expr>'".to_string())
+        )
+    }
+
+    #[test]
+    fn test_session_variables() {
+        let mut calculator = Calculator::new(Mode::Standard);
+        calculator.execute(Command::Let("x".to_string(), "6 / 4".to_string()));
+        calculator.execute(Command::Let("y".to_string(), "-x - 1".to_string()));
+        assert_eq!(
+            (
+                calculator.execute(Command::Vars),
+                calculator
+                    .execute(Command::Eval("x * y".to_string()))
+                    .lines()
+                    .next()
+                    .map(str::to_string)
+            ),
+            ("x = 1\ny = -2".to_string(), Some("= -2".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_eval_division_by_zero() {
+        assert_eq!(
+            Calculator::new(Mode::Standard).execute(Command::Eval("1 / (2 - 2)".to_string())),
+            "division by zero ((2 - 2) is 0)\n(1 / (2 - 2))"
+        )
+    }
+}
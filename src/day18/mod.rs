@@ -0,0 +1,900 @@
+use crate::parse::{self, ParseError};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+pub mod calc;
+pub mod prob01;
+pub mod prob02;
+
+/// Parse every expression in the homework, using the provided precedence rules.
+pub fn parse(
+    input: &str,
+    parse_expr: impl Fn(&str) -> Result<Term, ParseError>,
+) -> Result<Vec<Term>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| parse_expr(line).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Term>, ParseError>>()
+}
+
+/// The sum of every expression's value, or why the first that can't be evaluated can't be.
+pub fn sum(exprs: &[Term]) -> Result<i64, EvalError> {
+    exprs
+        .iter()
+        .map(|expr| expr.eval(&Env::new()))
+        .sum::<Result<i64, EvalError>>()
+}
+
+/// A binary operator.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    pub fn symbol(&self) -> char {
+        match self {
+            Op::Add => '+',
+            Op::Sub => '-',
+            Op::Mul => '*',
+            Op::Div => '/',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+/// How tightly each operator binds, as a level (higher binds more tightly) and which way a run of
+/// operators at the same level groups.  Unary minus binds more tightly than any of them.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Precedence<'a>(pub &'a [(Op, u8, Assoc)]);
+
+impl Precedence<'_> {
+    /// Part 1: everything evaluated left to right.
+    pub const EQUAL: Precedence<'static> = Precedence(&[
+        (Op::Add, 1, Assoc::Left),
+        (Op::Sub, 1, Assoc::Left),
+        (Op::Mul, 1, Assoc::Left),
+        (Op::Div, 1, Assoc::Left),
+    ]);
+    /// Part 2: addition (and subtraction) before multiplication (and division).
+    pub const ADDITION_FIRST: Precedence<'static> = Precedence(&[
+        (Op::Add, 2, Assoc::Left),
+        (Op::Sub, 2, Assoc::Left),
+        (Op::Mul, 1, Assoc::Left),
+        (Op::Div, 1, Assoc::Left),
+    ]);
+    /// The usual rules: multiplication and division before addition and subtraction.
+    pub const STANDARD: Precedence<'static> = Precedence(&[
+        (Op::Add, 1, Assoc::Left),
+        (Op::Sub, 1, Assoc::Left),
+        (Op::Mul, 2, Assoc::Left),
+        (Op::Div, 2, Assoc::Left),
+    ]);
+
+    pub fn of(&self, op: Op) -> Option<(u8, Assoc)> {
+        self.0
+            .iter()
+            .find(|(entry, _, _)| *entry == op)
+            .map(|&(_, level, assoc)| (level, assoc))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Token {
+    Lit(i64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    Equals,
+    Let,
+    In,
+}
+
+impl Token {
+    /// The binary operator this token stands for, if it is one.
+    pub fn op(&self) -> Option<Op> {
+        match self {
+            Token::Plus => Some(Op::Add),
+            Token::Minus => Some(Op::Sub),
+            Token::Star => Some(Op::Mul),
+            Token::Slash => Some(Op::Div),
+            _ => None,
+        }
+    }
+
+    pub fn from_raw(char: char) -> Option<Token> {
+        match char {
+            '+' => Some(Token::Plus),
+            '-' => Some(Token::Minus),
+            '*' => Some(Token::Star),
+            '/' => Some(Token::Slash),
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            '=' => Some(Token::Equals),
+            'a'..='z' | 'A'..='Z' | '_' => Some(Token::Ident(char.to_string())),
+            _ => char.to_digit(10).map(|digit| Token::Lit(digit as i64)),
+        }
+    }
+
+    /// The keyword a complete word stands for, or the word as a variable name.
+    fn from_word(word: String) -> Token {
+        match word.as_str() {
+            "let" => Token::Let,
+            "in" => Token::In,
+            _ => Token::Ident(word),
+        }
+    }
+}
+
+/// A token along with the byte range of the line it was read from.
+pub type Spanned = (Range<usize>, Token);
+
+/// The values of the variables in scope.
+pub type Env = HashMap<String, i64>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum EvalError {
+    /// Division by a term that came to zero.
+    DivisionByZero(Term),
+    Unbound(String),
+    /// A result too large for an `i64`, in the term that produced it.
+    Overflow(Term),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::DivisionByZero(Term::Lit(_)) => write!(f, "division by zero"),
+            EvalError::DivisionByZero(divisor) => write!(f, "division by zero ({} is 0)", divisor),
+            EvalError::Unbound(name) => write!(f, "no variable called '{}'", name),
+            EvalError::Overflow(term) => write!(f, "{} is too large", term),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Term {
+    Lit(i64),
+    Var(String),
+    Neg(Box<Term>),
+    Add(Box<Term>, Box<Term>),
+    Sub(Box<Term>, Box<Term>),
+    Mul(Box<Term>, Box<Term>),
+    Div(Box<Term>, Box<Term>),
+    /// `let name = value in body`.
+    Let(String, Box<Term>, Box<Term>),
+}
+
+impl Term {
+    /// Split an expression into tokens, merging consecutive digits into a single literal and
+    /// consecutive letters, digits and underscores into a single word.
+    pub fn parse(line: &str) -> Result<Vec<Spanned>, ParseError> {
+        let mut merged_tokens: Vec<Spanned> = Vec::new();
+        for (i, char) in line.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+            let span = i..i + char.len_utf8();
+            let token = Token::from_raw(char).ok_or_else(|| {
+                ParseError::within(
+                    line,
+                    &line[span.clone()],
+                    "expected a digit, letter, operator or parenthesis",
+                )
+            })?;
+            match (merged_tokens.last_mut(), token) {
+                (Some((last_span, Token::Lit(lhs))), Token::Lit(rhs)) if last_span.end == i => {
+                    last_span.end = span.end;
+                    *lhs = lhs
+                        .checked_mul(10)
+                        .and_then(|lhs| lhs.checked_add(rhs))
+                        .ok_or_else(|| {
+                            ParseError::within(line, &line[last_span.clone()], "number too large")
+                        })?;
+                }
+                (Some((last_span, Token::Ident(word))), Token::Ident(_) | Token::Lit(_))
+                    if last_span.end == i =>
+                {
+                    last_span.end = span.end;
+                    word.push(char);
+                }
+                (_, token) => merged_tokens.push((span, token)),
+            }
+        }
+        Ok(merged_tokens
+            .into_iter()
+            .map(|(span, token)| match token {
+                Token::Ident(word) => (span, Token::from_word(word)),
+                token => (span, token),
+            })
+            .collect())
+    }
+
+    /// Build a term from the tokens of `line` by precedence climbing, binding operators
+    /// according to `precedence`.
+    pub fn lex(
+        line: &str,
+        tokens: &[Spanned],
+        precedence: &Precedence,
+    ) -> Result<Term, ParseError> {
+        let mut parser = Parser {
+            line,
+            tokens,
+            pos: 0,
+            precedence,
+            depth: 0,
+        };
+        let term = parser.expr(0)?;
+        match parser.peek() {
+            None => Ok(term),
+            Some(Token::RParen) => Err(parser.error("unmatched ')'")),
+            Some(_) => Err(parser.error("expected an operator between numbers")),
+        }
+    }
+
+    /// Tokenise and build a term from a single expression.
+    pub fn read(line: &str, precedence: &Precedence) -> Result<Term, ParseError> {
+        Term::lex(line, &Term::parse(line)?, precedence)
+    }
+
+    pub fn binary(op: Op, lhs: Term, rhs: Term) -> Term {
+        let (lhs, rhs) = (Box::new(lhs), Box::new(rhs));
+        match op {
+            Op::Add => Term::Add(lhs, rhs),
+            Op::Sub => Term::Sub(lhs, rhs),
+            Op::Mul => Term::Mul(lhs, rhs),
+            Op::Div => Term::Div(lhs, rhs),
+        }
+    }
+
+    /// The label for this term's node in a tree, and the terms below it.
+    fn node(&self) -> (String, Vec<&Term>) {
+        match self {
+            Term::Lit(val) => (val.to_string(), vec![]),
+            Term::Var(name) => (name.clone(), vec![]),
+            Term::Neg(term) => ("neg".to_string(), vec![term]),
+            Term::Add(lhs, rhs) => ("+".to_string(), vec![lhs, rhs]),
+            Term::Sub(lhs, rhs) => ("-".to_string(), vec![lhs, rhs]),
+            Term::Mul(lhs, rhs) => ("*".to_string(), vec![lhs, rhs]),
+            Term::Div(lhs, rhs) => ("/".to_string(), vec![lhs, rhs]),
+            Term::Let(name, value, body) => (format!("let {}", name), vec![value, body]),
+        }
+    }
+
+    /// The term drawn as a tree, one node per line, with each operator above its operands.
+    pub fn tree(&self) -> String {
+        let mut out = String::new();
+        self.draw(&mut out, "", "");
+        out
+    }
+
+    /// Draw this node after `lead`, and its children after `indent`.
+    fn draw(&self, out: &mut String, lead: &str, indent: &str) {
+        let (label, children) = self.node();
+        out.push_str(&format!("{}{}\n", lead, label));
+        for (i, child) in children.iter().enumerate() {
+            if i + 1 < children.len() {
+                child.draw(out, &format!("{}├── ", indent), &format!("{}│   ", indent));
+            } else {
+                child.draw(out, &format!("{}└── ", indent), &format!("{}    ", indent));
+            }
+        }
+    }
+
+    /// The value of the term, looking up variables in `env`.  Division is integer division,
+    /// rounding towards zero.
+    pub fn eval(&self, env: &Env) -> Result<i64, EvalError> {
+        let checked = |result: Option<i64>| result.ok_or_else(|| EvalError::Overflow(self.clone()));
+        match self {
+            Term::Lit(val) => Ok(*val),
+            Term::Var(name) => env
+                .get(name)
+                .copied()
+                .ok_or_else(|| EvalError::Unbound(name.clone())),
+            Term::Neg(term) => checked(term.eval(env)?.checked_neg()),
+            Term::Add(lhs, rhs) => checked(lhs.eval(env)?.checked_add(rhs.eval(env)?)),
+            Term::Sub(lhs, rhs) => checked(lhs.eval(env)?.checked_sub(rhs.eval(env)?)),
+            Term::Mul(lhs, rhs) => checked(lhs.eval(env)?.checked_mul(rhs.eval(env)?)),
+            Term::Div(lhs, rhs) => {
+                let (lhs, divisor) = (lhs.eval(env)?, rhs.eval(env)?);
+                if divisor == 0 {
+                    return Err(EvalError::DivisionByZero((**rhs).clone()));
+                }
+                checked(lhs.checked_div(divisor))
+            }
+            Term::Let(name, value, body) => {
+                let mut env = env.clone();
+                env.insert(name.clone(), value.eval(&env)?);
+                body.eval(&env)
+            }
+        }
+    }
+}
+
+/// Fully parenthesised, so that the grouping doesn't depend on any precedence rules.
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Lit(val) => write!(f, "{}", val),
+            Term::Var(name) => write!(f, "{}", name),
+            Term::Neg(term) => write!(f, "-{}", term),
+            Term::Add(lhs, rhs) => write!(f, "({} + {})", lhs, rhs),
+            Term::Sub(lhs, rhs) => write!(f, "({} - {})", lhs, rhs),
+            Term::Mul(lhs, rhs) => write!(f, "({} * {})", lhs, rhs),
+            Term::Div(lhs, rhs) => write!(f, "({} / {})", lhs, rhs),
+            Term::Let(name, value, body) => write!(f, "(let {} = {} in {})", name, value, body),
+        }
+    }
+}
+
+/// How deeply the parser may recurse, through parentheses, unary minus, `let` and operators
+/// grouping to the right, before giving up rather than running out of stack.
+const MAX_DEPTH: usize = 256;
+
+struct Parser<'a> {
+    line: &'a str,
+    tokens: &'a [Spanned],
+    pos: usize,
+    precedence: &'a Precedence<'a>,
+    /// How many calls to `expr` and `operand` are under way.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    /// An error at the token `pos` is on.
+    fn error(&self, message: &str) -> ParseError {
+        self.error_at(self.pos, message)
+    }
+
+    /// An error at the token at index `pos`, or at the end of the line if there isn't one.
+    fn error_at(&self, pos: usize, message: &str) -> ParseError {
+        match self.tokens.get(pos) {
+            Some((span, _)) => ParseError::within(self.line, &self.line[span.clone()], message),
+            None => ParseError::within(self.line, &self.line[self.line.len()..], message),
+        }
+    }
+
+    /// Move past the next token if it is `expected`, or fail with `message`.
+    fn expect(&mut self, expected: Token, message: &str) -> Result<(), ParseError> {
+        if self.peek() != Some(&expected) {
+            return Err(self.error(message));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Run `parse` one level deeper, or fail if that is too deep.
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Term, ParseError>,
+    ) -> Result<Term, ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("expression nested too deeply"));
+        }
+        self.depth += 1;
+        let term = parse(self);
+        self.depth -= 1;
+        term
+    }
+
+    /// Parse operands joined by operators that bind at least as tightly as `min_level`.
+    fn expr(&mut self, min_level: u8) -> Result<Term, ParseError> {
+        self.nested(|parser| parser.operators(min_level))
+    }
+
+    fn operators(&mut self, min_level: u8) -> Result<Term, ParseError> {
+        let mut lhs = self.operand()?;
+        while let Some(op) = self.peek().and_then(Token::op) {
+            let (level, assoc) = self
+                .precedence
+                .of(op)
+                .ok_or_else(|| self.error("no precedence given for this operator"))?;
+            if level < min_level {
+                break;
+            }
+            self.pos += 1;
+            if self.peek().is_none() {
+                return Err(self.error_at(self.pos - 1, "expected a number after this operator"));
+            }
+            let rhs = self.expr(match assoc {
+                Assoc::Left => level + 1,
+                Assoc::Right => level,
+            })?;
+            lhs = Term::binary(op, lhs, rhs);
+        }
+        Ok(lhs)
+    }
+
+    fn operand(&mut self) -> Result<Term, ParseError> {
+        self.nested(Parser::primary)
+    }
+
+    /// A literal, variable, negation, parenthesised expression or `let`.
+    fn primary(&mut self) -> Result<Term, ParseError> {
+        let start = self.pos;
+        let token = self.peek().cloned();
+        self.pos += 1;
+        match token {
+            Some(Token::Lit(val)) => Ok(Term::Lit(val)),
+            Some(Token::Ident(name)) => Ok(Term::Var(name)),
+            Some(Token::Minus) => match self.peek() {
+                Some(_) => Ok(Term::Neg(Box::new(self.operand()?))),
+                None => Err(self.error_at(start, "expected a number after this operator")),
+            },
+            Some(Token::LParen) => {
+                let term = self.expr(0)?;
+                match self.peek() {
+                    Some(Token::RParen) => {
+                        self.pos += 1;
+                        Ok(term)
+                    }
+                    None => Err(self.error_at(start, "unclosed '('")),
+                    Some(_) => Err(self.error("expected an operator or ')'")),
+                }
+            }
+            Some(Token::Let) => {
+                let name = match self.peek() {
+                    Some(Token::Ident(name)) => name.clone(),
+                    _ => return Err(self.error("expected a variable name after 'let'")),
+                };
+                self.pos += 1;
+                self.expect(Token::Equals, "expected '=' after the variable name")?;
+                let value = self.expr(0)?;
+                self.expect(Token::In, "expected 'in' after the value being bound")?;
+                let body = self.expr(0)?;
+                Ok(Term::Let(name, Box::new(value), Box::new(body)))
+            }
+            Some(Token::RParen) => Err(self.error_at(start, "expected an expression before ')'")),
+            Some(Token::Equals | Token::In) => Err(self.error_at(start, "expected an expression")),
+            Some(_) => Err(self.error_at(start, "expected a number before this operator")),
+            None => Err(self.error_at(start, "expected an expression")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_term_lit_eval() {
+        assert_eq!(Term::Lit(42).eval(&Env::new()), Ok(42))
+    }
+
+    #[test]
+    fn test_term_add_eval() {
+        assert_eq!(
+            Term::Add(Box::new(Term::Lit(40)), Box::new(Term::Lit(2))).eval(&Env::new()),
+            Ok(42)
+        )
+    }
+
+    #[test]
+    fn test_term_mul_eval() {
+        assert_eq!(
+            Term::Mul(Box::new(Term::Lit(21)), Box::new(Term::Lit(2))).eval(&Env::new()),
+            Ok(42)
+        )
+    }
+
+    fn tokens(line: &str) -> Vec<Token> {
+        Term::parse(line)
+            .unwrap()
+            .into_iter()
+            .map(|(_, token)| token)
+            .collect()
+    }
+
+    #[test]
+    fn test_term_parse_single_lit() {
+        assert_eq!(tokens("1"), vec![Token::Lit(1)])
+    }
+
+    #[test]
+    fn test_term_parse_consecutive_lit() {
+        assert_eq!(Term::parse("1234"), Ok(vec![(0..4, Token::Lit(1234))]))
+    }
+
+    #[test]
+    fn test_term_parse_separated_lit() {
+        assert_eq!(tokens("12 34"), vec![Token::Lit(12), Token::Lit(34)])
+    }
+
+    #[test]
+    fn test_term_parse_add() {
+        assert_eq!(tokens("+"), vec![Token::Plus])
+    }
+
+    #[test]
+    fn test_term_parse_mul() {
+        assert_eq!(tokens("*"), vec![Token::Star])
+    }
+
+    #[test]
+    fn test_term_parse_lparen() {
+        assert_eq!(tokens("("), vec![Token::LParen])
+    }
+
+    #[test]
+    fn test_term_parse_rparen() {
+        assert_eq!(tokens(")"), vec![Token::RParen])
+    }
+
+    #[test]
+    fn test_term_parse_mixed() {
+        assert_eq!(
+            tokens("1 + (23 * 2)"),
+            vec![
+                Token::Lit(1),
+                Token::Plus,
+                Token::LParen,
+                Token::Lit(23),
+                Token::Star,
+                Token::Lit(2),
+                Token::RParen
+            ]
+        )
+    }
+
+    #[test]
+    fn test_term_parse_bad_char() {
+        assert_eq!(
+            Term::parse("1 + $"),
+            Err(ParseError::new(
+                1,
+                5,
+                "$",
+                "expected a digit, letter, operator or parenthesis"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_parse_too_large() {
+        assert_eq!(
+            Term::parse("2 * 9999999999999999999"),
+            Err(ParseError::new(
+                1,
+                5,
+                "9999999999999999999",
+                "number too large"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_read_unclosed() {
+        assert_eq!(
+            Term::read("2 * (1 + 2", &Precedence::EQUAL),
+            Err(ParseError::new(1, 5, "(", "unclosed '('"))
+        )
+    }
+
+    #[test]
+    fn test_term_read_deeply_nested() {
+        let parens = format!("{}1{}", "(".repeat(200_000), ")".repeat(200_000));
+        let negs = format!("{}1", "-".repeat(200_000));
+        assert_eq!(
+            (
+                Term::read(&parens, &Precedence::EQUAL).map_err(|e| e.message),
+                Term::read(&negs, &Precedence::EQUAL).map_err(|e| e.message)
+            ),
+            (
+                Err("expression nested too deeply".to_string()),
+                Err("expression nested too deeply".to_string())
+            )
+        )
+    }
+
+    #[test]
+    fn test_term_read_nested() {
+        let parens = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+        assert_eq!(Term::read(&parens, &Precedence::EQUAL), Ok(Term::Lit(1)))
+    }
+
+    #[test]
+    fn test_term_read_trailing_operator() {
+        assert_eq!(
+            Term::read("1 + 2 *", &Precedence::EQUAL),
+            Err(ParseError::new(
+                1,
+                7,
+                "*",
+                "expected a number after this operator"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_read_leading_operator() {
+        assert_eq!(
+            Term::read("(* 2)", &Precedence::EQUAL),
+            Err(ParseError::new(
+                1,
+                2,
+                "*",
+                "expected a number before this operator"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_read_unmatched() {
+        assert_eq!(
+            Term::read("1 + 2)", &Precedence::EQUAL),
+            Err(ParseError::new(1, 6, ")", "unmatched ')'"))
+        )
+    }
+
+    #[test]
+    fn test_term_read_empty_parens() {
+        assert_eq!(
+            Term::read("1 + ()", &Precedence::EQUAL),
+            Err(ParseError::new(
+                1,
+                6,
+                ")",
+                "expected an expression before ')'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_read_missing_operator() {
+        assert_eq!(
+            Term::read("1 (2)", &Precedence::EQUAL),
+            Err(ParseError::new(
+                1,
+                3,
+                "(",
+                "expected an operator between numbers"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_read_missing_operator_in_parens() {
+        assert_eq!(
+            Term::read("(1 2)", &Precedence::EQUAL),
+            Err(ParseError::new(1, 4, "2", "expected an operator or ')'"))
+        )
+    }
+
+    #[test]
+    fn test_term_read_empty() {
+        assert_eq!(
+            Term::read("", &Precedence::EQUAL),
+            Err(ParseError::new(1, 1, "", "expected an expression"))
+        )
+    }
+
+    #[test]
+    fn test_term_read_render() {
+        assert_eq!(
+            Term::read("1 + (2 * 3", &Precedence::EQUAL)
+                .unwrap_err()
+                .render("1 + (2 * 3"),
+            "line 1, column 5: unclosed '(' (found '(')\n    1 + (2 * 3\n        ^"
+        )
+    }
+
+    #[test]
+    fn test_term_read_standard() {
+        assert_eq!(
+            Term::read("2 * 3 + 4 * 5", &Precedence::STANDARD)
+                .unwrap()
+                .eval(&Env::new()),
+            Ok(26)
+        )
+    }
+
+    #[test]
+    fn test_term_read_right_assoc() {
+        assert_eq!(
+            Term::read("1 + 2 + 3", &Precedence(&[(Op::Add, 1, Assoc::Right)])),
+            Ok(Term::Add(
+                Box::new(Term::Lit(1)),
+                Box::new(Term::Add(Box::new(Term::Lit(2)), Box::new(Term::Lit(3))))
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_read_missing_precedence() {
+        assert_eq!(
+            Term::read("1 * 2", &Precedence(&[(Op::Add, 1, Assoc::Left)])),
+            Err(ParseError::new(
+                1,
+                3,
+                "*",
+                "no precedence given for this operator"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_display() {
+        assert_eq!(
+            Term::read("1 + 2 * (3 + 4)", &Precedence::STANDARD)
+                .unwrap()
+                .to_string(),
+            "(1 + (2 * (3 + 4)))"
+        )
+    }
+
+    #[test]
+    fn test_term_tree() {
+        assert_eq!(
+            Term::read("1 + 2 * 3", &Precedence::EQUAL).unwrap().tree(),
+            "*\n\
+             ├── +\n\
+             │   ├── 1\n\
+             │   └── 2\n\
+             └── 3\n"
+        )
+    }
+
+    fn eval(line: &str) -> Result<i64, EvalError> {
+        Term::read(line, &Precedence::STANDARD)
+            .unwrap()
+            .eval(&Env::new())
+    }
+
+    #[test]
+    fn test_term_parse_words() {
+        assert_eq!(
+            tokens("let x_1 = 2 in x_1"),
+            vec![
+                Token::Let,
+                Token::Ident("x_1".to_string()),
+                Token::Equals,
+                Token::Lit(2),
+                Token::In,
+                Token::Ident("x_1".to_string())
+            ]
+        )
+    }
+
+    #[test]
+    fn test_eval_sub_div() {
+        assert_eq!(eval("20 - 6 / 4 - 3"), Ok(16))
+    }
+
+    #[test]
+    fn test_eval_div_truncates() {
+        assert_eq!(eval("-7 / 2"), Ok(-3))
+    }
+
+    #[test]
+    fn test_eval_neg() {
+        assert_eq!(eval("2 * -(3 + 4) - -1"), Ok(-13))
+    }
+
+    #[test]
+    fn test_read_neg_binds_tightly() {
+        assert_eq!(
+            Term::read("-2 + 3", &Precedence::EQUAL),
+            Ok(Term::Add(
+                Box::new(Term::Neg(Box::new(Term::Lit(2)))),
+                Box::new(Term::Lit(3))
+            ))
+        )
+    }
+
+    #[test]
+    fn test_eval_division_by_zero() {
+        assert_eq!(
+            eval("1 / (2 - 2)"),
+            Err(EvalError::DivisionByZero(Term::Sub(
+                Box::new(Term::Lit(2)),
+                Box::new(Term::Lit(2))
+            )))
+        )
+    }
+
+    #[test]
+    fn test_sum_division_by_zero() {
+        assert_eq!(
+            sum(&[
+                Term::Lit(1),
+                Term::Div(Box::new(Term::Lit(1)), Box::new(Term::Lit(0)))
+            ]),
+            Err(EvalError::DivisionByZero(Term::Lit(0)))
+        )
+    }
+
+    #[test]
+    fn test_eval_overflow() {
+        assert_eq!(
+            eval("9223372036854775807 + 1").unwrap_err().to_string(),
+            "(9223372036854775807 + 1) is too large"
+        )
+    }
+
+    #[test]
+    fn test_eval_let() {
+        assert_eq!(eval("let x = 2 + 3 in x * (let x = 1 in x + 1)"), Ok(10))
+    }
+
+    #[test]
+    fn test_eval_env() {
+        assert_eq!(
+            Term::read("x - y", &Precedence::STANDARD)
+                .unwrap()
+                .eval(&hashmap!("x".to_string() => 5, "y".to_string() => 7)),
+            Ok(-2)
+        )
+    }
+
+    #[test]
+    fn test_eval_unbound() {
+        assert_eq!(
+            eval("let x = 1 in y"),
+            Err(EvalError::Unbound("y".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_read_let_missing_in() {
+        assert_eq!(
+            Term::read("let x = 1", &Precedence::STANDARD),
+            Err(ParseError::new(
+                1,
+                10,
+                "",
+                "expected 'in' after the value being bound"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_read_let_missing_name() {
+        assert_eq!(
+            Term::read("let 1 = 1 in 1", &Precedence::STANDARD),
+            Err(ParseError::new(
+                1,
+                5,
+                "1",
+                "expected a variable name after 'let'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_term_display_let() {
+        assert_eq!(
+            Term::read("let x = -1 in x / 2", &Precedence::STANDARD)
+                .unwrap()
+                .to_string(),
+            "(let x = -1 in (x / 2))"
+        )
+    }
+
+    #[test]
+    fn test_term_tree_let() {
+        assert_eq!(
+            Term::read("let x = -1 in x", &Precedence::STANDARD)
+                .unwrap()
+                .tree(),
+            "let x\n\
+             ├── neg\n\
+             │   └── 1\n\
+             └── x\n"
+        )
+    }
+}
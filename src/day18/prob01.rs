@@ -0,0 +1,88 @@
+use crate::day18::{Precedence, Term};
+use crate::parse::ParseError;
+
+/// Parse an expression where `+` and `*` have the same precedence.
+pub fn parse_expr(line: &str) -> Result<Term, ParseError> {
+    Term::read(line, &Precedence::EQUAL)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_expr_lit() {
+        assert_eq!(parse_expr("42").unwrap(), Term::Lit(42))
+    }
+
+    #[test]
+    fn test_parse_expr_add() {
+        assert_eq!(
+            parse_expr("40 + 2").unwrap(),
+            Term::Add(Box::new(Term::Lit(40)), Box::new(Term::Lit(2)))
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_mul() {
+        assert_eq!(
+            parse_expr("21 * 2").unwrap(),
+            Term::Mul(Box::new(Term::Lit(21)), Box::new(Term::Lit(2)))
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_chain() {
+        assert_eq!(
+            parse_expr("10 + 11 * 2").unwrap(),
+            Term::Mul(
+                Box::new(Term::Add(Box::new(Term::Lit(10)), Box::new(Term::Lit(11)))),
+                Box::new(Term::Lit(2))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_paren() {
+        assert_eq!(
+            parse_expr("10 + (11 * 2)").unwrap(),
+            Term::Add(
+                Box::new(Term::Lit(10)),
+                Box::new(Term::Mul(Box::new(Term::Lit(11)), Box::new(Term::Lit(2))))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_paren_noop() {
+        assert_eq!(parse_expr("(42)").unwrap(), Term::Lit(42))
+    }
+
+    #[test]
+    fn test_parse_expr_paren_nested() {
+        assert_eq!(
+            parse_expr("10 + (11 * (2 + 4))").unwrap(),
+            Term::Add(
+                Box::new(Term::Lit(10)),
+                Box::new(Term::Mul(
+                    Box::new(Term::Lit(11)),
+                    Box::new(Term::Add(Box::new(Term::Lit(2)), Box::new(Term::Lit(4))))
+                ))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_paren_double_open() {
+        assert_eq!(
+            parse_expr("10 + ((11 + 2) * (2 + 4))").unwrap(),
+            Term::Add(
+                Box::new(Term::Lit(10)),
+                Box::new(Term::Mul(
+                    Box::new(Term::Add(Box::new(Term::Lit(11)), Box::new(Term::Lit(2)))),
+                    Box::new(Term::Add(Box::new(Term::Lit(2)), Box::new(Term::Lit(4))))
+                ))
+            )
+        )
+    }
+}
@@ -0,0 +1,168 @@
+use crate::day18::{Precedence, Term};
+use crate::parse::ParseError;
+
+/// Parse an expression where `+` binds more tightly than `*`.
+pub fn parse_expr(line: &str) -> Result<Term, ParseError> {
+    Term::read(line, &Precedence::ADDITION_FIRST)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day18::Env;
+
+    #[test]
+    fn test_parse_expr_lit() {
+        assert_eq!(parse_expr("42").unwrap(), Term::Lit(42))
+    }
+
+    #[test]
+    fn test_parse_expr_add() {
+        assert_eq!(
+            parse_expr("40 + 2").unwrap(),
+            Term::Add(Box::new(Term::Lit(40)), Box::new(Term::Lit(2)))
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_mul() {
+        assert_eq!(
+            parse_expr("21 * 2").unwrap(),
+            Term::Mul(Box::new(Term::Lit(21)), Box::new(Term::Lit(2)))
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_chain() {
+        assert_eq!(
+            parse_expr("10 + 11 + 2").unwrap(),
+            Term::Add(
+                Box::new(Term::Add(Box::new(Term::Lit(10)), Box::new(Term::Lit(11)))),
+                Box::new(Term::Lit(2))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_order_of_ops1() {
+        assert_eq!(
+            parse_expr("10 * 11 + 2 * 4").unwrap(),
+            Term::Mul(
+                Box::new(Term::Mul(
+                    Box::new(Term::Lit(10)),
+                    Box::new(Term::Add(Box::new(Term::Lit(11)), Box::new(Term::Lit(2))))
+                )),
+                Box::new(Term::Lit(4))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_order_of_ops2() {
+        assert_eq!(
+            parse_expr("10 * (11 * 0) + (2 * 0) * 4").unwrap(),
+            Term::Mul(
+                Box::new(Term::Mul(
+                    Box::new(Term::Lit(10)),
+                    Box::new(Term::Add(
+                        Box::new(Term::Mul(Box::new(Term::Lit(11)), Box::new(Term::Lit(0)))),
+                        Box::new(Term::Mul(Box::new(Term::Lit(2)), Box::new(Term::Lit(0))))
+                    ))
+                )),
+                Box::new(Term::Lit(4))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_paren() {
+        assert_eq!(
+            parse_expr("10 + (11 * 2)").unwrap(),
+            Term::Add(
+                Box::new(Term::Lit(10)),
+                Box::new(Term::Mul(Box::new(Term::Lit(11)), Box::new(Term::Lit(2))))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_paren_noop() {
+        assert_eq!(parse_expr("(42)").unwrap(), Term::Lit(42))
+    }
+
+    #[test]
+    fn test_parse_expr_paren_nested() {
+        assert_eq!(
+            parse_expr("10 + (11 * (2 + 4))").unwrap(),
+            Term::Add(
+                Box::new(Term::Lit(10)),
+                Box::new(Term::Mul(
+                    Box::new(Term::Lit(11)),
+                    Box::new(Term::Add(Box::new(Term::Lit(2)), Box::new(Term::Lit(4))))
+                ))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_expr_paren_double_open() {
+        assert_eq!(
+            parse_expr("10 + ((11 + 2) * (2 + 4))").unwrap(),
+            Term::Add(
+                Box::new(Term::Lit(10)),
+                Box::new(Term::Mul(
+                    Box::new(Term::Add(Box::new(Term::Lit(11)), Box::new(Term::Lit(2)))),
+                    Box::new(Term::Add(Box::new(Term::Lit(2)), Box::new(Term::Lit(4))))
+                ))
+            )
+        )
+    }
+
+    #[test]
+    fn test_eval1() {
+        assert_eq!(
+            parse_expr("1 + (2 * 3) + (4 * (5 + 6))")
+                .unwrap()
+                .eval(&Env::new()),
+            Ok(51)
+        )
+    }
+
+    #[test]
+    fn test_eval2() {
+        assert_eq!(
+            parse_expr("2 * 3 + (4 * 5)").unwrap().eval(&Env::new()),
+            Ok(46)
+        )
+    }
+
+    #[test]
+    fn test_eval3() {
+        assert_eq!(
+            parse_expr("5 + (8 * 3 + 9 + 3 * 4 * 3)")
+                .unwrap()
+                .eval(&Env::new()),
+            Ok(1445)
+        )
+    }
+
+    #[test]
+    fn test_eval4() {
+        assert_eq!(
+            parse_expr("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")
+                .unwrap()
+                .eval(&Env::new()),
+            Ok(669060)
+        )
+    }
+
+    #[test]
+    fn test_eval5() {
+        assert_eq!(
+            parse_expr("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")
+                .unwrap()
+                .eval(&Env::new()),
+            Ok(23340)
+        )
+    }
+}
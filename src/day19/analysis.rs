@@ -0,0 +1,493 @@
+//! Static checks on a set of rules, to find mistakes before any messages are validated.
+
+use crate::day19::earley::nullable;
+use crate::day19::Symbol;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// The shortest and longest messages a rule can match; `max` is `None` if there is no limit.
+/// Lengths too long to count stop at `usize::MAX`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Lengths {
+    pub min: usize,
+    pub max: Option<usize>,
+}
+
+impl fmt::Display for Lengths {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{}", max),
+            Some(max) => write!(f, "{}..={}", self.min, max),
+            None => write!(f, "{}..", self.min),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Report {
+    /// Each reference to a rule that isn't defined, as the rule it's in and the missing rule.
+    pub undefined: Vec<(usize, usize)>,
+    pub unreachable: Vec<usize>,
+    /// Rules that can start by matching themselves, which sends a top-down parser round forever.
+    pub left_recursive: Vec<usize>,
+    /// Rules that can never finish matching a message, however long.
+    pub unproductive: Vec<usize>,
+    /// The lengths of message every other rule can match.
+    pub lengths: BTreeMap<usize, Lengths>,
+}
+
+impl Report {
+    /// Whether nothing is wrong with the grammar.  Left recursion isn't a mistake as such, so it
+    /// doesn't count.
+    pub fn is_clean(&self) -> bool {
+        self.undefined.is_empty() && self.unreachable.is_empty() && self.unproductive.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |rules: &[usize]| {
+            rules
+                .iter()
+                .map(|rule| rule.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        for (rule, missing) in &self.undefined {
+            writeln!(f, "rule {} refers to undefined rule {}", rule, missing)?;
+        }
+        if !self.unreachable.is_empty() {
+            writeln!(f, "unreachable from rule 0: {}", list(&self.unreachable))?;
+        }
+        if !self.left_recursive.is_empty() {
+            writeln!(f, "left recursive: {}", list(&self.left_recursive))?;
+        }
+        if !self.unproductive.is_empty() {
+            writeln!(f, "never match a message: {}", list(&self.unproductive))?;
+        }
+        writeln!(f, "message lengths:")?;
+        for (rule, lengths) in &self.lengths {
+            writeln!(f, "    {}: {}", rule, lengths)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run every check on `grammar`, whose messages are matched by rule `start`.
+pub fn check(grammar: &HashMap<usize, Symbol>, start: usize) -> Report {
+    let productive = productive(grammar);
+    let mut unproductive = grammar
+        .keys()
+        .filter(|rule| !productive.contains(rule))
+        .copied()
+        .collect::<Vec<usize>>();
+    unproductive.sort_unstable();
+    Report {
+        undefined: undefined(grammar),
+        unreachable: unreachable(grammar, start),
+        left_recursive: left_recursive(grammar),
+        unproductive,
+        lengths: lengths(grammar),
+    }
+}
+
+/// The rules each rule refers to, in every alternative.
+fn references(sym: &Symbol) -> impl Iterator<Item = &usize> {
+    let prods: &[Vec<usize>] = match sym {
+        Symbol::Term(_) => &[],
+        Symbol::Nonterm(prods) => prods,
+    };
+    prods.iter().flatten()
+}
+
+pub fn undefined(grammar: &HashMap<usize, Symbol>) -> Vec<(usize, usize)> {
+    let mut undefined = grammar
+        .iter()
+        .flat_map(|(rule, sym)| {
+            references(sym)
+                .filter(|referenced| !grammar.contains_key(referenced))
+                .map(move |referenced| (*rule, *referenced))
+        })
+        .collect::<Vec<(usize, usize)>>();
+    undefined.sort_unstable();
+    undefined.dedup();
+    undefined
+}
+
+/// Every rule reachable from `from`, including itself, following the edges given by `next`.
+fn reachable(from: usize, next: impl Fn(usize) -> Vec<usize>) -> HashSet<usize> {
+    let mut seen = HashSet::new();
+    let mut todo = vec![from];
+    while let Some(rule) = todo.pop() {
+        if seen.insert(rule) {
+            todo.extend(next(rule));
+        }
+    }
+    seen
+}
+
+pub fn unreachable(grammar: &HashMap<usize, Symbol>, start: usize) -> Vec<usize> {
+    let reached = reachable(start, |rule| match grammar.get(&rule) {
+        Some(sym) => references(sym).copied().collect(),
+        None => vec![],
+    });
+    let mut unreachable = grammar
+        .keys()
+        .filter(|rule| !reached.contains(rule))
+        .copied()
+        .collect::<Vec<usize>>();
+    unreachable.sort_unstable();
+    unreachable
+}
+
+/// The rules that some alternative of `rule` can start with, skipping over any rules before it
+/// that can match nothing.
+fn left_corners(
+    grammar: &HashMap<usize, Symbol>,
+    nullable: &HashSet<usize>,
+    rule: usize,
+) -> Vec<usize> {
+    match grammar.get(&rule) {
+        Some(Symbol::Nonterm(prods)) => prods
+            .iter()
+            .flat_map(|prod| {
+                let leading = prod
+                    .iter()
+                    .position(|rule| !nullable.contains(rule))
+                    .map_or(prod.len(), |i| i + 1);
+                prod[..leading].iter().copied()
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub fn left_recursive(grammar: &HashMap<usize, Symbol>) -> Vec<usize> {
+    let nullable = nullable(grammar);
+    let mut left_recursive = grammar
+        .keys()
+        .filter(|&&rule| {
+            left_corners(grammar, &nullable, rule)
+                .into_iter()
+                .any(|corner| {
+                    reachable(corner, |rule| left_corners(grammar, &nullable, rule)).contains(&rule)
+                })
+        })
+        .copied()
+        .collect::<Vec<usize>>();
+    left_recursive.sort_unstable();
+    left_recursive
+}
+
+/// The rules that can match at least one message.
+pub fn productive(grammar: &HashMap<usize, Symbol>) -> HashSet<usize> {
+    fixpoint(grammar, |productive, sym| match sym {
+        Symbol::Term(_) => true,
+        Symbol::Nonterm(prods) => prods
+            .iter()
+            .any(|prod| prod.iter().all(|rule| productive.contains(rule))),
+    })
+}
+
+/// The smallest set of rules for which `holds` is true, given the set found so far.
+fn fixpoint(
+    grammar: &HashMap<usize, Symbol>,
+    holds: impl Fn(&HashSet<usize>, &Symbol) -> bool,
+) -> HashSet<usize> {
+    let mut found = HashSet::new();
+    loop {
+        let more = grammar
+            .iter()
+            .filter(|(rule, sym)| !found.contains(*rule) && holds(&found, sym))
+            .map(|(rule, _)| *rule)
+            .collect::<Vec<usize>>();
+        if more.is_empty() {
+            return found;
+        }
+        found.extend(more);
+    }
+}
+
+/// How deep the shallowest derivation of every productive rule goes: 1 for a character, and one
+/// more than the alternative with the least height otherwise.  Always following that alternative
+/// finishes, as each rule it uses has a smaller height than the last.
+pub fn heights(grammar: &HashMap<usize, Symbol>) -> BTreeMap<usize, usize> {
+    let mut heights = BTreeMap::new();
+    loop {
+        // Every rule found in a round has the same height, one more than the round before's.
+        let more = grammar
+            .iter()
+            .filter(|(rule, _)| !heights.contains_key(*rule))
+            .filter_map(|(rule, sym)| {
+                let height = match sym {
+                    Symbol::Term(_) => Some(1),
+                    Symbol::Nonterm(prods) => prods
+                        .iter()
+                        .filter_map(|prod| height(&heights, prod))
+                        .min()
+                        .map(|height| height + 1),
+                };
+                Some((*rule, height?))
+            })
+            .collect::<Vec<(usize, usize)>>();
+        if more.is_empty() {
+            return heights;
+        }
+        heights.extend(more);
+    }
+}
+
+/// The height of an alternative, that of its deepest rule, or `None` if some rule in it has no
+/// height in `heights`.
+pub fn height(heights: &BTreeMap<usize, usize>, prod: &[usize]) -> Option<usize> {
+    prod.iter()
+        .try_fold(0, |height, rule| Some(height.max(*heights.get(rule)?)))
+}
+
+/// The shortest and longest message every productive rule can match.
+pub fn lengths(grammar: &HashMap<usize, Symbol>) -> BTreeMap<usize, Lengths> {
+    let productive = productive(grammar);
+    // Only alternatives that can finish matching say anything about length.
+    let prods = |sym: &Symbol| -> Vec<Vec<usize>> {
+        match sym {
+            Symbol::Term(_) => vec![],
+            Symbol::Nonterm(prods) => prods
+                .iter()
+                .filter(|prod| prod.iter().all(|rule| productive.contains(rule)))
+                .cloned()
+                .collect(),
+        }
+    };
+    let nonempty = fixpoint(grammar, |nonempty, sym| match sym {
+        Symbol::Term(_) => true,
+        Symbol::Nonterm(_) => prods(sym)
+            .iter()
+            .any(|prod| prod.iter().any(|rule| nonempty.contains(rule))),
+    });
+
+    // A rule can match messages of any length if it can reach a loop that grows the message each
+    // time round: some rule that can include itself alongside something that isn't empty.
+    let children = |rule: usize| prods(&grammar[&rule]).into_iter().flatten().collect();
+    let pumping = productive
+        .iter()
+        .filter(|&&rule| {
+            prods(&grammar[&rule]).iter().any(|prod| {
+                prod.iter().enumerate().any(|(i, &child)| {
+                    let grows = prod
+                        .iter()
+                        .enumerate()
+                        .any(|(j, sibling)| i != j && nonempty.contains(sibling));
+                    grows && reachable(child, children).contains(&rule)
+                })
+            })
+        })
+        .copied()
+        .collect::<HashSet<usize>>();
+    let unbounded = productive
+        .iter()
+        .filter(|&&rule| !reachable(rule, children).is_disjoint(&pumping))
+        .copied()
+        .collect::<HashSet<usize>>();
+
+    let mut min: HashMap<usize, usize> = HashMap::new();
+    let mut max: HashMap<usize, usize> = HashMap::new();
+    loop {
+        let mut changed = false;
+        for &rule in &productive {
+            let sym = &grammar[&rule];
+            let (shortest, longest) = match sym {
+                Symbol::Term(_) => (Some(1), Some(1)),
+                Symbol::Nonterm(_) => {
+                    let total = |lengths: &HashMap<usize, usize>, prod: &Vec<usize>| {
+                        prod.iter().try_fold(0usize, |total, rule| {
+                            Some(total.saturating_add(*lengths.get(rule)?))
+                        })
+                    };
+                    let prods = prods(sym);
+                    (
+                        prods.iter().filter_map(|prod| total(&min, prod)).min(),
+                        prods.iter().filter_map(|prod| total(&max, prod)).max(),
+                    )
+                }
+            };
+            if let Some(shortest) = shortest {
+                if min.get(&rule).is_none_or(|&old| shortest < old) {
+                    min.insert(rule, shortest);
+                    changed = true;
+                }
+            }
+            if let Some(longest) = longest.filter(|_| !unbounded.contains(&rule)) {
+                if max.get(&rule).is_none_or(|&old| longest > old) {
+                    max.insert(rule, longest);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    min.into_iter()
+        .map(|(rule, min)| {
+            (
+                rule,
+                Lengths {
+                    min,
+                    max: max.get(&rule).copied(),
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> HashMap<usize, Symbol> {
+        hashmap!(
+            0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+            1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+            2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+            3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+            4 => Symbol::Term('a'),
+            5 => Symbol::Term('b')
+        )
+    }
+
+    #[test]
+    fn test_check_clean() {
+        let report = check(&example(), 0);
+        assert!(report.is_clean() && report.left_recursive.is_empty())
+    }
+
+    #[test]
+    fn test_undefined() {
+        assert_eq!(
+            undefined(&hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 2], vec![2]]),
+                1 => Symbol::Term('a')
+            )),
+            vec![(0, 2)]
+        )
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let mut grammar = example();
+        grammar.insert(6, Symbol::Nonterm(vec![vec![7]]));
+        grammar.insert(7, Symbol::Term('c'));
+        assert_eq!(unreachable(&grammar, 0), vec![6, 7])
+    }
+
+    #[test]
+    fn test_left_recursive() {
+        assert_eq!(
+            left_recursive(&hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1]]),
+                1 => Symbol::Nonterm(vec![vec![3], vec![2, 1, 3]]),
+                2 => Symbol::Nonterm(vec![vec![], vec![0]]),
+                3 => Symbol::Term('a'),
+                4 => Symbol::Nonterm(vec![vec![3, 4], vec![3]])
+            )),
+            vec![0, 1, 2]
+        )
+    }
+
+    #[test]
+    fn test_unproductive() {
+        let report = check(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1], vec![2], vec![3]]),
+                1 => Symbol::Nonterm(vec![vec![3, 1]]),
+                2 => Symbol::Nonterm(vec![vec![4]]),
+                3 => Symbol::Term('a')
+            ),
+            0,
+        );
+        assert_eq!(report.unproductive, vec![1, 2])
+    }
+
+    #[test]
+    fn test_heights() {
+        assert_eq!(
+            heights(&hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1], vec![2]]),
+                1 => Symbol::Nonterm(vec![vec![0]]),
+                2 => Symbol::Nonterm(vec![vec![3, 4]]),
+                3 => Symbol::Term('a'),
+                4 => Symbol::Nonterm(vec![vec![], vec![4, 4]]),
+                5 => Symbol::Nonterm(vec![vec![5, 3]])
+            )),
+            btreemap!(0 => 3, 1 => 4, 2 => 2, 3 => 1, 4 => 1)
+        )
+    }
+
+    #[test]
+    fn test_lengths() {
+        assert_eq!(
+            lengths(&example())[&0],
+            Lengths {
+                min: 6,
+                max: Some(6)
+            }
+        )
+    }
+
+    #[test]
+    fn test_lengths_saturate() {
+        let mut grammar = (0..70)
+            .map(|i| (i, Symbol::Nonterm(vec![vec![i + 1, i + 1]])))
+            .collect::<HashMap<usize, Symbol>>();
+        grammar.insert(70, Symbol::Term('a'));
+        assert_eq!(
+            lengths(&grammar)[&0],
+            Lengths {
+                min: usize::MAX,
+                max: Some(usize::MAX)
+            }
+        )
+    }
+
+    #[test]
+    fn test_lengths_loops() {
+        assert_eq!(
+            lengths(&hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 2]]),
+                1 => Symbol::Nonterm(vec![vec![3], vec![3, 1]]),
+                2 => Symbol::Nonterm(vec![vec![3, 3], vec![2], vec![]]),
+                3 => Symbol::Term('a')
+            )),
+            btreemap!(
+                0 => Lengths { min: 1, max: None },
+                1 => Lengths { min: 1, max: None },
+                2 => Lengths { min: 0, max: Some(2) },
+                3 => Lengths { min: 1, max: Some(1) }
+            )
+        )
+    }
+
+    #[test]
+    fn test_report_display() {
+        let report = check(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![0, 1], vec![1]]),
+                1 => Symbol::Nonterm(vec![vec![2], vec![3]]),
+                2 => Symbol::Term('a'),
+                4 => Symbol::Term('b')
+            ),
+            0,
+        );
+        assert_eq!(
+            report.to_string(),
+            "rule 1 refers to undefined rule 3\n\
+             unreachable from rule 0: 4\n\
+             left recursive: 0\n\
+             message lengths:\n\
+             \x20   0: 1..\n\
+             \x20   1: 1\n\
+             \x20   2: 1\n\
+             \x20   4: 1\n"
+        )
+    }
+}
@@ -0,0 +1,235 @@
+//! Compiling grammars without recursion into a single regular expression.
+
+use crate::day19::Symbol;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CompileError {
+    /// The rules, in order, along a path that leads back to where it started.
+    Recursive(Vec<usize>),
+    MissingRule(usize),
+    /// The rule whose pattern grew past `MAX_PATTERN_LEN`.
+    TooLong(usize),
+    /// The regex crate wouldn't take the pattern, usually because it compiles too big.
+    Regex(regex::Error),
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompileError::Recursive(cycle) => write!(
+                f,
+                "the grammar is recursive, so has no regex: {}",
+                cycle
+                    .iter()
+                    .map(|rule| rule.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" -> ")
+            ),
+            CompileError::MissingRule(rule) => write!(f, "no rule {}", rule),
+            CompileError::TooLong(rule) => write!(
+                f,
+                "rule {} compiles to more than {} bytes of regex",
+                rule, MAX_PATTERN_LEN
+            ),
+            CompileError::Regex(e) => write!(f, "the regex crate rejected the pattern: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+/// The longest pattern any rule may compile to.  Alternatives that each use a rule more than once
+/// can double the pattern at every level, and a grammar that does that is better checked a
+/// message at a time.
+pub const MAX_PATTERN_LEN: usize = 100_000;
+
+/// The pattern for rule `start`, anchored so that it only matches whole messages.
+pub fn pattern(grammar: &HashMap<usize, Symbol>, start: usize) -> Result<String, CompileError> {
+    let mut compiler = Compiler {
+        grammar,
+        done: HashMap::new(),
+        path: Vec::new(),
+    };
+    Ok(format!("^{}$", compiler.rule(start)?))
+}
+
+pub fn compile(grammar: &HashMap<usize, Symbol>, start: usize) -> Result<Regex, CompileError> {
+    let pattern = pattern(grammar, start)?;
+    debug!(
+        "compiled rule {} into {} bytes of regex",
+        start,
+        pattern.len()
+    );
+    Regex::new(&pattern).map_err(CompileError::Regex)
+}
+
+struct Compiler<'a> {
+    grammar: &'a HashMap<usize, Symbol>,
+    /// The pattern for every rule compiled so far.
+    done: HashMap<usize, String>,
+    /// The rules being compiled, outermost first.
+    path: Vec<usize>,
+}
+
+impl Compiler<'_> {
+    fn rule(&mut self, rule: usize) -> Result<String, CompileError> {
+        if let Some(pattern) = self.done.get(&rule) {
+            return Ok(pattern.clone());
+        }
+        if let Some(i) = self.path.iter().position(|&on_path| on_path == rule) {
+            let mut cycle = self.path[i..].to_vec();
+            cycle.push(rule);
+            return Err(CompileError::Recursive(cycle));
+        }
+        let pattern = match self.grammar.get(&rule) {
+            None => return Err(CompileError::MissingRule(rule)),
+            Some(Symbol::Term(c)) => regex::escape(&c.to_string()),
+            Some(Symbol::Nonterm(prods)) => {
+                self.path.push(rule);
+                let alternatives = prods
+                    .iter()
+                    .map(|prod| {
+                        prod.iter()
+                            .map(|&rule| self.rule(rule))
+                            .collect::<Result<String, CompileError>>()
+                    })
+                    .collect::<Result<Vec<String>, CompileError>>()?;
+                self.path.pop();
+                if alternatives.len() == 1 {
+                    alternatives.into_iter().next().unwrap()
+                } else {
+                    format!("(?:{})", alternatives.join("|"))
+                }
+            }
+        };
+        if pattern.len() > MAX_PATTERN_LEN {
+            return Err(CompileError::TooLong(rule));
+        }
+        self.done.insert(rule, pattern.clone());
+        Ok(pattern)
+    }
+}
+
+/// How many of the messages match rule 0 of a grammar without recursion.
+pub fn validate_messages(
+    grammar: &HashMap<usize, Symbol>,
+    messages: &[String],
+) -> Result<usize, CompileError> {
+    let regex = compile(grammar, 0)?;
+    Ok(messages
+        .iter()
+        .filter(|message| regex.is_match(message))
+        .count())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> HashMap<usize, Symbol> {
+        hashmap!(
+            0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+            1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+            2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+            3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+            4 => Symbol::Term('a'),
+            5 => Symbol::Term('b')
+        )
+    }
+
+    #[test]
+    fn test_pattern() {
+        assert_eq!(
+            pattern(&example(), 0),
+            Ok("^a(?:(?:aa|bb)(?:ab|ba)|(?:ab|ba)(?:aa|bb))b$".to_string())
+        )
+    }
+
+    #[test]
+    fn test_pattern_escapes() {
+        assert_eq!(
+            pattern(
+                &hashmap!(
+                    0 => Symbol::Nonterm(vec![vec![1, 2]]),
+                    1 => Symbol::Term('.'),
+                    2 => Symbol::Term('*')
+                ),
+                0
+            ),
+            Ok(r"^\.\*$".to_string())
+        )
+    }
+
+    #[test]
+    fn test_validate_messages() {
+        assert_eq!(
+            validate_messages(
+                &example(),
+                &[
+                    "ababbb".to_string(),
+                    "bababa".to_string(),
+                    "abbbab".to_string(),
+                    "aaabbb".to_string(),
+                    "aaaabbb".to_string(),
+                ]
+            ),
+            Ok(2)
+        )
+    }
+
+    /// Each rule uses the next one twice, so the pattern triples in length at every level.
+    fn blow_up() -> HashMap<usize, Symbol> {
+        let mut grammar = (0..12)
+            .map(|i| (i, Symbol::Nonterm(vec![vec![i + 1, i + 1], vec![i + 1]])))
+            .collect::<HashMap<usize, Symbol>>();
+        grammar.insert(12, Symbol::Term('a'));
+        grammar
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert!(matches!(
+            pattern(&blow_up(), 0),
+            Err(CompileError::TooLong(rule)) if rule < 12
+        ))
+    }
+
+    #[test]
+    fn test_too_long_short_enough() {
+        assert!(pattern(&blow_up(), 6).is_ok())
+    }
+
+    #[test]
+    fn test_recursive() {
+        assert_eq!(
+            pattern(
+                &hashmap!(
+                    0 => Symbol::Nonterm(vec![vec![8]]),
+                    8 => Symbol::Nonterm(vec![vec![42], vec![42, 8]]),
+                    42 => Symbol::Term('a')
+                ),
+                0
+            ),
+            Err(CompileError::Recursive(vec![8, 8]))
+        )
+    }
+
+    #[test]
+    fn test_recursive_display() {
+        assert_eq!(
+            CompileError::Recursive(vec![1, 2, 1]).to_string(),
+            "the grammar is recursive, so has no regex: 1 -> 2 -> 1"
+        )
+    }
+
+    #[test]
+    fn test_missing_rule() {
+        assert_eq!(
+            pattern(&hashmap!(0 => Symbol::Nonterm(vec![vec![1]])), 0),
+            Err(CompileError::MissingRule(1))
+        )
+    }
+}
@@ -0,0 +1,233 @@
+//! An Earley recogniser, which accepts messages from any context-free set of rules, however they
+//! recurse.
+
+use crate::day19::Symbol;
+use std::collections::{HashMap, HashSet};
+
+/// A production partway through being matched: `rule`'s alternative `prod`, matched up to `dot`
+/// from position `origin` in the message.  The start item has no rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Item<'a> {
+    rule: Option<usize>,
+    prod: &'a [usize],
+    dot: usize,
+    origin: usize,
+}
+
+impl Item<'_> {
+    fn next(&self) -> Option<usize> {
+        self.prod.get(self.dot).copied()
+    }
+
+    fn advance(self) -> Self {
+        Item {
+            dot: self.dot + 1,
+            ..self
+        }
+    }
+}
+
+/// The rules that can match an empty message.
+pub fn nullable(grammar: &HashMap<usize, Symbol>) -> HashSet<usize> {
+    let mut nullable = HashSet::new();
+    loop {
+        let found = grammar
+            .iter()
+            .filter(|(rule, _)| !nullable.contains(*rule))
+            .filter(|(_, sym)| match sym {
+                Symbol::Term(_) => false,
+                Symbol::Nonterm(prods) => prods
+                    .iter()
+                    .any(|prod| prod.iter().all(|rule| nullable.contains(rule))),
+            })
+            .map(|(rule, _)| *rule)
+            .collect::<Vec<usize>>();
+        if found.is_empty() {
+            return nullable;
+        }
+        nullable.extend(found);
+    }
+}
+
+/// Whether rule `start` of `grammar` matches the whole of `message`.  Rules that aren't in the
+/// grammar never match.  Use a `Recogniser` to check many messages against the same grammar.
+pub fn recognise(grammar: &HashMap<usize, Symbol>, start: usize, message: &str) -> bool {
+    Recogniser::new(grammar).recognise(start, message)
+}
+
+/// A grammar ready to check messages against, having worked out up front which of its rules
+/// can match nothing.
+pub struct Recogniser<'a> {
+    grammar: &'a HashMap<usize, Symbol>,
+    nullable: HashSet<usize>,
+}
+
+impl Recogniser<'_> {
+    pub fn new(grammar: &HashMap<usize, Symbol>) -> Recogniser<'_> {
+        Recogniser {
+            grammar,
+            nullable: nullable(grammar),
+        }
+    }
+
+    /// Whether rule `start` matches the whole of `message`.
+    pub fn recognise(&self, start: usize, message: &str) -> bool {
+        let grammar = self.grammar;
+        let nullable = &self.nullable;
+        let chars = message.chars().collect::<Vec<char>>();
+        let start_prod = [start];
+        let start_item = Item {
+            rule: None,
+            prod: &start_prod,
+            dot: 0,
+            origin: 0,
+        };
+
+        let mut chart: Vec<Vec<Item>> = vec![Vec::new(); chars.len() + 1];
+        let mut seen: Vec<HashSet<Item>> = vec![HashSet::new(); chars.len() + 1];
+        chart[0].push(start_item);
+        seen[0].insert(start_item);
+        for k in 0..=chars.len() {
+            let mut i = 0;
+            while i < chart[k].len() {
+                let item = chart[k][i];
+                i += 1;
+                let mut found = Vec::new();
+                match item.next() {
+                    // Complete: everything waiting on this rule where it started can move on.
+                    None => {
+                        found.extend(
+                            chart[item.origin]
+                                .iter()
+                                .filter(|waiting| {
+                                    item.rule.is_some() && waiting.next() == item.rule
+                                })
+                                .map(|waiting| waiting.advance()),
+                        );
+                    }
+                    Some(rule) => match grammar.get(&rule) {
+                        // Scan: a matching character moves the item into the next set.
+                        Some(Symbol::Term(c)) if chars.get(k) == Some(c) => {
+                            if seen[k + 1].insert(item.advance()) {
+                                chart[k + 1].push(item.advance());
+                            }
+                        }
+                        // Predict: start matching each alternative here.  A rule that can match
+                        // nothing can also be skipped straight away.
+                        Some(Symbol::Nonterm(prods)) => {
+                            found.extend(prods.iter().map(|prod| Item {
+                                rule: Some(rule),
+                                prod,
+                                dot: 0,
+                                origin: k,
+                            }));
+                            if nullable.contains(&rule) {
+                                found.push(item.advance());
+                            }
+                        }
+                        Some(Symbol::Term(_)) | None => (),
+                    },
+                }
+                for item in found {
+                    if seen[k].insert(item) {
+                        chart[k].push(item);
+                    }
+                }
+            }
+            trace!("{}: {} items at {}", message, chart[k].len(), k);
+        }
+        seen[chars.len()].contains(&start_item.advance())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_recognise_term() {
+        assert!(recognise(&hashmap!(0 => Symbol::Term('a')), 0, "a"))
+    }
+
+    #[test]
+    fn test_recognise_left_recursion() {
+        let grammar = hashmap!(
+            0 => Symbol::Nonterm(vec![vec![3], vec![0, 2]]),
+            2 => Symbol::Term('a'),
+            3 => Symbol::Term('b')
+        );
+        assert_eq!(
+            ["b", "baaa", "ab", "bab", ""]
+                .iter()
+                .map(|message| recognise(&grammar, 0, message))
+                .collect::<Vec<bool>>(),
+            vec![true, true, false, false, false]
+        )
+    }
+
+    #[test]
+    fn test_recognise_right_recursion() {
+        let grammar = hashmap!(
+            0 => Symbol::Nonterm(vec![vec![2, 0, 3], vec![2, 3]]),
+            2 => Symbol::Term('a'),
+            3 => Symbol::Term('b')
+        );
+        assert_eq!(
+            ["ab", "aaabbb", "aabbb", "abab"]
+                .iter()
+                .map(|message| recognise(&grammar, 0, message))
+                .collect::<Vec<bool>>(),
+            vec![true, true, false, false]
+        )
+    }
+
+    #[test]
+    fn test_recognise_ambiguous() {
+        // Both sides of a sum, either way round.
+        let grammar = hashmap!(
+            0 => Symbol::Nonterm(vec![vec![0, 1, 0], vec![2]]),
+            1 => Symbol::Term('+'),
+            2 => Symbol::Term('x')
+        );
+        assert!(recognise(&grammar, 0, "x+x+x+x"))
+    }
+
+    #[test]
+    fn test_recognise_empty_production() {
+        // Balanced parentheses, including none at all.
+        let grammar = hashmap!(
+            0 => Symbol::Nonterm(vec![vec![], vec![1, 0, 2, 0]]),
+            1 => Symbol::Term('('),
+            2 => Symbol::Term(')')
+        );
+        assert_eq!(
+            ["", "()", "(())()", "(()", ")("]
+                .iter()
+                .map(|message| recognise(&grammar, 0, message))
+                .collect::<Vec<bool>>(),
+            vec![true, true, true, false, false]
+        )
+    }
+
+    #[test]
+    fn test_recognise_missing_rule() {
+        assert!(!recognise(
+            &hashmap!(0 => Symbol::Nonterm(vec![vec![1]])),
+            0,
+            "a"
+        ))
+    }
+
+    #[test]
+    fn test_nullable() {
+        assert_eq!(
+            nullable(&hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 2]]),
+                1 => Symbol::Nonterm(vec![vec![], vec![3]]),
+                2 => Symbol::Nonterm(vec![vec![1]]),
+                3 => Symbol::Term('a')
+            )),
+            hashset!(0, 1, 2)
+        )
+    }
+}
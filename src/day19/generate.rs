@@ -0,0 +1,305 @@
+//! Generating messages from a grammar, to build test corpora for the validators: every valid
+//! message up to some length, random valid messages, and invalid messages that only just miss.
+
+use crate::day19::analysis;
+use crate::day19::earley::Recogniser;
+use crate::day19::Symbol;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Every message of at most `max_len` characters that rule `start` matches.
+pub fn enumerate(
+    grammar: &HashMap<usize, Symbol>,
+    start: usize,
+    max_len: usize,
+) -> BTreeSet<String> {
+    // Grow each rule's messages together until none of them change, so that recursive rules
+    // build on each other.
+    let mut messages: HashMap<usize, BTreeSet<String>> = HashMap::new();
+    loop {
+        let mut changed = false;
+        for (rule, sym) in grammar {
+            let found = match sym {
+                Symbol::Term(c) if max_len > 0 => std::iter::once(c.to_string()).collect(),
+                Symbol::Term(_) => BTreeSet::new(),
+                Symbol::Nonterm(prods) => prods
+                    .iter()
+                    .flat_map(|prod| {
+                        prod.iter().fold(vec![String::new()], |prefixes, rule| {
+                            let empty = BTreeSet::new();
+                            let suffixes = messages.get(rule).unwrap_or(&empty);
+                            prefixes
+                                .iter()
+                                .flat_map(|prefix| {
+                                    suffixes
+                                        .iter()
+                                        .filter(move |suffix| {
+                                            prefix.chars().count() + suffix.chars().count()
+                                                <= max_len
+                                        })
+                                        .map(move |suffix| format!("{}{}", prefix, suffix))
+                                })
+                                .collect()
+                        })
+                    })
+                    .collect(),
+            };
+            let known = messages.entry(*rule).or_default();
+            if found.len() > known.len() {
+                *known = found;
+                changed = true;
+            }
+        }
+        if !changed {
+            return messages.remove(&start).unwrap_or_default();
+        }
+    }
+}
+
+/// A small seeded pseudo-random number generator (SplitMix64), so that the same seed always
+/// gives the same messages.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to but not including `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// How deep into a message's derivation alternatives are picked at random.  Beyond this, the
+/// alternative with the least height is taken, so that recursive rules always finish.
+const MAX_RANDOM_DEPTH: usize = 20;
+
+/// How many times to try mutating a valid message before giving up on a near miss.
+const MAX_MUTATIONS: usize = 100;
+
+pub struct Generator<'a> {
+    grammar: &'a HashMap<usize, Symbol>,
+    recogniser: Recogniser<'a>,
+    /// The height of every rule that can match a message, from `analysis::heights`.
+    heights: BTreeMap<usize, usize>,
+    /// Every character the grammar can match.
+    alphabet: Vec<char>,
+    rng: Rng,
+}
+
+impl Generator<'_> {
+    pub fn new(grammar: &HashMap<usize, Symbol>, seed: u64) -> Generator<'_> {
+        let alphabet = grammar
+            .values()
+            .filter_map(|sym| match sym {
+                Symbol::Term(c) => Some(*c),
+                Symbol::Nonterm(_) => None,
+            })
+            .collect::<BTreeSet<char>>();
+        Generator {
+            grammar,
+            recogniser: Recogniser::new(grammar),
+            heights: analysis::heights(grammar),
+            alphabet: alphabet.into_iter().collect(),
+            rng: Rng::new(seed),
+        }
+    }
+
+    /// A random message that rule `start` matches, or `None` if it can't match any.
+    pub fn sample(&mut self, start: usize) -> Option<String> {
+        self.heights.get(&start)?;
+        let mut message = String::new();
+        self.expand(start, 0, &mut message);
+        Some(message)
+    }
+
+    /// Append a random message matched by `rule`, which must be able to match one.
+    fn expand(&mut self, rule: usize, depth: usize, message: &mut String) {
+        let grammar = self.grammar;
+        match &grammar[&rule] {
+            Symbol::Term(c) => message.push(*c),
+            Symbol::Nonterm(prods) => {
+                let choices = prods
+                    .iter()
+                    .filter_map(|prod| Some((prod, analysis::height(&self.heights, prod)?)))
+                    .collect::<Vec<(&Vec<usize>, usize)>>();
+                let prod = if depth < MAX_RANDOM_DEPTH {
+                    choices[self.rng.below(choices.len())].0
+                } else {
+                    choices.iter().min_by_key(|(_, height)| *height).unwrap().0
+                };
+                for &rule in prod {
+                    self.expand(rule, depth + 1, message);
+                }
+            }
+        }
+    }
+
+    /// `message` with one random edit: a character changed, removed, added or swapped with the
+    /// next one.
+    pub fn mutate(&mut self, message: &str) -> String {
+        if self.alphabet.is_empty() {
+            return message.to_string();
+        }
+        let mut chars = message.chars().collect::<Vec<char>>();
+        let letter = self.alphabet[self.rng.below(self.alphabet.len())];
+        match (self.rng.below(4), chars.len()) {
+            (0, len) if len > 0 => chars[self.rng.below(len)] = letter,
+            (1, len) if len > 0 => {
+                chars.remove(self.rng.below(len));
+            }
+            (2, len) if len > 1 => {
+                let i = self.rng.below(len - 1);
+                chars.swap(i, i + 1);
+            }
+            (_, len) => chars.insert(self.rng.below(len + 1), letter),
+        }
+        chars.into_iter().collect()
+    }
+
+    /// A message that rule `start` doesn't match, one edit away from one that it does.  `None`
+    /// if no such message turned up.
+    pub fn near_miss(&mut self, start: usize) -> Option<String> {
+        (0..MAX_MUTATIONS).find_map(|_| {
+            let valid = self.sample(start)?;
+            let mutated = self.mutate(&valid);
+            if self.recogniser.recognise(start, &mutated) {
+                None
+            } else {
+                Some(mutated)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day19::earley;
+
+    fn example() -> HashMap<usize, Symbol> {
+        hashmap!(
+            0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+            1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+            2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+            3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+            4 => Symbol::Term('a'),
+            5 => Symbol::Term('b')
+        )
+    }
+
+    /// `a` then any number of `b`s, then `c`.
+    fn looping() -> HashMap<usize, Symbol> {
+        hashmap!(
+            0 => Symbol::Nonterm(vec![vec![1, 2, 3]]),
+            1 => Symbol::Term('a'),
+            2 => Symbol::Nonterm(vec![vec![], vec![2, 4]]),
+            3 => Symbol::Term('c'),
+            4 => Symbol::Term('b')
+        )
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let messages = enumerate(&example(), 0, 6);
+        assert_eq!(
+            (
+                messages.len(),
+                messages.contains("aaaabb"),
+                messages.contains("ababbb")
+            ),
+            (8, true, true)
+        )
+    }
+
+    #[test]
+    fn test_enumerate_too_short() {
+        assert!(enumerate(&example(), 0, 5).is_empty())
+    }
+
+    #[test]
+    fn test_enumerate_recursive() {
+        assert_eq!(
+            enumerate(&looping(), 0, 4),
+            btreeset!("ac".to_string(), "abc".to_string(), "abbc".to_string())
+        )
+    }
+
+    #[test]
+    fn test_rng_repeatable() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(
+            (0..5).map(|_| a.next_u64()).collect::<Vec<u64>>(),
+            (0..5).map(|_| b.next_u64()).collect::<Vec<u64>>()
+        )
+    }
+
+    #[test]
+    fn test_sample_valid() {
+        let grammar = looping();
+        let mut generator = Generator::new(&grammar, 19);
+        assert!((0..50).all(|_| {
+            let message = generator.sample(0).unwrap();
+            earley::recognise(&grammar, 0, &message)
+        }))
+    }
+
+    #[test]
+    fn test_sample_repeatable() {
+        let grammar = example();
+        let sample = |seed| {
+            let mut generator = Generator::new(&grammar, seed);
+            (0..10)
+                .map(|_| generator.sample(0).unwrap())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(sample(3), sample(3))
+    }
+
+    #[test]
+    fn test_sample_finishes_on_ties() {
+        let grammar = hashmap!(
+            0 => Symbol::Nonterm(vec![vec![1], vec![2]]),
+            1 => Symbol::Nonterm(vec![vec![0]]),
+            2 => Symbol::Term('a')
+        );
+        let mut generator = Generator::new(&grammar, 0);
+        assert!((0..20_000).all(|_| generator.sample(0).as_deref() == Some("a")))
+    }
+
+    #[test]
+    fn test_sample_unproductive() {
+        let grammar = hashmap!(0 => Symbol::Nonterm(vec![vec![0]]));
+        assert_eq!(Generator::new(&grammar, 0).sample(0), None)
+    }
+
+    #[test]
+    fn test_mutate_one_edit() {
+        let grammar = example();
+        let mut generator = Generator::new(&grammar, 5);
+        assert!((0..50).all(|_| {
+            let len = generator.mutate("abab").chars().count();
+            (3..=5).contains(&len)
+        }))
+    }
+
+    #[test]
+    fn test_near_miss_invalid() {
+        let grammar = example();
+        let mut generator = Generator::new(&grammar, 11);
+        assert!((0..20).all(|_| {
+            let message = generator.near_miss(0).unwrap();
+            !earley::recognise(&grammar, 0, &message)
+        }))
+    }
+}
@@ -0,0 +1,337 @@
+use crate::parse::{self, ParseError};
+use std::collections::HashMap;
+
+pub mod analysis;
+pub mod compile;
+pub mod earley;
+pub mod generate;
+pub mod prob01;
+pub mod prob02;
+
+/// Split the puzzle input into the lines defining the grammar rules and the messages to validate.
+pub fn parse(input: &str) -> (Vec<String>, Vec<String>) {
+    let mut grammar: Vec<String> = Vec::new();
+    let mut messages: Vec<String> = Vec::new();
+    let mut in_messages = false;
+    for line in input.lines() {
+        if line.is_empty() {
+            in_messages = true;
+            continue;
+        }
+        if in_messages {
+            messages.push(line.to_string());
+        } else {
+            grammar.push(line.to_string());
+        }
+    }
+    (grammar, messages)
+}
+
+/// What the day 19 tools were asked to load, and whatever else they were given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    pub input: String,
+    pub overrides: Option<String>,
+    /// The remaining arguments, in order.
+    pub rest: Vec<String>,
+}
+
+/// Read the day 19 tools' arguments: `--input` (or `-i`) naming the puzzle input,
+/// `src/bin/day19.txt` if there isn't one, and `--overrides` naming a file of rules to replace,
+/// as for `aoc`.  Verbosity flags are skipped, as `trace::init_from_args` handles them.
+pub fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut input = None;
+    let mut overrides = None;
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let slot = match arg.as_str() {
+            "--input" | "-i" => &mut input,
+            "--overrides" => &mut overrides,
+            _ if crate::trace::parse_flag(arg).is_some() => continue,
+            _ if arg.starts_with('-') => return Err(format!("unknown flag '{}'", arg)),
+            _ => {
+                rest.push(arg.clone());
+                continue;
+            }
+        };
+        let path = args.next().ok_or_else(|| format!("{} needs a file", arg))?;
+        if slot.replace(path.clone()).is_some() {
+            return Err(format!("{} given more than once", arg));
+        }
+    }
+    Ok(Args {
+        input: input.unwrap_or_else(|| "src/bin/day19.txt".to_string()),
+        overrides,
+        rest,
+    })
+}
+
+/// The arguments a day 19 tool was run with, exiting with its `usage` if they're wrong.
+pub fn args(usage: &str) -> Args {
+    parse_args(&std::env::args().skip(1).collect::<Vec<String>>()).unwrap_or_else(|message| {
+        eprintln!("error: {}\n\n{}", message, usage);
+        std::process::exit(1);
+    })
+}
+
+/// The grammar from the input named in `args`, with its overrides applied, exiting with the
+/// error if either file doesn't parse.
+pub fn load_grammar(args: &Args) -> HashMap<usize, Symbol> {
+    let grammar = parse::load(&args.input, |file| Symbol::parse_grammar(parse(file).0));
+    let overrides = args
+        .overrides
+        .as_ref()
+        .map(|path| parse::load(path, Symbol::parse_overrides))
+        .unwrap_or_default();
+    prob02::patch_grammar(grammar, overrides)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Symbol {
+    Nonterm(Vec<Vec<usize>>),
+    Term(char),
+}
+
+impl Symbol {
+    /// Parse the grammar rules, which sit at the top of the puzzle input so that the index of
+    /// each rule is also its line number.
+    pub fn parse_grammar(lines: Vec<String>) -> Result<HashMap<usize, Symbol>, ParseError> {
+        Symbol::parse_rules(
+            lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| (i + 1, line.as_str())),
+        )
+    }
+
+    /// Parse replacements for some of the grammar's rules, one per line, in any order.
+    pub fn parse_overrides(input: &str) -> Result<HashMap<usize, Symbol>, ParseError> {
+        Symbol::parse_rules(parse::lines(input))
+    }
+
+    /// Parse rules from their line numbers and text, rejecting any rule defined twice.
+    fn parse_rules<'a>(
+        lines: impl Iterator<Item = (usize, &'a str)>,
+    ) -> Result<HashMap<usize, Symbol>, ParseError> {
+        let mut rules = HashMap::new();
+        for (i, line) in lines {
+            let (name, sym) = Symbol::from_line(line).map_err(|e| e.on_line(i))?;
+            if rules.insert(name, sym).is_some() {
+                return Err(ParseError::within(
+                    line,
+                    line.split(": ").next().unwrap(),
+                    &format!("rule {} is already defined", name),
+                )
+                .on_line(i));
+            }
+        }
+        Ok(rules)
+    }
+
+    pub fn from_line(line: &str) -> Result<(usize, Symbol), ParseError> {
+        let pieces = line.splitn(2, ": ").collect::<Vec<&str>>();
+        if pieces.len() != 2 {
+            return Err(ParseError::within(
+                line,
+                line,
+                "expected a rule like '0: 1 2 | \"a\"'",
+            ));
+        }
+        let name = parse::number::<usize>(line, pieces[0])?;
+        let sym = if pieces[1].starts_with('"') {
+            let chars = pieces[1].chars().collect::<Vec<char>>();
+            if chars.len() != 3 || chars[2] != '"' {
+                return Err(ParseError::within(
+                    line,
+                    pieces[1],
+                    "expected a single quoted character",
+                ));
+            }
+            Symbol::Term(chars[1])
+        } else {
+            Symbol::Nonterm(
+                pieces[1]
+                    .split(" | ")
+                    .map(|chunk| {
+                        chunk
+                            .split(' ')
+                            .map(|pname| parse::number::<usize>(line, pname))
+                            .collect()
+                    })
+                    .collect::<Result<Vec<Vec<usize>>, ParseError>>()?,
+            )
+        };
+        Ok((name, sym))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(
+            &args
+                .iter()
+                .map(|arg| arg.to_string())
+                .collect::<Vec<String>>(),
+        )
+    }
+
+    #[test]
+    fn test_parse_args_default() {
+        assert_eq!(
+            args(&["-vv"]),
+            Ok(Args {
+                input: "src/bin/day19.txt".to_string(),
+                overrides: None,
+                rest: Vec::new()
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args(&["sample", "-i", "in.txt", "--overrides", "o.txt", "3"]),
+            Ok(Args {
+                input: "in.txt".to_string(),
+                overrides: Some("o.txt".to_string()),
+                rest: vec!["sample".to_string(), "3".to_string()]
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert_eq!(
+            (
+                args(&["--seed"]),
+                args(&["--input"]),
+                args(&["-i", "a.txt", "--input", "b.txt"])
+            ),
+            (
+                Err("unknown flag '--seed'".to_string()),
+                Err("--input needs a file".to_string()),
+                Err("--input given more than once".to_string())
+            )
+        )
+    }
+
+    #[test]
+    fn test_symbol_from_line_term() {
+        assert_eq!(Symbol::from_line("0: \"a\""), Ok((0, Symbol::Term('a'))))
+    }
+
+    #[test]
+    fn test_symbol_from_line_nonterm_single() {
+        assert_eq!(
+            Symbol::from_line("0: 4 1 5"),
+            Ok((0, Symbol::Nonterm(vec![vec![4, 1, 5]])))
+        )
+    }
+
+    #[test]
+    fn test_symbol_from_line_nonterm_digits() {
+        assert_eq!(
+            Symbol::from_line("0: 42 37"),
+            Ok((0, Symbol::Nonterm(vec![vec![42, 37]])))
+        )
+    }
+
+    #[test]
+    fn test_symbol_from_line_nonterm_multiple() {
+        assert_eq!(
+            Symbol::from_line("1: 2 3 | 3 2"),
+            Ok((1, Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]])))
+        )
+    }
+
+    #[test]
+    fn test_symbol_parse_grammar() {
+        assert_eq!(
+            Symbol::parse_grammar(vec![
+                "0: 1 2".to_string(),
+                "1: \"a\"".to_string(),
+                "2: 1 3 | 3 1".to_string(),
+                "3: \"b\"".to_string(),
+            ]),
+            Ok(hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Nonterm(vec![vec![1, 3], vec![3, 1]]),
+                3 => Symbol::Term('b')
+            ))
+        )
+    }
+
+    #[test]
+    fn test_symbol_from_line_bad_rule() {
+        assert_eq!(
+            Symbol::from_line("2: 1 x | 3 1"),
+            Err(ParseError::new(1, 6, "x", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_symbol_from_line_bad_term() {
+        assert_eq!(
+            Symbol::from_line("1: \"ab\""),
+            Err(ParseError::new(
+                1,
+                4,
+                "\"ab\"",
+                "expected a single quoted character"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_symbol_parse_grammar_error_line() {
+        assert_eq!(
+            Symbol::parse_grammar(vec!["0: 1".to_string(), "1 \"a\"".to_string()])
+                .map_err(|e| e.line),
+            Err(2)
+        )
+    }
+
+    #[test]
+    fn test_symbol_parse_grammar_duplicate() {
+        assert_eq!(
+            Symbol::parse_grammar(vec![
+                "0: 1".to_string(),
+                "1: \"a\"".to_string(),
+                "0: 1 1".to_string(),
+            ]),
+            Err(ParseError::new(3, 1, "0", "rule 0 is already defined"))
+        )
+    }
+
+    #[test]
+    fn test_symbol_parse_overrides() {
+        assert_eq!(
+            Symbol::parse_overrides("8: 42 | 42 8\n\n11: 42 31 | 42 11 31\n"),
+            Ok(hashmap!(
+                8 => Symbol::Nonterm(vec![vec![42], vec![42, 8]]),
+                11 => Symbol::Nonterm(vec![vec![42, 31], vec![42, 11, 31]])
+            ))
+        )
+    }
+
+    #[test]
+    fn test_symbol_parse_overrides_duplicate() {
+        assert_eq!(
+            Symbol::parse_overrides("8: 42\n\n8: 42 8\n").map_err(|e| e.line),
+            Err(3)
+        )
+    }
+
+    #[test]
+    fn test_symbol_parse_overrides_error_line() {
+        assert_eq!(
+            Symbol::parse_overrides("8: 42\n\n11 42").map_err(|e| e.line),
+            Err(3)
+        )
+    }
+}
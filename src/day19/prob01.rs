@@ -0,0 +1,302 @@
+use crate::day19::earley::Recogniser;
+use crate::day19::{compile, Symbol};
+use std::collections::HashMap;
+
+/// Count the valid messages with a single regex, or message by message with the Earley
+/// recogniser if the grammar has no regex.
+pub fn validate_messages(grammar: HashMap<usize, Symbol>, messages: Vec<String>) -> usize {
+    match compile::validate_messages(&grammar, &messages) {
+        Ok(count) => return count,
+        Err(e) => debug!("{}", e),
+    }
+    let recogniser = Recogniser::new(&grammar);
+    messages
+        .into_iter()
+        .filter(|message| recogniser.recognise(0, message))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn validate_message(grammar: &HashMap<usize, Symbol>, message: &str) -> bool {
+        Recogniser::new(grammar).recognise(0, message)
+    }
+
+    #[test]
+    fn test_validate_messages_too_long_for_regex() {
+        let mut grammar = (0..12)
+            .map(|i| (i, Symbol::Nonterm(vec![vec![i + 1, i + 1], vec![i + 1]])))
+            .collect::<HashMap<usize, Symbol>>();
+        grammar.insert(12, Symbol::Term('a'));
+        assert_eq!(
+            validate_messages(
+                grammar,
+                vec!["a".to_string(), "aaaaa".to_string(), "ab".to_string()]
+            ),
+            2
+        )
+    }
+
+    #[test]
+    fn test_validate_message_static() {
+        assert!(validate_message(&hashmap!(0 => Symbol::Term('a')), "a"))
+    }
+
+    #[test]
+    fn test_validate_message_static_fail() {
+        assert!(!validate_message(&hashmap!(0 => Symbol::Term('a')), "b"))
+    }
+
+    #[test]
+    fn test_validate_message_multi_rule() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1]]),
+                1 => Symbol::Nonterm(vec![vec![2]]),
+                2 => Symbol::Nonterm(vec![vec![3]]),
+                3 => Symbol::Nonterm(vec![vec![4]]),
+                4 => Symbol::Nonterm(vec![vec![5]]),
+                5 => Symbol::Term('a')
+            ),
+            "a"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_rule_false() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1]]),
+                1 => Symbol::Nonterm(vec![vec![2]]),
+                2 => Symbol::Nonterm(vec![vec![3]]),
+                3 => Symbol::Nonterm(vec![vec![4]]),
+                4 => Symbol::Nonterm(vec![vec![5]]),
+                5 => Symbol::Term('a')
+            ),
+            "b"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_term() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1, 1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "aaaaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_term_fail() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1, 1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "aabaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod1() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![2, 2, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "aaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod2() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![2, 2, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "bbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod_backtrack() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![1, 2, 1]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "aba"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod_fail() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![2, 2, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "aab"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_too_few_chars() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "a"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_leftover_chars() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "aaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message1() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "ababbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message2() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "bababa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message3() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "abbbab"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message4() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "aaabbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message5() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "aaaabbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_messages1() {
+        assert_eq!(
+            validate_messages(
+                hashmap!(
+                    0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                    1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                    2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                    3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                    4 => Symbol::Term('a'),
+                    5 => Symbol::Term('b')
+                ),
+                vec![
+                    "ababbb".to_string(),
+                    "bababa".to_string(),
+                    "abbbab".to_string(),
+                    "aaabbb".to_string(),
+                    "aaaabbb".to_string()
+                ]
+            ),
+            2
+        )
+    }
+
+    #[test]
+    fn test_validate_messages2() {
+        assert_eq!(
+            validate_messages(
+                hashmap!(
+                    0 => Symbol::Nonterm(vec![vec![1, 2]]),
+                    1 => Symbol::Term('a'),
+                    2 => Symbol::Nonterm(vec![vec![1, 3], vec![3, 1]]),
+                    3 => Symbol::Term('b')
+                ),
+                vec![
+                    "aab".to_string(),
+                    "aba".to_string(),
+                    "a".to_string(),
+                    "ab".to_string(),
+                    "aa".to_string()
+                ]
+            ),
+            2
+        )
+    }
+
+    #[test]
+    fn test_validate_message_undefined_rule() {
+        assert!(!validate_message(
+            &hashmap!(0 => Symbol::Nonterm(vec![vec![1]])),
+            "a"
+        ))
+    }
+}
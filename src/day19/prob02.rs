@@ -0,0 +1,441 @@
+use crate::day19::earley::Recogniser;
+use crate::day19::Symbol;
+use std::collections::HashMap;
+
+/// Replace rules of the grammar with those in `overrides`.
+pub fn patch_grammar(
+    mut grammar: HashMap<usize, Symbol>,
+    overrides: HashMap<usize, Symbol>,
+) -> HashMap<usize, Symbol> {
+    grammar.extend(overrides);
+    grammar
+}
+
+pub fn validate_messages(grammar: HashMap<usize, Symbol>, messages: Vec<String>) -> usize {
+    let recogniser = Recogniser::new(&grammar);
+    messages
+        .into_iter()
+        .filter(|message| is_valid(&recogniser, message))
+        .count()
+}
+
+pub fn validate_message(grammar: &HashMap<usize, Symbol>, message: &str) -> bool {
+    is_valid(&Recogniser::new(grammar), message)
+}
+
+fn is_valid(recogniser: &Recogniser, message: &str) -> bool {
+    let valid = recogniser.recognise(0, message);
+    trace!("{}: {}", message, if valid { "valid" } else { "invalid" });
+    valid
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_message_static() {
+        assert!(validate_message(&hashmap!(0 => Symbol::Term('a')), "a"))
+    }
+
+    #[test]
+    fn test_validate_message_static_fail() {
+        assert!(!validate_message(&hashmap!(0 => Symbol::Term('a')), "b"))
+    }
+
+    #[test]
+    fn test_validate_message_multi_rule() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1]]),
+                1 => Symbol::Nonterm(vec![vec![2]]),
+                2 => Symbol::Nonterm(vec![vec![3]]),
+                3 => Symbol::Nonterm(vec![vec![4]]),
+                4 => Symbol::Nonterm(vec![vec![5]]),
+                5 => Symbol::Term('a')
+            ),
+            "a"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_rule_false() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1]]),
+                1 => Symbol::Nonterm(vec![vec![2]]),
+                2 => Symbol::Nonterm(vec![vec![3]]),
+                3 => Symbol::Nonterm(vec![vec![4]]),
+                4 => Symbol::Nonterm(vec![vec![5]]),
+                5 => Symbol::Term('a')
+            ),
+            "b"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_term() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1, 1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "aaaaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_term_fail() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1, 1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "aabaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod1() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![2, 2, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "aaa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod2() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![2, 2, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "bbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod_backtrack() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![1, 2, 1]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "aba"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_multi_prod_fail() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1, 1], vec![2, 2, 2]]),
+                1 => Symbol::Term('a'),
+                2 => Symbol::Term('b')
+            ),
+            "aab"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_too_few_chars() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "a"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message_leftover_chars() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![1, 1]]),
+                1 => Symbol::Term('a')
+            ),
+            "aaa"
+        ))
+    }
+
+    fn left_recursive() -> HashMap<usize, Symbol> {
+        hashmap!(
+            0 => Symbol::Nonterm(vec![vec![1]]),
+            1 => Symbol::Nonterm(vec![vec![3], vec![1, 2]]),
+            2 => Symbol::Term('a'),
+            3 => Symbol::Term('b')
+        )
+    }
+
+    #[test]
+    fn test_validate_message_recursive1() {
+        assert!(validate_message(&left_recursive(), "b"))
+    }
+
+    #[test]
+    fn test_validate_message_recursive2() {
+        assert!(validate_message(&left_recursive(), "ba"))
+    }
+
+    #[test]
+    fn test_validate_message_recursive3() {
+        assert!(!validate_message(&left_recursive(), "bba"))
+    }
+
+    #[test]
+    fn test_patch_grammar() {
+        assert_eq!(
+            patch_grammar(
+                hashmap!(0 => Symbol::Nonterm(vec![vec![8]]), 8 => Symbol::Term('a')),
+                Symbol::parse_overrides("8: 0").unwrap()
+            ),
+            hashmap!(0 => Symbol::Nonterm(vec![vec![8]]), 8 => Symbol::Nonterm(vec![vec![0]]))
+        )
+    }
+
+    #[test]
+    fn test_validate_message1() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "ababbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message2() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "bababa"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message3() {
+        assert!(validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "abbbab"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message4() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "aaabbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_message5() {
+        assert!(!validate_message(
+            &hashmap!(
+                0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                4 => Symbol::Term('a'),
+                5 => Symbol::Term('b')
+            ),
+            "aaaabbb"
+        ))
+    }
+
+    #[test]
+    fn test_validate_messages1() {
+        assert_eq!(
+            validate_messages(
+                hashmap!(
+                    0 => Symbol::Nonterm(vec![vec![4, 1, 5]]),
+                    1 => Symbol::Nonterm(vec![vec![2, 3], vec![3, 2]]),
+                    2 => Symbol::Nonterm(vec![vec![4, 4], vec![5, 5]]),
+                    3 => Symbol::Nonterm(vec![vec![4, 5], vec![5, 4]]),
+                    4 => Symbol::Term('a'),
+                    5 => Symbol::Term('b')
+                ),
+                vec![
+                    "ababbb".to_string(),
+                    "bababa".to_string(),
+                    "abbbab".to_string(),
+                    "aaabbb".to_string(),
+                    "aaaabbb".to_string()
+                ]
+            ),
+            2
+        )
+    }
+
+    #[test]
+    fn test_validate_messages2() {
+        assert_eq!(
+            validate_messages(
+                hashmap!(
+                    0 => Symbol::Nonterm(vec![vec![1, 2]]),
+                    1 => Symbol::Term('a'),
+                    2 => Symbol::Nonterm(vec![vec![1, 3], vec![3, 1]]),
+                    3 => Symbol::Term('b')
+                ),
+                vec![
+                    "aab".to_string(),
+                    "aba".to_string(),
+                    "a".to_string(),
+                    "ab".to_string(),
+                    "aa".to_string()
+                ]
+            ),
+            2
+        )
+    }
+
+    #[test]
+    fn test_validate_messages3() {
+        assert_eq!(
+            validate_messages(
+                hashmap!(
+                    42 => Symbol::Nonterm(vec![vec![9, 14], vec![10, 1]]),
+                    9 => Symbol::Nonterm(vec![vec![14, 27], vec![1, 26]]),
+                    10 => Symbol::Nonterm(vec![vec![23, 14], vec![28, 1]]),
+                    1 => Symbol::Term('a'),
+                    11 => Symbol::Nonterm(vec![vec![42, 31]]),
+                    5 => Symbol::Nonterm(vec![vec![1, 14], vec![15, 1]]),
+                    19 => Symbol::Nonterm(vec![vec![14, 1], vec![14, 14]]),
+                    12 => Symbol::Nonterm(vec![vec![24, 14], vec![19, 1]]),
+                    16 => Symbol::Nonterm(vec![vec![15, 1], vec![14, 14]]),
+                    31 => Symbol::Nonterm(vec![vec![14, 17], vec![1, 13]]),
+                    6 => Symbol::Nonterm(vec![vec![14, 14], vec![1, 14]]),
+                    2 => Symbol::Nonterm(vec![vec![1, 24], vec![14, 4]]),
+                    0 => Symbol::Nonterm(vec![vec![8, 11]]),
+                    13 => Symbol::Nonterm(vec![vec![14, 3], vec![1, 12]]),
+                    15 => Symbol::Nonterm(vec![vec![1], vec![14]]),
+                    17 => Symbol::Nonterm(vec![vec![14, 2], vec![1, 7]]),
+                    23 => Symbol::Nonterm(vec![vec![25, 1], vec![22, 14]]),
+                    28 => Symbol::Nonterm(vec![vec![16, 1]]),
+                    4 => Symbol::Nonterm(vec![vec![1, 1]]),
+                    20 => Symbol::Nonterm(vec![vec![14, 14], vec![1, 15]]),
+                    3 => Symbol::Nonterm(vec![vec![5, 14], vec![16, 1]]),
+                    27 => Symbol::Nonterm(vec![vec![1, 6], vec![14, 18]]),
+                    14 => Symbol::Term('b'),
+                    21 => Symbol::Nonterm(vec![vec![14, 1], vec![1, 14]]),
+                    25 => Symbol::Nonterm(vec![vec![1, 1], vec![1, 14]]),
+                    22 => Symbol::Nonterm(vec![vec![14, 14]]),
+                    8 => Symbol::Nonterm(vec![vec![42]]),
+                    26 => Symbol::Nonterm(vec![vec![14, 22], vec![1, 20]]),
+                    18 => Symbol::Nonterm(vec![vec![15, 15]]),
+                    7 => Symbol::Nonterm(vec![vec![14, 5], vec![1, 21]]),
+                    24 => Symbol::Nonterm(vec![vec![14, 1]])
+                ),
+                vec![
+                    "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa".to_string(),
+                    "bbabbbbaabaabba".to_string(),
+                    "babbbbaabbbbbabbbbbbaabaaabaaa".to_string(),
+                    "aaabbbbbbaaaabaababaabababbabaaabbababababaaa".to_string(),
+                    "bbbbbbbaaaabbbbaaabbabaaa".to_string(),
+                    "bbbababbbbaaaaaaaabbababaaababaabab".to_string(),
+                    "ababaaaaaabaaab".to_string(),
+                    "ababaaaaabbbaba".to_string(),
+                    "baabbaaaabbaaaababbaababb".to_string(),
+                    "abbbbabbbbaaaababbbbbbaaaababb".to_string(),
+                    "aaaaabbaabaaaaababaa".to_string(),
+                    "aaaabbaaaabbaaa".to_string(),
+                    "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa".to_string(),
+                    "babaaabbbaaabaababbaabababaaab".to_string(),
+                    "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba".to_string(),
+                ]
+            ),
+            3
+        )
+    }
+
+    #[test]
+    fn test_validate_messages4() {
+        assert_eq!(
+            validate_messages(
+                hashmap!(
+                    42 => Symbol::Nonterm(vec![vec![9, 14], vec![10, 1]]),
+                    9 => Symbol::Nonterm(vec![vec![14, 27], vec![1, 26]]),
+                    10 => Symbol::Nonterm(vec![vec![23, 14], vec![28, 1]]),
+                    1 => Symbol::Term('a'),
+                    11 => Symbol::Nonterm(vec![vec![42, 31], vec![42, 11, 31]]),
+                    5 => Symbol::Nonterm(vec![vec![1, 14], vec![15, 1]]),
+                    19 => Symbol::Nonterm(vec![vec![14, 1], vec![14, 14]]),
+                    12 => Symbol::Nonterm(vec![vec![24, 14], vec![19, 1]]),
+                    16 => Symbol::Nonterm(vec![vec![15, 1], vec![14, 14]]),
+                    31 => Symbol::Nonterm(vec![vec![14, 17], vec![1, 13]]),
+                    6 => Symbol::Nonterm(vec![vec![14, 14], vec![1, 14]]),
+                    2 => Symbol::Nonterm(vec![vec![1, 24], vec![14, 4]]),
+                    0 => Symbol::Nonterm(vec![vec![8, 11]]),
+                    13 => Symbol::Nonterm(vec![vec![14, 3], vec![1, 12]]),
+                    15 => Symbol::Nonterm(vec![vec![1], vec![14]]),
+                    17 => Symbol::Nonterm(vec![vec![14, 2], vec![1, 7]]),
+                    23 => Symbol::Nonterm(vec![vec![25, 1], vec![22, 14]]),
+                    28 => Symbol::Nonterm(vec![vec![16, 1]]),
+                    4 => Symbol::Nonterm(vec![vec![1, 1]]),
+                    20 => Symbol::Nonterm(vec![vec![14, 14], vec![1, 15]]),
+                    3 => Symbol::Nonterm(vec![vec![5, 14], vec![16, 1]]),
+                    27 => Symbol::Nonterm(vec![vec![1, 6], vec![14, 18]]),
+                    14 => Symbol::Term('b'),
+                    21 => Symbol::Nonterm(vec![vec![14, 1], vec![1, 14]]),
+                    25 => Symbol::Nonterm(vec![vec![1, 1], vec![1, 14]]),
+                    22 => Symbol::Nonterm(vec![vec![14, 14]]),
+                    8 => Symbol::Nonterm(vec![vec![42], vec![42, 8]]),
+                    26 => Symbol::Nonterm(vec![vec![14, 22], vec![1, 20]]),
+                    18 => Symbol::Nonterm(vec![vec![15, 15]]),
+                    7 => Symbol::Nonterm(vec![vec![14, 5], vec![1, 21]]),
+                    24 => Symbol::Nonterm(vec![vec![14, 1]])
+                ),
+                vec![
+                    "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa".to_string(),
+                    "bbabbbbaabaabba".to_string(),
+                    "babbbbaabbbbbabbbbbbaabaaabaaa".to_string(),
+                    "aaabbbbbbaaaabaababaabababbabaaabbababababaaa".to_string(),
+                    "bbbbbbbaaaabbbbaaabbabaaa".to_string(),
+                    "bbbababbbbaaaaaaaabbababaaababaabab".to_string(),
+                    "ababaaaaaabaaab".to_string(),
+                    "ababaaaaabbbaba".to_string(),
+                    "baabbaaaabbaaaababbaababb".to_string(),
+                    "abbbbabbbbaaaababbbbbbaaaababb".to_string(),
+                    "aaaaabbaabaaaaababaa".to_string(),
+                    "aaaabbaaaabbaaa".to_string(),
+                    "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa".to_string(),
+                    "babaaabbbaaabaababbaabababaaab".to_string(),
+                    "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba".to_string(),
+                ]
+            ),
+            12
+        )
+    }
+}
@@ -0,0 +1,444 @@
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use std::collections::HashSet;
+use std::rc::Rc;
+
+pub mod prob01;
+pub mod prob02;
+
+/// Parse the camera tiles, which are separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Rc<Tile>>, ParseError> {
+    let mut tiles: Vec<Rc<Tile>> = Vec::new();
+    let mut curr_tile: Vec<String> = Vec::new();
+    let mut tile_start = 1usize;
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if !curr_tile.is_empty() {
+                let tile =
+                    Tile::from_lines(curr_tile.clone()).map_err(|e| e.on_line(tile_start))?;
+                tiles.push(Rc::new(tile));
+                curr_tile.clear();
+            }
+        } else {
+            if curr_tile.is_empty() {
+                tile_start = i + 1;
+            }
+            curr_tile.push(line.to_string());
+        }
+    }
+    if !curr_tile.is_empty() {
+        let tile = Tile::from_lines(curr_tile).map_err(|e| e.on_line(tile_start))?;
+        tiles.push(Rc::new(tile));
+    }
+    Ok(tiles)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Tile {
+    pub id: usize,
+    pub pixels: Grid<bool>,
+    pub edge_counts: HashSet<usize>,
+    pub forward_edges: Vec<u16>,
+    pub backward_edges: Vec<u16>,
+}
+
+impl Tile {
+    pub fn new(
+        id: usize,
+        pixels: Grid<bool>,
+        edge_counts: HashSet<usize>,
+        forward_edges: Vec<u16>,
+        backward_edges: Vec<u16>,
+    ) -> Tile {
+        Tile {
+            id,
+            pixels,
+            edge_counts,
+            forward_edges,
+            backward_edges,
+        }
+    }
+
+    pub fn from_pixels(id: usize, pixels: Grid<bool>) -> Tile {
+        // edges run clockwise around the tile
+        let edges = {
+            let last = pixels.width() - 1;
+            let top = pixels.row(0).to_vec();
+            let right = pixels.column(last).copied().collect::<Vec<bool>>();
+            let bottom = pixels
+                .row(last)
+                .iter()
+                .rev()
+                .copied()
+                .collect::<Vec<bool>>();
+            let left = pixels.column(0).rev().copied().collect::<Vec<bool>>();
+            vec![top, right, bottom, left]
+        };
+        let forward_edges = edges
+            .iter()
+            .map(|edge| {
+                edge.iter().fold(0u16, |acc, state| {
+                    acc.rotate_left(1) + if *state { 1 } else { 0 }
+                })
+            })
+            .collect::<Vec<u16>>();
+        let backward_edges = edges
+            .iter()
+            .map(|edge| {
+                edge.iter().rev().fold(0u16, |acc, state| {
+                    acc.rotate_left(1) + if *state { 1 } else { 0 }
+                })
+            })
+            .collect::<Vec<u16>>();
+        let edge_counts = edges
+            .iter()
+            .map(|edge| edge.iter().filter(|p| **p).count())
+            .collect::<HashSet<usize>>();
+
+        Tile::new(id, pixels, edge_counts, forward_edges, backward_edges)
+    }
+
+    /// Parse a tile from its `Tile <id>:` header line and the square of pixels beneath it.
+    /// Errors are reported relative to the header line.
+    pub fn from_lines(lines: Vec<String>) -> Result<Tile, ParseError> {
+        let id_raw = lines[0]
+            .strip_prefix("Tile ")
+            .and_then(|rest| rest.strip_suffix(':'))
+            .ok_or_else(|| ParseError::within(&lines[0], &lines[0], "expected 'Tile <id>:'"))?;
+        let id = parse::number::<usize>(&lines[0], id_raw)?;
+
+        let size = lines.len() - 1;
+        if size == 0 {
+            return Err(ParseError::within(
+                &lines[0],
+                &lines[0],
+                "expected the tile's pixels beneath its header",
+            ));
+        }
+        for (i, line) in lines.iter().enumerate().skip(1) {
+            if line.chars().count() != size {
+                return Err(ParseError::within(
+                    line,
+                    line,
+                    &format!("expected a row of {} pixels to match the tile height", size),
+                )
+                .on_line(i + 1));
+            }
+        }
+        let pixels = Grid::parse(&lines[1..].join("\n")).map_err(|e| e.on_line(2))?;
+
+        Ok(Tile::from_pixels(id, pixels))
+    }
+
+    /// Create a copy of this tile with the provided translations applied.  flip_horizontal flips
+    /// the tile over on its y-axis.  rotate rotates the tile clockwise; must be a multiple of
+    /// 90deg.
+    pub fn translate(&self, flip_horizontal: bool, rotate: u16) -> Tile {
+        let mut pixels = if flip_horizontal {
+            self.pixels.flip_horizontal()
+        } else {
+            self.pixels.clone()
+        };
+        pixels = match rotate % 360 {
+            0 => pixels,
+            90 => pixels.rotate_clockwise(),
+            180 => pixels.rotate_clockwise().rotate_clockwise(),
+            270 => pixels.rotate_counter_clockwise(),
+            r => panic!("Invalid rotation requested: {}", r),
+        };
+
+        Tile::from_pixels(self.id, pixels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tile_from_lines() {
+        assert_eq!(
+            Tile::from_lines(vec![
+                "Tile 3079:".to_string(),
+                "#.#.#####.".to_string(),
+                ".#..######".to_string(),
+                "..#.......".to_string(),
+                "######....".to_string(),
+                "####.#..#.".to_string(),
+                ".#...#.##.".to_string(),
+                "#.#####.##".to_string(),
+                "..#.###...".to_string(),
+                "..#.......".to_string(),
+                "..#.###...".to_string()
+            ]),
+            Ok(Tile::new(
+                3079,
+                Grid::from_rows(vec![
+                    vec![true, false, true, false, true, true, true, true, true, false],
+                    vec![false, true, false, false, true, true, true, true, true, true],
+                    vec![false, false, true, false, false, false, false, false, false, false],
+                    vec![true, true, true, true, true, true, false, false, false, false],
+                    vec![true, true, true, true, false, true, false, false, true, false],
+                    vec![false, true, false, false, false, true, false, true, true, false],
+                    vec![true, false, true, true, true, true, true, false, true, true],
+                    vec![false, false, true, false, true, true, true, false, false, false],
+                    vec![false, false, true, false, false, false, false, false, false, false],
+                    vec![false, false, true, false, true, true, true, false, false, false]
+                ]),
+                hashset!(7, 2, 4, 4),
+                vec!(0b1010111110, 0b0100001000, 0b0001110100, 0b0001011001),
+                vec!(0b0111110101, 0b0001000010, 0b0010111000, 0b1001101000)
+            ))
+        )
+    }
+
+    #[test]
+    fn test_tile_from_lines_bad_header() {
+        assert_eq!(
+            Tile::from_lines(vec![
+                "Tile 12".to_string(),
+                "#.".to_string(),
+                ".#".to_string()
+            ]),
+            Err(ParseError::new(1, 1, "Tile 12", "expected 'Tile <id>:'"))
+        )
+    }
+
+    #[test]
+    fn test_tile_from_lines_no_pixels() {
+        assert_eq!(
+            Tile::from_lines(vec!["Tile 12:".to_string()]),
+            Err(ParseError::new(
+                1,
+                1,
+                "Tile 12:",
+                "expected the tile's pixels beneath its header"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_tile_from_lines_not_square() {
+        assert_eq!(
+            Tile::from_lines(vec![
+                "Tile 12:".to_string(),
+                "#..".to_string(),
+                ".#.".to_string()
+            ])
+            .map_err(|e| e.line),
+            Err(2)
+        )
+    }
+
+    #[test]
+    fn test_parse_empty_tile_line() {
+        assert_eq!(
+            parse("Tile 1:\n#.\n.#\n\nTile 2:\n").map_err(|e| (e.line, e.column)),
+            Err((5, 1))
+        )
+    }
+
+    #[test]
+    fn test_tile_from_lines_bad_pixel() {
+        assert_eq!(
+            Tile::from_lines(vec![
+                "Tile 12:".to_string(),
+                "#.".to_string(),
+                ".o".to_string()
+            ]),
+            Err(ParseError::new(3, 2, "o", "expected '.' or '#'"))
+        )
+    }
+
+    #[test]
+    fn test_parse_error_line() {
+        assert_eq!(
+            parse("Tile 1:\n#.\n.#\n\nTile 2:\n#.\n.\n").map_err(|e| (e.line, e.column)),
+            Err((7, 1))
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_noop() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(false, 0),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_rot90() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(false, 90),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![false, true, true],
+                    vec![false, false, false],
+                    vec![true, false, true]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_rot180() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(false, 180),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, false],
+                    vec![false, false, true],
+                    vec![true, false, true]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_rot270() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(false, 270),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![false, false, false],
+                    vec![true, true, false]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_flip() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(true, 0),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![false, false, true],
+                    vec![true, false, false]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_flip_rot90() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(true, 90),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![false, false, false],
+                    vec![false, true, true]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_flip_rot180() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(true, 180),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![false, false, true],
+                    vec![true, false, false],
+                    vec![true, false, true]
+                ])
+            )
+        )
+    }
+
+    #[test]
+    fn test_tile_translate_flip_rot270() {
+        assert_eq!(
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, false, true],
+                    vec![true, false, false],
+                    vec![false, false, true]
+                ])
+            )
+            .translate(true, 270),
+            Tile::from_pixels(
+                42,
+                Grid::from_rows(vec![
+                    vec![true, true, false],
+                    vec![false, false, false],
+                    vec![true, false, true]
+                ])
+            )
+        )
+    }
+}
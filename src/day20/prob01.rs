@@ -0,0 +1,204 @@
+use crate::day20::Tile;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+pub fn find_corners(tiles: Vec<Rc<Tile>>) -> HashSet<usize> {
+    let cache = tiles
+        .iter()
+        .flat_map(|tile| {
+            tile.forward_edges
+                .iter()
+                .map(move |edge| (*edge, tile.id))
+                .chain(tile.backward_edges.iter().map(move |edge| (*edge, tile.id)))
+        })
+        .fold(HashMap::new(), |mut map, (edge, tile)| {
+            map.entry(edge).or_insert_with(HashSet::new).insert(tile);
+            map
+        });
+    trace!("edge cache: {:?}", cache);
+    tiles
+        .into_iter()
+        .filter_map(|tile| {
+            trace!("tile: {}", tile.id);
+            if (0usize..4)
+                .filter(|i| {
+                    trace!(
+                        "  forward: {}, backward: {}",
+                        tile.forward_edges[*i],
+                        tile.backward_edges[*i]
+                    );
+                    !(cache
+                        .get(&tile.forward_edges[*i])
+                        .is_some_and(|matches| matches.len() == 1)
+                        | cache
+                            .get(&tile.backward_edges[*i])
+                            .is_some_and(|matches| matches.len() == 1))
+                })
+                .count()
+                == 2
+            {
+                Some(tile.id)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_corners() {
+        assert_eq!(
+            find_corners(vec![
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2311:".to_string(),
+                        "..##.#..#.".to_string(),
+                        "##..#.....".to_string(),
+                        "#...##..#.".to_string(),
+                        "####.#...#".to_string(),
+                        "##.##.###.".to_string(),
+                        "##...#.###".to_string(),
+                        ".#.#.#..##".to_string(),
+                        "..#....#..".to_string(),
+                        "###...#.#.".to_string(),
+                        "..###..###".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1951:".to_string(),
+                        "#.##...##.".to_string(),
+                        "#.####...#".to_string(),
+                        ".....#..##".to_string(),
+                        "#...######".to_string(),
+                        ".##.#....#".to_string(),
+                        ".###.#####".to_string(),
+                        "###.##.##.".to_string(),
+                        ".###....#.".to_string(),
+                        "..#.#..#.#".to_string(),
+                        "#...##.#..".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1171:".to_string(),
+                        "####...##.".to_string(),
+                        "#..##.#..#".to_string(),
+                        "##.#..#.#.".to_string(),
+                        ".###.####.".to_string(),
+                        "..###.####".to_string(),
+                        ".##....##.".to_string(),
+                        ".#...####.".to_string(),
+                        "#.##.####.".to_string(),
+                        "####..#...".to_string(),
+                        ".....##...".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1427:".to_string(),
+                        "###.##.#..".to_string(),
+                        ".#..#.##..".to_string(),
+                        ".#.##.#..#".to_string(),
+                        "#.#.#.##.#".to_string(),
+                        "....#...##".to_string(),
+                        "...##..##.".to_string(),
+                        "...#.#####".to_string(),
+                        ".#.####.#.".to_string(),
+                        "..#..###.#".to_string(),
+                        "..##.#..#.".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1489:".to_string(),
+                        "##.#.#....".to_string(),
+                        "..##...#..".to_string(),
+                        ".##..##...".to_string(),
+                        "..#...#...".to_string(),
+                        "#####...#.".to_string(),
+                        "#..#.#.#.#".to_string(),
+                        "...#.#.#..".to_string(),
+                        "##.#...##.".to_string(),
+                        "..##.##.##".to_string(),
+                        "###.##.#..".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2473:".to_string(),
+                        "#....####.".to_string(),
+                        "#..#.##...".to_string(),
+                        "#.##..#...".to_string(),
+                        "######.#.#".to_string(),
+                        ".#...#.#.#".to_string(),
+                        ".#########".to_string(),
+                        ".###.#..#.".to_string(),
+                        "########.#".to_string(),
+                        "##...##.#.".to_string(),
+                        "..###.#.#.".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2971:".to_string(),
+                        "..#.#....#".to_string(),
+                        "#...###...".to_string(),
+                        "#.#.###...".to_string(),
+                        "##.##..#..".to_string(),
+                        ".#####..##".to_string(),
+                        ".#..####.#".to_string(),
+                        "#..#.#..#.".to_string(),
+                        "..####.###".to_string(),
+                        "..#.#.###.".to_string(),
+                        "...#.#.#.#".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2729:".to_string(),
+                        "...#.#.#.#".to_string(),
+                        "####.#....".to_string(),
+                        "..#.#.....".to_string(),
+                        "....#..#.#".to_string(),
+                        ".##..##.#.".to_string(),
+                        ".#.####...".to_string(),
+                        "####.#.#..".to_string(),
+                        "##.####...".to_string(),
+                        "##..#.##..".to_string(),
+                        "#.##...##.".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 3079:".to_string(),
+                        "#.#.#####.".to_string(),
+                        ".#..######".to_string(),
+                        "..#.......".to_string(),
+                        "######....".to_string(),
+                        "####.#..#.".to_string(),
+                        ".#...#.##.".to_string(),
+                        "#.#####.##".to_string(),
+                        "..#.###...".to_string(),
+                        "..#.......".to_string(),
+                        "..#.###...".to_string(),
+                    ])
+                    .unwrap()
+                )
+            ]),
+            hashset!(1951, 3079, 1171, 2971)
+        )
+    }
+}
@@ -0,0 +1,468 @@
+use crate::day20::Tile;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Arrange the tiles into rows so that neighbouring edges match, or say why they don't fit.
+pub fn assemble_tiles(tiles: Vec<Rc<Tile>>) -> Result<Vec<Vec<Rc<Tile>>>, String> {
+    let cache = tiles
+        .iter()
+        .flat_map(|tile| {
+            tile.forward_edges
+                .iter()
+                .map(move |edge| (*edge, tile.id))
+                .chain(tile.backward_edges.iter().map(move |edge| (*edge, tile.id)))
+        })
+        .fold(HashMap::new(), |mut map, (edge, tile)| {
+            map.entry(edge).or_insert_with(HashSet::new).insert(tile);
+            map
+        });
+
+    let corners = get_tiles_by_matching_edges(&tiles, &cache, 2);
+    let mut tiles_by_id = tiles
+        .into_iter()
+        .map(|tile| (tile.id, tile.clone()))
+        .collect::<HashMap<usize, Rc<Tile>>>();
+
+    let mut out: Vec<Vec<Rc<Tile>>> = Vec::new();
+
+    while !tiles_by_id.is_empty() {
+        let first_tile = if out.is_empty() {
+            // select a corner tile to the the top-left tile and rotate it to fit that position
+            let corner = corners
+                .iter()
+                .sorted()
+                .next()
+                .and_then(|id| tiles_by_id.remove(id))
+                .ok_or_else(|| {
+                    "no tile has exactly two unmatched edges to be a corner".to_string()
+                })?;
+            orientations(&corner)
+                .into_iter()
+                .find(|tile| {
+                    is_unmatched_edge(tile, &0, &cache) && is_unmatched_edge(tile, &3, &cache)
+                })
+                .map(Rc::new)
+                .ok_or_else(|| format!("corner tile {} doesn't fit the top left", corner.id))?
+        } else {
+            find_next_tile(&out.last().unwrap()[0], 2, &cache, &mut tiles_by_id)?
+        };
+
+        debug!("starting row {} with tile {}", out.len() + 1, first_tile.id);
+
+        let mut current: Vec<Rc<Tile>> = Vec::new();
+        current.push(first_tile.clone());
+
+        while has_match(&current.last().unwrap().forward_edges, &1, &cache)
+            || has_match(&current.last().unwrap().backward_edges, &1, &cache)
+        {
+            let next_tile = find_next_tile(current.last().unwrap(), 1, &cache, &mut tiles_by_id)?;
+            current.push(next_tile);
+        }
+
+        out.push(current);
+    }
+
+    Ok(out)
+}
+
+pub fn find_next_tile(
+    curr_tile: &Rc<Tile>,
+    edge_id: usize,
+    cache: &HashMap<u16, HashSet<usize>>,
+    tiles_by_id: &mut HashMap<usize, Rc<Tile>>,
+) -> Result<Rc<Tile>, String> {
+    trace!("next tile for {:?} edge {}", curr_tile, edge_id);
+
+    let next_tile_id = *cache
+        .get(&curr_tile.forward_edges[edge_id])
+        .and_then(|ids| ids.iter().find(|id| **id != curr_tile.id))
+        .ok_or_else(|| format!("no tile matches edge {} of tile {}", edge_id, curr_tile.id))?;
+    debug!(
+        "  tile {} edge {} -> tile {}",
+        curr_tile.id, edge_id, next_tile_id
+    );
+    let next_tile = tiles_by_id
+        .remove(&next_tile_id)
+        .ok_or_else(|| format!("tile {} matches more than one other tile", next_tile_id))?;
+
+    // edges run clockwise, so the facing edge of the next tile reads backwards
+    let facing_edge = (edge_id + 2) % 4;
+    orientations(&next_tile)
+        .into_iter()
+        .find(|tile| tile.backward_edges[facing_edge] == curr_tile.forward_edges[edge_id])
+        .map(Rc::new)
+        .ok_or_else(|| {
+            format!(
+                "tile {} does not fit next to tile {}",
+                next_tile_id, curr_tile.id
+            )
+        })
+}
+
+/// All eight orientations of a tile, flipped ones first.
+fn orientations(tile: &Tile) -> Vec<Tile> {
+    [true, false]
+        .iter()
+        .cartesian_product([0, 90, 180, 270].iter())
+        .map(|(flip, rotate)| tile.translate(*flip, *rotate))
+        .collect()
+}
+
+pub fn has_match(edge: &[u16], edge_id: &usize, cache: &HashMap<u16, HashSet<usize>>) -> bool {
+    cache.get(&edge[*edge_id]).is_some_and(|ids| ids.len() > 1)
+}
+
+pub fn is_unmatched_edge(
+    tile: &Tile,
+    edge_i: &usize,
+    cache: &HashMap<u16, HashSet<usize>>,
+) -> bool {
+    !has_match(&tile.forward_edges, edge_i, cache)
+        && !has_match(&tile.backward_edges, edge_i, cache)
+}
+
+pub fn get_tiles_by_matching_edges(
+    tiles: &[Rc<Tile>],
+    cache: &HashMap<u16, HashSet<usize>>,
+    matched_edges: usize,
+) -> HashSet<usize> {
+    tiles
+        .iter()
+        .filter_map(|tile| {
+            if (0usize..4)
+                .filter(|i| is_unmatched_edge(tile, i, cache))
+                .count()
+                == matched_edges
+            {
+                Some(tile.id)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_assemble_tiles_one_tile() {
+        assert_eq!(
+            assemble_tiles(vec![Rc::new(
+                Tile::from_lines(vec![
+                    "Tile 1:".to_string(),
+                    "#.".to_string(),
+                    "..".to_string()
+                ])
+                .unwrap()
+            )]),
+            Err("no tile has exactly two unmatched edges to be a corner".to_string())
+        )
+    }
+
+    #[test]
+    fn test_assemble_tiles() {
+        assert_eq!(
+            assemble_tiles(vec![
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2311:".to_string(),
+                        "..##.#..#.".to_string(),
+                        "##..#.....".to_string(),
+                        "#...##..#.".to_string(),
+                        "####.#...#".to_string(),
+                        "##.##.###.".to_string(),
+                        "##...#.###".to_string(),
+                        ".#.#.#..##".to_string(),
+                        "..#....#..".to_string(),
+                        "###...#.#.".to_string(),
+                        "..###..###".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1951:".to_string(),
+                        "#.##...##.".to_string(),
+                        "#.####...#".to_string(),
+                        ".....#..##".to_string(),
+                        "#...######".to_string(),
+                        ".##.#....#".to_string(),
+                        ".###.#####".to_string(),
+                        "###.##.##.".to_string(),
+                        ".###....#.".to_string(),
+                        "..#.#..#.#".to_string(),
+                        "#...##.#..".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1171:".to_string(),
+                        "####...##.".to_string(),
+                        "#..##.#..#".to_string(),
+                        "##.#..#.#.".to_string(),
+                        ".###.####.".to_string(),
+                        "..###.####".to_string(),
+                        ".##....##.".to_string(),
+                        ".#...####.".to_string(),
+                        "#.##.####.".to_string(),
+                        "####..#...".to_string(),
+                        ".....##...".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1427:".to_string(),
+                        "###.##.#..".to_string(),
+                        ".#..#.##..".to_string(),
+                        ".#.##.#..#".to_string(),
+                        "#.#.#.##.#".to_string(),
+                        "....#...##".to_string(),
+                        "...##..##.".to_string(),
+                        "...#.#####".to_string(),
+                        ".#.####.#.".to_string(),
+                        "..#..###.#".to_string(),
+                        "..##.#..#.".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 1489:".to_string(),
+                        "##.#.#....".to_string(),
+                        "..##...#..".to_string(),
+                        ".##..##...".to_string(),
+                        "..#...#...".to_string(),
+                        "#####...#.".to_string(),
+                        "#..#.#.#.#".to_string(),
+                        "...#.#.#..".to_string(),
+                        "##.#...##.".to_string(),
+                        "..##.##.##".to_string(),
+                        "###.##.#..".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2473:".to_string(),
+                        "#....####.".to_string(),
+                        "#..#.##...".to_string(),
+                        "#.##..#...".to_string(),
+                        "######.#.#".to_string(),
+                        ".#...#.#.#".to_string(),
+                        ".#########".to_string(),
+                        ".###.#..#.".to_string(),
+                        "########.#".to_string(),
+                        "##...##.#.".to_string(),
+                        "..###.#.#.".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2971:".to_string(),
+                        "..#.#....#".to_string(),
+                        "#...###...".to_string(),
+                        "#.#.###...".to_string(),
+                        "##.##..#..".to_string(),
+                        ".#####..##".to_string(),
+                        ".#..####.#".to_string(),
+                        "#..#.#..#.".to_string(),
+                        "..####.###".to_string(),
+                        "..#.#.###.".to_string(),
+                        "...#.#.#.#".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 2729:".to_string(),
+                        "...#.#.#.#".to_string(),
+                        "####.#....".to_string(),
+                        "..#.#.....".to_string(),
+                        "....#..#.#".to_string(),
+                        ".##..##.#.".to_string(),
+                        ".#.####...".to_string(),
+                        "####.#.#..".to_string(),
+                        "##.####...".to_string(),
+                        "##..#.##..".to_string(),
+                        "#.##...##.".to_string(),
+                    ])
+                    .unwrap()
+                ),
+                Rc::new(
+                    Tile::from_lines(vec![
+                        "Tile 3079:".to_string(),
+                        "#.#.#####.".to_string(),
+                        ".#..######".to_string(),
+                        "..#.......".to_string(),
+                        "######....".to_string(),
+                        "####.#..#.".to_string(),
+                        ".#...#.##.".to_string(),
+                        "#.#####.##".to_string(),
+                        "..#.###...".to_string(),
+                        "..#.......".to_string(),
+                        "..#.###...".to_string(),
+                    ])
+                    .unwrap()
+                )
+            ]),
+            Ok(vec![
+                vec![
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 1171:".to_string(),
+                            ".....##...".to_string(),
+                            "####..#...".to_string(),
+                            "#.##.####.".to_string(),
+                            ".#...####.".to_string(),
+                            ".##....##.".to_string(),
+                            "..###.####".to_string(),
+                            ".###.####.".to_string(),
+                            "##.#..#.#.".to_string(),
+                            "#..##.#..#".to_string(),
+                            "####...##.".to_string(),
+                        ])
+                        .unwrap()
+                    ),
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 1489:".to_string(),
+                            "....#.#.##".to_string(),
+                            "..#...##..".to_string(),
+                            "...##..##.".to_string(),
+                            "...#...#..".to_string(),
+                            ".#...#####".to_string(),
+                            "#.#.#.#..#".to_string(),
+                            "..#.#.#...".to_string(),
+                            ".##...#.##".to_string(),
+                            "##.##.##..".to_string(),
+                            "..#.##.###".to_string(),
+                        ])
+                        .unwrap()
+                    ),
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 2971:".to_string(),
+                            "#....#.#..".to_string(),
+                            "...###...#".to_string(),
+                            "...###.#.#".to_string(),
+                            "..#..##.##".to_string(),
+                            "##..#####.".to_string(),
+                            "#.####..#.".to_string(),
+                            ".#..#.#..#".to_string(),
+                            "###.####..".to_string(),
+                            ".###.#.#..".to_string(),
+                            "#.#.#.#...".to_string()
+                        ])
+                        .unwrap()
+                    )
+                ],
+                vec![
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 2473:".to_string(),
+                            "####...##.".to_string(),
+                            "...######.".to_string(),
+                            "..##.###.#".to_string(),
+                            ".###.###.#".to_string(),
+                            "...#.#.#.#".to_string(),
+                            "##.######.".to_string(),
+                            "###..#.###".to_string(),
+                            "#..###.#..".to_string(),
+                            "#....##.##".to_string(),
+                            "...###.#..".to_string(),
+                        ])
+                        .unwrap()
+                    ),
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 1427:".to_string(),
+                            "..#.##.###".to_string(),
+                            "..##.#..#.".to_string(),
+                            "#..#.##.#.".to_string(),
+                            "#.##.#.#.#".to_string(),
+                            "##...#....".to_string(),
+                            ".##..##...".to_string(),
+                            "#####.#...".to_string(),
+                            ".#.####.#.".to_string(),
+                            "#.###..#..".to_string(),
+                            ".#..#.##..".to_string(),
+                        ])
+                        .unwrap()
+                    ),
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 2729:".to_string(),
+                            "#.#.#.#...".to_string(),
+                            "....#.####".to_string(),
+                            ".....#.#..".to_string(),
+                            "#.#..#....".to_string(),
+                            ".#.##..##.".to_string(),
+                            "...####.#.".to_string(),
+                            "..#.#.####".to_string(),
+                            "...####.##".to_string(),
+                            "..##.#..##".to_string(),
+                            ".##...##.#".to_string(),
+                        ])
+                        .unwrap()
+                    )
+                ],
+                vec![
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 3079:".to_string(),
+                            "...###.#..".to_string(),
+                            ".......#..".to_string(),
+                            "...###.#..".to_string(),
+                            "##.#####.#".to_string(),
+                            ".##.#...#.".to_string(),
+                            ".#..#.####".to_string(),
+                            "....######".to_string(),
+                            ".......#..".to_string(),
+                            "######..#.".to_string(),
+                            ".#####.#.#".to_string()
+                        ])
+                        .unwrap()
+                    ),
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 2311:".to_string(),
+                            ".#..#.##..".to_string(),
+                            ".....#..##".to_string(),
+                            ".#..##...#".to_string(),
+                            "#...#.####".to_string(),
+                            ".###.##.##".to_string(),
+                            "###.#...##".to_string(),
+                            "##..#.#.#.".to_string(),
+                            "..#....#..".to_string(),
+                            ".#.#...###".to_string(),
+                            "###..###..".to_string(),
+                        ])
+                        .unwrap()
+                    ),
+                    Rc::new(
+                        Tile::from_lines(vec![
+                            "Tile 1951:".to_string(),
+                            ".##...##.#".to_string(),
+                            "#...####.#".to_string(),
+                            "##..#.....".to_string(),
+                            "######...#".to_string(),
+                            "#....#.##.".to_string(),
+                            "#####.###.".to_string(),
+                            ".##.##.###".to_string(),
+                            ".#....###.".to_string(),
+                            "#.#..#.#..".to_string(),
+                            "..#.##...#".to_string()
+                        ])
+                        .unwrap()
+                    )
+                ]
+            ])
+        )
+    }
+}
@@ -0,0 +1,170 @@
+use crate::parse::{self, ParseError};
+use std::collections::{HashMap, HashSet};
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<Food>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| Food::from_line(line).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Food>, ParseError>>()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Food {
+    pub ingredients: HashSet<String>,
+    pub allergens: HashSet<String>,
+}
+
+impl Food {
+    pub fn new(ingredients: HashSet<String>, allergens: HashSet<String>) -> Food {
+        Food {
+            ingredients,
+            allergens,
+        }
+    }
+
+    pub fn from_line(line: &str) -> Result<Food, ParseError> {
+        let pieces = line
+            .strip_suffix(')')
+            .map(|stripped| stripped.split(" (contains ").collect::<Vec<&str>>())
+            .filter(|pieces| pieces.len() == 2)
+            .ok_or_else(|| {
+                ParseError::within(
+                    line,
+                    line,
+                    "expected ingredients followed by '(contains <allergens>)'",
+                )
+            })?;
+        let ingredients = pieces[0].split(' ').map(|s| s.to_string()).collect();
+        let allergens = pieces[1]
+            .replace(',', "")
+            .split(' ')
+            .map(|s| s.to_string())
+            .collect();
+        Ok(Food::new(ingredients, allergens))
+    }
+}
+
+pub fn find_allergen_ingredients(foods: &[Food]) -> HashMap<String, String> {
+    // build map of allergen -> possible ingredients (only ingredients present in all foods that
+    // contain the allergen)
+    let mut allergens: HashMap<String, HashSet<String>> = HashMap::new();
+    for food in foods {
+        for allergen in food.allergens.iter() {
+            let ingredients = allergens
+                .get(allergen)
+                .map(|ingredients| {
+                    ingredients
+                        .intersection(&food.ingredients)
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_else(|| food.ingredients.clone());
+            allergens.insert(allergen.clone(), ingredients);
+        }
+    }
+
+    // find all allergens that have only one possible ingredient, then remove that ingredient from
+    // all other allergens still left to solve, until no allergens are left
+    let mut allergen_ingredient: HashMap<String, String> = HashMap::new();
+    while !allergens.is_empty() {
+        let pinned = allergens
+            .iter()
+            .filter_map(|(allergen, ingredients)| {
+                if ingredients.len() == 1 {
+                    Some((allergen.clone(), ingredients.iter().next().unwrap().clone()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<(String, String)>>();
+
+        for (allergen, ingredient) in pinned {
+            allergens.remove(&allergen);
+            allergens.iter_mut().for_each(|(_, ingredients)| {
+                ingredients.remove(&ingredient);
+            });
+            allergen_ingredient.insert(allergen, ingredient);
+        }
+    }
+
+    allergen_ingredient
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_food_from_line() {
+        assert_eq!(
+            Food::from_line("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"),
+            Ok(Food::new(
+                hashset!(
+                    "mxmxvkd".to_string(),
+                    "kfcds".to_string(),
+                    "sqjhc".to_string(),
+                    "nhms".to_string()
+                ),
+                hashset!("dairy".to_string(), "fish".to_string())
+            ))
+        )
+    }
+
+    #[test]
+    fn test_food_from_line_missing_allergens() {
+        assert_eq!(
+            Food::from_line("mxmxvkd kfcds"),
+            Err(ParseError::new(
+                1,
+                1,
+                "mxmxvkd kfcds",
+                "expected ingredients followed by '(contains <allergens>)'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_find_allergen_ingredients() {
+        assert_eq!(
+            find_allergen_ingredients(&[
+                Food::new(
+                    hashset!(
+                        "mxmxvkd".to_string(),
+                        "kfcds".to_string(),
+                        "sqjhc".to_string(),
+                        "nhms".to_string()
+                    ),
+                    hashset!("dairy".to_string(), "fish".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "trh".to_string(),
+                        "fvjkl".to_string(),
+                        "sbzzf".to_string(),
+                        "mxmxvkd".to_string()
+                    ),
+                    hashset!("dairy".to_string())
+                ),
+                Food::new(
+                    hashset!("sqjhc".to_string(), "fvjkl".to_string()),
+                    hashset!("soy".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "sqjhc".to_string(),
+                        "mxmxvkd".to_string(),
+                        "sbzzf".to_string()
+                    ),
+                    hashset!("fish".to_string())
+                )
+            ]),
+            hashmap!(
+                "dairy".to_string() => "mxmxvkd".to_string(),
+                "fish".to_string() => "sqjhc".to_string(),
+                "soy".to_string() => "fvjkl".to_string()
+            )
+        )
+    }
+}
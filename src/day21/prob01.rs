@@ -0,0 +1,112 @@
+use crate::day21::{find_allergen_ingredients, Food};
+use std::collections::HashSet;
+
+pub fn problem1(foods: Vec<Food>) -> usize {
+    let safe_ingredients = find_safe_ingredients(&foods);
+    foods
+        .iter()
+        .flat_map(|food| food.ingredients.intersection(&safe_ingredients))
+        .count()
+}
+
+pub fn find_safe_ingredients(foods: &[Food]) -> HashSet<String> {
+    let allergen_ingredients = find_allergen_ingredients(foods)
+        .into_values()
+        .collect::<HashSet<String>>();
+    let all_ingredients = foods
+        .iter()
+        .flat_map(|food| food.ingredients.clone())
+        .collect::<HashSet<String>>();
+    all_ingredients
+        .difference(&allergen_ingredients)
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_safe_ingredients() {
+        assert_eq!(
+            find_safe_ingredients(&[
+                Food::new(
+                    hashset!(
+                        "mxmxvkd".to_string(),
+                        "kfcds".to_string(),
+                        "sqjhc".to_string(),
+                        "nhms".to_string()
+                    ),
+                    hashset!("dairy".to_string(), "fish".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "trh".to_string(),
+                        "fvjkl".to_string(),
+                        "sbzzf".to_string(),
+                        "mxmxvkd".to_string()
+                    ),
+                    hashset!("dairy".to_string())
+                ),
+                Food::new(
+                    hashset!("sqjhc".to_string(), "fvjkl".to_string()),
+                    hashset!("soy".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "sqjhc".to_string(),
+                        "mxmxvkd".to_string(),
+                        "sbzzf".to_string()
+                    ),
+                    hashset!("fish".to_string())
+                )
+            ]),
+            hashset!(
+                "kfcds".to_string(),
+                "nhms".to_string(),
+                "sbzzf".to_string(),
+                "trh".to_string()
+            )
+        )
+    }
+
+    #[test]
+    fn test_problem1() {
+        assert_eq!(
+            problem1(vec![
+                Food::new(
+                    hashset!(
+                        "mxmxvkd".to_string(),
+                        "kfcds".to_string(),
+                        "sqjhc".to_string(),
+                        "nhms".to_string()
+                    ),
+                    hashset!("dairy".to_string(), "fish".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "trh".to_string(),
+                        "fvjkl".to_string(),
+                        "sbzzf".to_string(),
+                        "mxmxvkd".to_string()
+                    ),
+                    hashset!("dairy".to_string())
+                ),
+                Food::new(
+                    hashset!("sqjhc".to_string(), "fvjkl".to_string()),
+                    hashset!("soy".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "sqjhc".to_string(),
+                        "mxmxvkd".to_string(),
+                        "sbzzf".to_string()
+                    ),
+                    hashset!("fish".to_string())
+                )
+            ]),
+            5
+        )
+    }
+}
@@ -0,0 +1,54 @@
+use crate::day21::{find_allergen_ingredients, Food};
+use itertools::Itertools;
+
+pub fn problem2(foods: Vec<Food>) -> String {
+    find_allergen_ingredients(&foods)
+        .into_iter()
+        .sorted()
+        .map(|(_, ingredient)| ingredient)
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_problem2() {
+        assert_eq!(
+            problem2(vec![
+                Food::new(
+                    hashset!(
+                        "mxmxvkd".to_string(),
+                        "kfcds".to_string(),
+                        "sqjhc".to_string(),
+                        "nhms".to_string()
+                    ),
+                    hashset!("dairy".to_string(), "fish".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "trh".to_string(),
+                        "fvjkl".to_string(),
+                        "sbzzf".to_string(),
+                        "mxmxvkd".to_string()
+                    ),
+                    hashset!("dairy".to_string())
+                ),
+                Food::new(
+                    hashset!("sqjhc".to_string(), "fvjkl".to_string()),
+                    hashset!("soy".to_string())
+                ),
+                Food::new(
+                    hashset!(
+                        "sqjhc".to_string(),
+                        "mxmxvkd".to_string(),
+                        "sbzzf".to_string()
+                    ),
+                    hashset!("fish".to_string())
+                )
+            ]),
+            "mxmxvkd,sqjhc,fvjkl".to_string()
+        )
+    }
+}
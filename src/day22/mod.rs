@@ -0,0 +1,106 @@
+use crate::parse::{self, ParseError};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<(VecDeque<u8>, VecDeque<u8>), ParseError> {
+    parse_decks(
+        input
+            .lines()
+            .map(|val| val.to_string())
+            .collect::<Vec<String>>(),
+    )
+}
+
+/// Parse both players' decks.  Each deck starts with a `Player N:` heading, and `lines` should
+/// be every line of the input so that errors point at the right line.
+pub fn parse_decks(lines: Vec<String>) -> Result<(VecDeque<u8>, VecDeque<u8>), ParseError> {
+    let mut decks: Vec<VecDeque<u8>> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with("Player") {
+            decks.push(VecDeque::new());
+            continue;
+        }
+        let card = parse::number::<u8>(line, line).map_err(|e| e.on_line(i + 1))?;
+        decks
+            .last_mut()
+            .ok_or_else(|| {
+                ParseError::within(line, line, "expected a 'Player' heading before the cards")
+                    .on_line(i + 1)
+            })?
+            .push_back(card);
+    }
+    match <[VecDeque<u8>; 2]>::try_from(decks) {
+        Ok([player1, player2]) => Ok((player1, player2)),
+        Err(decks) => Err(ParseError::new(
+            lines.len() + 1,
+            1,
+            "",
+            &format!("expected decks for 2 players, found {}", decks.len()),
+        )),
+    }
+}
+
+pub fn calc_score(winner: VecDeque<u8>) -> u64 {
+    winner
+        .into_iter()
+        .rev()
+        .enumerate()
+        .fold(0u64, |acc, (i, card)| acc + (i as u64 + 1) * card as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_decks() {
+        assert_eq!(
+            parse_decks(vec![
+                "Player 1:".to_string(),
+                "9".to_string(),
+                "2".to_string(),
+                "6".to_string(),
+                "3".to_string(),
+                "1".to_string(),
+                "".to_string(),
+                "Player 2:".to_string(),
+                "5".to_string(),
+                "8".to_string(),
+                "4".to_string(),
+                "7".to_string(),
+                "10".to_string(),
+            ]),
+            Ok((
+                VecDeque::from(vec![9, 2, 6, 3, 1]),
+                VecDeque::from(vec![5, 8, 4, 7, 10])
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_decks_bad_card() {
+        assert_eq!(
+            parse("Player 1:\n9\n2\n\nPlayer 2:\n5\nten\n"),
+            Err(ParseError::new(7, 1, "ten", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_parse_decks_missing_player() {
+        assert_eq!(
+            parse("Player 1:\n9\n2\n"),
+            Err(ParseError::new(
+                4,
+                1,
+                "",
+                "expected decks for 2 players, found 1"
+            ))
+        )
+    }
+}
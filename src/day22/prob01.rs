@@ -0,0 +1,72 @@
+use crate::day22::calc_score;
+use std::collections::VecDeque;
+
+pub fn play_round(p1: &mut VecDeque<u8>, p2: &mut VecDeque<u8>) {
+    let top1 = p1
+        .pop_front()
+        .expect("Tried to play card from empty hand for p1");
+    let top2 = p2
+        .pop_front()
+        .expect("Tried to play card from empty hand for p2");
+    if top1 > top2 {
+        p1.push_back(top1);
+        p1.push_back(top2);
+    } else {
+        p2.push_back(top2);
+        p2.push_back(top1);
+    }
+}
+
+pub fn play_game(p1: VecDeque<u8>, p2: VecDeque<u8>) -> u64 {
+    let mut p1 = p1;
+    let mut p2 = p2;
+    while !p1.is_empty() && !p2.is_empty() {
+        play_round(&mut p1, &mut p2);
+    }
+    let winner = if p1.is_empty() { p2 } else { p1 };
+    calc_score(winner)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_play_round_p1() {
+        let mut p1 = VecDeque::from(vec![9, 2, 6, 3, 1]);
+        let mut p2 = VecDeque::from(vec![5, 8, 4, 7, 10]);
+        play_round(&mut p1, &mut p2);
+        assert_eq!(
+            (p1, p2),
+            (
+                VecDeque::from(vec![2, 6, 3, 1, 9, 5]),
+                VecDeque::from(vec![8, 4, 7, 10])
+            )
+        )
+    }
+
+    #[test]
+    fn test_play_round_p2() {
+        let mut p1 = VecDeque::from(vec![2, 6, 3, 1, 9, 5]);
+        let mut p2 = VecDeque::from(vec![8, 4, 7, 10]);
+        play_round(&mut p1, &mut p2);
+        assert_eq!(
+            (p1, p2),
+            (
+                VecDeque::from(vec![6, 3, 1, 9, 5]),
+                VecDeque::from(vec![4, 7, 10, 8, 2])
+            )
+        )
+    }
+
+    #[test]
+    fn test_play_game() {
+        assert_eq!(
+            play_game(
+                VecDeque::from(vec![9, 2, 6, 3, 1]),
+                VecDeque::from(vec![5, 8, 4, 7, 10])
+            ),
+            306
+        )
+    }
+}
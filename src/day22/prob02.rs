@@ -0,0 +1,137 @@
+use crate::day22::calc_score;
+use std::collections::{HashSet, VecDeque};
+
+pub fn play_round(p1: &mut VecDeque<u8>, p2: &mut VecDeque<u8>) {
+    trace!("Player 1's deck: {:?}", p1);
+    trace!("Player 2's deck: {:?}", p2);
+    let top1 = p1
+        .pop_front()
+        .expect("Tried to play card from empty hand for p1");
+    let top2 = p2
+        .pop_front()
+        .expect("Tried to play card from empty hand for p2");
+    trace!("Player 1 plays: {}", top1);
+    trace!("Player 2 plays: {}", top2);
+    if top1 as usize <= p1.len() && top2 as usize <= p2.len() {
+        trace!("Playing a sub-game to determine the winner...");
+        let p1_rec = p1
+            .iter()
+            .take(top1 as usize)
+            .copied()
+            .collect::<VecDeque<u8>>();
+        let p2_rec = p2
+            .iter()
+            .take(top2 as usize)
+            .copied()
+            .collect::<VecDeque<u8>>();
+        let (winner, _) = play_game(p1_rec, p2_rec);
+        if winner == 1 {
+            trace!("Player 1 wins round");
+            p1.push_back(top1);
+            p1.push_back(top2);
+        } else {
+            trace!("Player 2 wins round");
+            p2.push_back(top2);
+            p2.push_back(top1);
+        }
+    } else if top1 > top2 {
+        trace!("Player 1 wins round");
+        p1.push_back(top1);
+        p1.push_back(top2);
+    } else {
+        trace!("Player 2 wins round");
+        p2.push_back(top2);
+        p2.push_back(top1);
+    }
+}
+
+pub fn play_game(p1: VecDeque<u8>, p2: VecDeque<u8>) -> (u8, u64) {
+    let mut prev_hands: HashSet<(VecDeque<u8>, VecDeque<u8>)> = HashSet::new();
+    let mut p1 = p1;
+    let mut p2 = p2;
+    while !p1.is_empty() && !p2.is_empty() {
+        if prev_hands.contains(&(p1.clone(), p2.clone())) {
+            debug!("Hands repeated, player 1 wins\n  {:?}\n  {:?}", p1, p2);
+            return (1, calc_score(p1));
+        }
+        prev_hands.insert((p1.clone(), p2.clone()));
+        play_round(&mut p1, &mut p2);
+    }
+    if p1.is_empty() {
+        (2, calc_score(p2))
+    } else {
+        (1, calc_score(p1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_play_round_p1() {
+        let mut p1 = VecDeque::from(vec![9, 2, 6, 3, 1]);
+        let mut p2 = VecDeque::from(vec![5, 8, 4, 7, 10]);
+        play_round(&mut p1, &mut p2);
+        assert_eq!(
+            (p1, p2),
+            (
+                VecDeque::from(vec![2, 6, 3, 1, 9, 5]),
+                VecDeque::from(vec![8, 4, 7, 10])
+            )
+        )
+    }
+
+    #[test]
+    fn test_play_round_p2() {
+        let mut p1 = VecDeque::from(vec![2, 6, 3, 1, 9, 5]);
+        let mut p2 = VecDeque::from(vec![8, 4, 7, 10]);
+        play_round(&mut p1, &mut p2);
+        assert_eq!(
+            (p1, p2),
+            (
+                VecDeque::from(vec![6, 3, 1, 9, 5]),
+                VecDeque::from(vec![4, 7, 10, 8, 2])
+            )
+        )
+    }
+
+    #[test]
+    fn test_play_round_p9() {
+        let mut p1 = VecDeque::from(vec![4, 9, 8, 5, 2]);
+        let mut p2 = VecDeque::from(vec![3, 10, 1, 7, 6]);
+        play_round(&mut p1, &mut p2);
+        assert_eq!(
+            (p1, p2),
+            (
+                VecDeque::from(vec![9, 8, 5, 2]),
+                VecDeque::from(vec![10, 1, 7, 6, 3, 4])
+            )
+        )
+    }
+
+    #[test]
+    fn test_play_round_p15() {
+        let mut p1 = VecDeque::from(vec![1, 8, 3]);
+        let mut p2 = VecDeque::from(vec![4, 10, 9, 7, 5, 6, 2]);
+        play_round(&mut p1, &mut p2);
+        assert_eq!(
+            (p1, p2),
+            (
+                VecDeque::from(vec![8, 3]),
+                VecDeque::from(vec![10, 9, 7, 5, 6, 2, 4, 1])
+            )
+        )
+    }
+
+    #[test]
+    fn test_play_game() {
+        assert_eq!(
+            play_game(
+                VecDeque::from(vec![9, 2, 6, 3, 1]),
+                VecDeque::from(vec![5, 8, 4, 7, 10]),
+            ),
+            (2, 291)
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use crate::parse::{self, ParseError};
+use std::collections::HashSet;
+
+pub mod prob01;
+pub mod prob02;
+
+/// The fewest cups a game can be played with: the current cup, the three it picks up and a
+/// destination.
+const MIN_CUPS: usize = 5;
+
+/// Parse the cup labels, written as a single string of digits like `389125467`.  The labels must
+/// be the numbers from 1 up to the number of cups, in any order, with at least `MIN_CUPS` cups.
+pub fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+    let (i, line) = parse::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected the cup labels"))?;
+    let line = line.trim_end();
+    let mut seen: HashSet<u8> = HashSet::new();
+    let labels = line
+        .char_indices()
+        .map(|(j, c)| {
+            let raw = &line[j..j + c.len_utf8()];
+            match c.to_digit(10) {
+                Some(label) if label > 0 => {
+                    if seen.insert(label as u8) {
+                        Ok(label as u8)
+                    } else {
+                        Err(ParseError::within(line, raw, "cup label appears twice"))
+                    }
+                }
+                _ => Err(ParseError::within(
+                    line,
+                    raw,
+                    "expected a cup label from 1 to 9",
+                )),
+            }
+        })
+        .collect::<Result<Vec<u8>, ParseError>>()
+        .map_err(|e| e.on_line(i))?;
+    if labels.len() < MIN_CUPS {
+        return Err(ParseError::within(
+            line,
+            line,
+            &format!("expected at least {} cups", MIN_CUPS),
+        )
+        .on_line(i));
+    }
+    // The labels are distinct, so if none is above the number of cups then none is missing.
+    if let Some(j) = labels
+        .iter()
+        .position(|&label| label as usize > labels.len())
+    {
+        return Err(ParseError::within(
+            line,
+            &line[j..=j],
+            &format!(
+                "expected the labels 1 to {} with none missing",
+                labels.len()
+            ),
+        )
+        .on_line(i));
+    }
+    Ok(labels)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("389125467\n"), Ok(vec![3, 8, 9, 1, 2, 5, 4, 6, 7]))
+    }
+
+    #[test]
+    fn test_parse_zero() {
+        assert_eq!(
+            parse("3890"),
+            Err(ParseError::new(
+                1,
+                4,
+                "0",
+                "expected a cup label from 1 to 9"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_fewer_labels() {
+        assert_eq!(parse("53142"), Ok(vec![5, 3, 1, 4, 2]))
+    }
+
+    #[test]
+    fn test_parse_too_few() {
+        assert_eq!(
+            (parse("39"), parse("1")),
+            (
+                Err(ParseError::new(1, 1, "39", "expected at least 5 cups")),
+                Err(ParseError::new(1, 1, "1", "expected at least 5 cups"))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_missing_label() {
+        assert_eq!(
+            parse("24136\n"),
+            Err(ParseError::new(
+                1,
+                5,
+                "6",
+                "expected the labels 1 to 5 with none missing"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_duplicate() {
+        assert_eq!(
+            parse("38938"),
+            Err(ParseError::new(1, 4, "3", "cup label appears twice"))
+        )
+    }
+}
@@ -0,0 +1,248 @@
+use crate::parse::{self, ParseError};
+
+pub mod prob01;
+pub mod prob02;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Direction>>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| parse_directions(line).map_err(|e| e.on_line(i)))
+        .collect::<Result<Vec<Vec<Direction>>, ParseError>>()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+pub fn parse_directions(raw: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut directions: Vec<Direction> = Vec::new();
+    let mut chars = raw.char_indices();
+    while let Some((i, char)) = chars.next() {
+        let direction = match char {
+            'e' => Direction::East,
+            'w' => Direction::West,
+            'n' | 's' => {
+                let (j, next) = chars.next().unwrap_or((raw.len(), ' '));
+                let end = (j + next.len_utf8()).min(raw.len());
+                match (char, next) {
+                    ('n', 'e') => Direction::NorthEast,
+                    ('n', 'w') => Direction::NorthWest,
+                    ('s', 'e') => Direction::SouthEast,
+                    ('s', 'w') => Direction::SouthWest,
+                    _ => {
+                        return Err(ParseError::within(
+                            raw,
+                            &raw[i..end],
+                            "expected 'e' or 'w' after 'n' or 's'",
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(ParseError::within(
+                    raw,
+                    &raw[i..i + char.len_utf8()],
+                    "expected one of 'e', 'se', 'sw', 'w', 'nw' or 'ne'",
+                ))
+            }
+        };
+        directions.push(direction);
+    }
+    Ok(directions)
+}
+
+pub fn calc_target(direction: Vec<Direction>) -> (i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+
+    for d in direction {
+        match d {
+            Direction::East => x += 1,
+            Direction::SouthEast => {
+                y += 1;
+                if (y % 2i32).abs() == 0 {
+                    x += 1;
+                }
+            }
+            Direction::SouthWest => {
+                y += 1;
+                if (y % 2i32).abs() == 1 {
+                    x -= 1;
+                }
+            }
+            Direction::West => x -= 1,
+            Direction::NorthWest => {
+                y -= 1;
+                if (y % 2i32).abs() == 1 {
+                    x -= 1;
+                }
+            }
+            Direction::NorthEast => {
+                y -= 1;
+                if (y % 2i32).abs() == 0 {
+                    x += 1;
+                }
+            }
+        }
+    }
+
+    (x, y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_directions() {
+        assert_eq!(
+            parse_directions("eseswwnwne"),
+            Ok(vec![
+                Direction::East,
+                Direction::SouthEast,
+                Direction::SouthWest,
+                Direction::West,
+                Direction::NorthWest,
+                Direction::NorthEast
+            ])
+        )
+    }
+
+    #[test]
+    fn test_parse_directions_bad_char() {
+        assert_eq!(
+            parse_directions("esexw"),
+            Err(ParseError::new(
+                1,
+                4,
+                "x",
+                "expected one of 'e', 'se', 'sw', 'w', 'nw' or 'ne'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_directions_bad_diagonal() {
+        assert_eq!(
+            parse_directions("enn"),
+            Err(ParseError::new(
+                1,
+                2,
+                "nn",
+                "expected 'e' or 'w' after 'n' or 's'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_directions_trailing_north() {
+        assert_eq!(
+            parse_directions("wn"),
+            Err(ParseError::new(
+                1,
+                2,
+                "n",
+                "expected 'e' or 'w' after 'n' or 's'"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_calc_target1() {
+        assert_eq!(
+            calc_target(vec![Direction::East, Direction::SouthEast, Direction::West]),
+            (0, 1)
+        )
+    }
+
+    #[test]
+    fn test_calc_target2() {
+        assert_eq!(
+            calc_target(vec![
+                Direction::NorthWest,
+                Direction::West,
+                Direction::SouthWest,
+                Direction::East,
+                Direction::East
+            ]),
+            (0, 0)
+        )
+    }
+
+    #[test]
+    fn test_calc_target_e() {
+        assert_eq!(calc_target(vec![Direction::East]), (1, 0))
+    }
+
+    #[test]
+    fn test_calc_target_w() {
+        assert_eq!(calc_target(vec![Direction::West]), (-1, 0))
+    }
+
+    #[test]
+    fn test_calc_target_se() {
+        assert_eq!(calc_target(vec![Direction::SouthEast]), (0, 1))
+    }
+
+    #[test]
+    fn test_calc_target_sw() {
+        assert_eq!(calc_target(vec![Direction::SouthWest]), (-1, 1))
+    }
+
+    #[test]
+    fn test_calc_target_ne() {
+        assert_eq!(calc_target(vec![Direction::NorthEast]), (0, -1))
+    }
+
+    #[test]
+    fn test_calc_target_nw() {
+        assert_eq!(calc_target(vec![Direction::NorthWest]), (-1, -1))
+    }
+
+    #[test]
+    fn test_calc_target_e_e() {
+        assert_eq!(calc_target(vec![Direction::East, Direction::East]), (2, 0))
+    }
+
+    #[test]
+    fn test_calc_target_w_w() {
+        assert_eq!(calc_target(vec![Direction::West, Direction::West]), (-2, 0))
+    }
+
+    #[test]
+    fn test_calc_target_se_se() {
+        assert_eq!(
+            calc_target(vec![Direction::SouthEast, Direction::SouthEast]),
+            (1, 2)
+        )
+    }
+
+    #[test]
+    fn test_calc_target_sw_sw() {
+        assert_eq!(
+            calc_target(vec![Direction::SouthWest, Direction::SouthWest]),
+            (-1, 2)
+        )
+    }
+
+    #[test]
+    fn test_calc_target_ne_ne() {
+        assert_eq!(
+            calc_target(vec![Direction::NorthEast, Direction::NorthEast]),
+            (1, -2)
+        )
+    }
+
+    #[test]
+    fn test_calc_target_nw_nw() {
+        assert_eq!(
+            calc_target(vec![Direction::NorthWest, Direction::NorthWest]),
+            (-1, -2)
+        )
+    }
+}
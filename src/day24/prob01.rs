@@ -0,0 +1,46 @@
+use crate::day24::{calc_target, Direction};
+use std::collections::HashMap;
+
+pub fn flip_tiles(directions: Vec<Vec<Direction>>) -> usize {
+    let mut tiles: HashMap<(i32, i32), bool> = HashMap::new();
+    for direction in directions {
+        let val = tiles.entry(calc_target(direction)).or_default();
+        *val = !*val;
+    }
+    tiles.values().filter(|val| **val).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day24::parse_directions;
+
+    #[test]
+    fn test_flip_tiles() {
+        assert_eq!(
+            flip_tiles(vec![
+                parse_directions("sesenwnenenewseeswwswswwnenewsewsw").unwrap(),
+                parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap(),
+                parse_directions("seswneswswsenwwnwse").unwrap(),
+                parse_directions("nwnwneseeswswnenewneswwnewseswneseene").unwrap(),
+                parse_directions("swweswneswnenwsewnwneneseenw").unwrap(),
+                parse_directions("eesenwseswswnenwswnwnwsewwnwsene").unwrap(),
+                parse_directions("sewnenenenesenwsewnenwwwse").unwrap(),
+                parse_directions("wenwwweseeeweswwwnwwe").unwrap(),
+                parse_directions("wsweesenenewnwwnwsenewsenwwsesesenwne").unwrap(),
+                parse_directions("neeswseenwwswnwswswnw").unwrap(),
+                parse_directions("nenwswwsewswnenenewsenwsenwnesesenew").unwrap(),
+                parse_directions("enewnwewneswsewnwswenweswnenwsenwsw").unwrap(),
+                parse_directions("sweneswneswneneenwnewenewwneswswnese").unwrap(),
+                parse_directions("swwesenesewenwneswnwwneseswwne").unwrap(),
+                parse_directions("enesenwswwswneneswsenwnewswseenwsese").unwrap(),
+                parse_directions("wnwnesenesenenwwnenwsewesewsesesew").unwrap(),
+                parse_directions("nenewswnwewswnenesenwnesewesw").unwrap(),
+                parse_directions("eneswnwswnwsenenwnwnwwseeswneewsenese").unwrap(),
+                parse_directions("neswnwewnwnwseenwseesewsenwsweewe").unwrap(),
+                parse_directions("wseweeenwnesenwwwswnew").unwrap()
+            ]),
+            10
+        )
+    }
+}
@@ -0,0 +1,287 @@
+use crate::automaton::Automaton;
+use crate::day24::{calc_target, Direction};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub fn run_days(directions: Vec<Vec<Direction>>, num_days: usize) -> usize {
+    Automaton::new(get_neighbors, rule)
+        .run(calc_initial_state(directions), num_days)
+        .len()
+}
+
+pub fn calc_initial_state(directions: Vec<Vec<Direction>>) -> HashSet<(i32, i32)> {
+    let mut tiles: HashMap<(i32, i32), bool> = HashMap::new();
+    for direction in directions {
+        let val = tiles.entry(calc_target(direction)).or_default();
+        *val = !*val;
+    }
+    tiles
+        .into_iter()
+        .filter_map(|(coord, val)| if val { Some(coord) } else { None })
+        .collect()
+}
+
+pub fn calc_next_day(tiles: HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
+    trace!("{:?}", tiles.iter().sorted().collect::<Vec<&(i32, i32)>>());
+    Automaton::new(get_neighbors, rule).step(&tiles)
+}
+
+/// A black tile stays black with one or two black neighbours, and a white tile turns black with
+/// exactly two.
+fn rule(black: &bool, black_neighbours: usize) -> bool {
+    black_neighbours == 2 || (*black && black_neighbours == 1)
+}
+
+pub fn get_neighbors(point: &(i32, i32)) -> Vec<(i32, i32)> {
+    let (x, y) = point;
+
+    let mut offsets = vec![(1, 0), (-1, 0), (0, -1), (0, 1)];
+    if (point.1 % 2i32).abs() == 0 {
+        offsets.push((-1, 1));
+        offsets.push((-1, -1));
+    } else {
+        offsets.push((1, 1));
+        offsets.push((1, -1));
+    }
+
+    offsets
+        .into_iter()
+        .map(|(x_off, y_off)| (*x + x_off, *y + y_off))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day24::parse_directions;
+
+    #[test]
+    fn test_get_neighbors_even() {
+        assert_eq!(
+            get_neighbors(&(3, 2))
+                .into_iter()
+                .collect::<HashSet<(i32, i32)>>(),
+            vec![(4, 2), (3, 3), (2, 3), (2, 2), (2, 1), (3, 1)]
+                .into_iter()
+                .collect::<HashSet<(i32, i32)>>()
+        )
+    }
+
+    #[test]
+    fn test_get_neighbors_odd() {
+        assert_eq!(
+            get_neighbors(&(3, 3))
+                .into_iter()
+                .collect::<HashSet<(i32, i32)>>(),
+            vec![(4, 3), (4, 4), (3, 4), (2, 3), (3, 2), (4, 2)]
+                .into_iter()
+                .collect::<HashSet<(i32, i32)>>()
+        )
+    }
+
+    #[test]
+    fn test_rule() {
+        assert_eq!(
+            (0..=3)
+                .map(|count| (rule(&true, count), rule(&false, count)))
+                .collect::<Vec<(bool, bool)>>(),
+            vec![(false, false), (true, false), (true, true), (false, false)]
+        )
+    }
+
+    #[test]
+    fn test_calc_next_day() {
+        assert_eq!(
+            calc_next_day(hashset!(
+                (-2, -1),
+                (-2, 0),
+                (-2, 1),
+                (-2, 2),
+                (-2, 3),
+                (-1, -1),
+                (0, 0),
+                (1, -3),
+                (1, 2),
+                (2, 0)
+            )),
+            hashset!(
+                (-3, 3),
+                (-2, 3),
+                (-2, 2),
+                (-2, 1),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (-2, 0),
+                (0, 0),
+                (1, 0),
+                (-3, -1),
+                (-2, -1),
+                (-1, -1),
+                (0, -1),
+                (-1, -2)
+            )
+        )
+    }
+
+    #[test]
+    fn test_run_days_1() {
+        assert_eq!(
+            run_days(
+                vec![
+                    parse_directions("sesenwnenenewseeswwswswwnenewsewsw").unwrap(),
+                    parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap(),
+                    parse_directions("seswneswswsenwwnwse").unwrap(),
+                    parse_directions("nwnwneseeswswnenewneswwnewseswneseene").unwrap(),
+                    parse_directions("swweswneswnenwsewnwneneseenw").unwrap(),
+                    parse_directions("eesenwseswswnenwswnwnwsewwnwsene").unwrap(),
+                    parse_directions("sewnenenenesenwsewnenwwwse").unwrap(),
+                    parse_directions("wenwwweseeeweswwwnwwe").unwrap(),
+                    parse_directions("wsweesenenewnwwnwsenewsenwwsesesenwne").unwrap(),
+                    parse_directions("neeswseenwwswnwswswnw").unwrap(),
+                    parse_directions("nenwswwsewswnenenewsenwsenwnesesenew").unwrap(),
+                    parse_directions("enewnwewneswsewnwswenweswnenwsenwsw").unwrap(),
+                    parse_directions("sweneswneswneneenwnewenewwneswswnese").unwrap(),
+                    parse_directions("swwesenesewenwneswnwwneseswwne").unwrap(),
+                    parse_directions("enesenwswwswneneswsenwnewswseenwsese").unwrap(),
+                    parse_directions("wnwnesenesenenwwnenwsewesewsesesew").unwrap(),
+                    parse_directions("nenewswnwewswnenesenwnesewesw").unwrap(),
+                    parse_directions("eneswnwswnwsenenwnwnwwseeswneewsenese").unwrap(),
+                    parse_directions("neswnwewnwnwseenwseesewsenwsweewe").unwrap(),
+                    parse_directions("wseweeenwnesenwwwswnew").unwrap()
+                ],
+                1
+            ),
+            15
+        )
+    }
+
+    #[test]
+    fn test_run_days_2() {
+        assert_eq!(
+            run_days(
+                vec![
+                    parse_directions("sesenwnenenewseeswwswswwnenewsewsw").unwrap(),
+                    parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap(),
+                    parse_directions("seswneswswsenwwnwse").unwrap(),
+                    parse_directions("nwnwneseeswswnenewneswwnewseswneseene").unwrap(),
+                    parse_directions("swweswneswnenwsewnwneneseenw").unwrap(),
+                    parse_directions("eesenwseswswnenwswnwnwsewwnwsene").unwrap(),
+                    parse_directions("sewnenenenesenwsewnenwwwse").unwrap(),
+                    parse_directions("wenwwweseeeweswwwnwwe").unwrap(),
+                    parse_directions("wsweesenenewnwwnwsenewsenwwsesesenwne").unwrap(),
+                    parse_directions("neeswseenwwswnwswswnw").unwrap(),
+                    parse_directions("nenwswwsewswnenenewsenwsenwnesesenew").unwrap(),
+                    parse_directions("enewnwewneswsewnwswenweswnenwsenwsw").unwrap(),
+                    parse_directions("sweneswneswneneenwnewenewwneswswnese").unwrap(),
+                    parse_directions("swwesenesewenwneswnwwneseswwne").unwrap(),
+                    parse_directions("enesenwswwswneneswsenwnewswseenwsese").unwrap(),
+                    parse_directions("wnwnesenesenenwwnenwsewesewsesesew").unwrap(),
+                    parse_directions("nenewswnwewswnenesenwnesewesw").unwrap(),
+                    parse_directions("eneswnwswnwsenenwnwnwwseeswneewsenese").unwrap(),
+                    parse_directions("neswnwewnwnwseenwseesewsenwsweewe").unwrap(),
+                    parse_directions("wseweeenwnesenwwwswnew").unwrap()
+                ],
+                2
+            ),
+            12
+        )
+    }
+
+    #[test]
+    fn test_run_days_3() {
+        assert_eq!(
+            run_days(
+                vec![
+                    parse_directions("sesenwnenenewseeswwswswwnenewsewsw").unwrap(),
+                    parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap(),
+                    parse_directions("seswneswswsenwwnwse").unwrap(),
+                    parse_directions("nwnwneseeswswnenewneswwnewseswneseene").unwrap(),
+                    parse_directions("swweswneswnenwsewnwneneseenw").unwrap(),
+                    parse_directions("eesenwseswswnenwswnwnwsewwnwsene").unwrap(),
+                    parse_directions("sewnenenenesenwsewnenwwwse").unwrap(),
+                    parse_directions("wenwwweseeeweswwwnwwe").unwrap(),
+                    parse_directions("wsweesenenewnwwnwsenewsenwwsesesenwne").unwrap(),
+                    parse_directions("neeswseenwwswnwswswnw").unwrap(),
+                    parse_directions("nenwswwsewswnenenewsenwsenwnesesenew").unwrap(),
+                    parse_directions("enewnwewneswsewnwswenweswnenwsenwsw").unwrap(),
+                    parse_directions("sweneswneswneneenwnewenewwneswswnese").unwrap(),
+                    parse_directions("swwesenesewenwneswnwwneseswwne").unwrap(),
+                    parse_directions("enesenwswwswneneswsenwnewswseenwsese").unwrap(),
+                    parse_directions("wnwnesenesenenwwnenwsewesewsesesew").unwrap(),
+                    parse_directions("nenewswnwewswnenesenwnesewesw").unwrap(),
+                    parse_directions("eneswnwswnwsenenwnwnwwseeswneewsenese").unwrap(),
+                    parse_directions("neswnwewnwnwseenwseesewsenwsweewe").unwrap(),
+                    parse_directions("wseweeenwnesenwwwswnew").unwrap()
+                ],
+                3
+            ),
+            25
+        )
+    }
+
+    #[test]
+    fn test_run_days_10() {
+        assert_eq!(
+            run_days(
+                vec![
+                    parse_directions("sesenwnenenewseeswwswswwnenewsewsw").unwrap(),
+                    parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap(),
+                    parse_directions("seswneswswsenwwnwse").unwrap(),
+                    parse_directions("nwnwneseeswswnenewneswwnewseswneseene").unwrap(),
+                    parse_directions("swweswneswnenwsewnwneneseenw").unwrap(),
+                    parse_directions("eesenwseswswnenwswnwnwsewwnwsene").unwrap(),
+                    parse_directions("sewnenenenesenwsewnenwwwse").unwrap(),
+                    parse_directions("wenwwweseeeweswwwnwwe").unwrap(),
+                    parse_directions("wsweesenenewnwwnwsenewsenwwsesesenwne").unwrap(),
+                    parse_directions("neeswseenwwswnwswswnw").unwrap(),
+                    parse_directions("nenwswwsewswnenenewsenwsenwnesesenew").unwrap(),
+                    parse_directions("enewnwewneswsewnwswenweswnenwsenwsw").unwrap(),
+                    parse_directions("sweneswneswneneenwnewenewwneswswnese").unwrap(),
+                    parse_directions("swwesenesewenwneswnwwneseswwne").unwrap(),
+                    parse_directions("enesenwswwswneneswsenwnewswseenwsese").unwrap(),
+                    parse_directions("wnwnesenesenenwwnenwsewesewsesesew").unwrap(),
+                    parse_directions("nenewswnwewswnenesenwnesewesw").unwrap(),
+                    parse_directions("eneswnwswnwsenenwnwnwwseeswneewsenese").unwrap(),
+                    parse_directions("neswnwewnwnwseenwseesewsenwsweewe").unwrap(),
+                    parse_directions("wseweeenwnesenwwwswnew").unwrap()
+                ],
+                10
+            ),
+            37
+        )
+    }
+
+    // takes longer than I'd like for part of the normal test suite
+    // #[test]
+    // fn test_run_days_100() {
+    //     assert_eq!(
+    //         run_days(
+    //             vec![
+    //                 parse_directions("sesenwnenenewseeswwswswwnenewsewsw").unwrap(),
+    //                 parse_directions("neeenesenwnwwswnenewnwwsewnenwseswesw").unwrap(),
+    //                 parse_directions("seswneswswsenwwnwse").unwrap(),
+    //                 parse_directions("nwnwneseeswswnenewneswwnewseswneseene").unwrap(),
+    //                 parse_directions("swweswneswnenwsewnwneneseenw").unwrap(),
+    //                 parse_directions("eesenwseswswnenwswnwnwsewwnwsene").unwrap(),
+    //                 parse_directions("sewnenenenesenwsewnenwwwse").unwrap(),
+    //                 parse_directions("wenwwweseeeweswwwnwwe").unwrap(),
+    //                 parse_directions("wsweesenenewnwwnwsenewsenwwsesesenwne").unwrap(),
+    //                 parse_directions("neeswseenwwswnwswswnw").unwrap(),
+    //                 parse_directions("nenwswwsewswnenenewsenwsenwnesesenew").unwrap(),
+    //                 parse_directions("enewnwewneswsewnwswenweswnenwsenwsw").unwrap(),
+    //                 parse_directions("sweneswneswneneenwnewenewwneswswnese").unwrap(),
+    //                 parse_directions("swwesenesewenwneswnwwneseswwne").unwrap(),
+    //                 parse_directions("enesenwswwswneneswsenwnewswseenwsese").unwrap(),
+    //                 parse_directions("wnwnesenesenenwwnenwsewesewsesesew").unwrap(),
+    //                 parse_directions("nenewswnwewswnenesenwnesewesw").unwrap(),
+    //                 parse_directions("eneswnwswnwsenenwnwnwwseeswneewsenese").unwrap(),
+    //                 parse_directions("neswnwewnwnwseenwseesewsenwsweewe").unwrap(),
+    //                 parse_directions("wseweeenwnesenwwwswnew").unwrap()
+    //             ],
+    //             100
+    //         ),
+    //         2208
+    //     )
+    // }
+}
@@ -0,0 +1,70 @@
+use crate::parse::{self, ParseError};
+
+pub mod prob01;
+
+/// Parse the card's and the door's public keys, one per line.  Each must be from 1 up to but not
+/// including the modulus, or no loop size would give it.
+pub fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+    let keys = parse::lines(input)
+        .map(|(i, line)| {
+            let key = parse::number::<u64>(line, line.trim()).map_err(|e| e.on_line(i))?;
+            if key == 0 || key >= prob01::MODULUS {
+                return Err(ParseError::within(
+                    line,
+                    line.trim(),
+                    &format!("expected a public key from 1 to {}", prob01::MODULUS - 1),
+                )
+                .on_line(i));
+            }
+            Ok(key)
+        })
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    if keys.len() != 2 {
+        return Err(ParseError::new(
+            input.lines().count() + 1,
+            1,
+            "",
+            &format!("expected 2 public keys, found {}", keys.len()),
+        ));
+    }
+    Ok((keys[0], keys[1]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("5764801\n17807724\n"), Ok((5764801, 17807724)))
+    }
+
+    #[test]
+    fn test_parse_key_out_of_range() {
+        assert_eq!(
+            (parse("0\n17807724\n"), parse("5764801\n20201227\n")),
+            (
+                Err(ParseError::new(
+                    1,
+                    1,
+                    "0",
+                    "expected a public key from 1 to 20201226"
+                )),
+                Err(ParseError::new(
+                    2,
+                    1,
+                    "20201227",
+                    "expected a public key from 1 to 20201226"
+                ))
+            )
+        )
+    }
+
+    #[test]
+    fn test_parse_missing_key() {
+        assert_eq!(
+            parse("5764801\n"),
+            Err(ParseError::new(2, 1, "", "expected 2 public keys, found 1"))
+        )
+    }
+}
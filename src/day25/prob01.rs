@@ -0,0 +1,48 @@
+/// Every value is taken modulo this prime, to which 7 is a primitive root: looping enough times
+/// from 7 reaches every number from 1 up to it.
+pub const MODULUS: u64 = 20201227;
+
+pub fn get_iterations(pubkey: u64, subject_number: u64) -> u64 {
+    let mut value = 1u64;
+    let mut loop_count = 0u64;
+    while value != pubkey {
+        value *= subject_number;
+        value %= MODULUS;
+        loop_count += 1;
+    }
+    loop_count
+}
+
+pub fn get_encryption_key(pubkey: u64, loop_count: u64) -> u64 {
+    let mut value = 1u64;
+    for _ in 0..loop_count {
+        value *= pubkey;
+        value %= MODULUS;
+    }
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_iterations1() {
+        assert_eq!(get_iterations(5764801, 7), 8)
+    }
+
+    #[test]
+    fn test_get_iterations2() {
+        assert_eq!(get_iterations(17807724, 7), 11)
+    }
+
+    #[test]
+    fn test_get_encryption_key1() {
+        assert_eq!(get_encryption_key(17807724, 8), 14897079)
+    }
+
+    #[test]
+    fn test_get_encryption_key2() {
+        assert_eq!(get_encryption_key(5764801, 11), 14897079)
+    }
+}
@@ -0,0 +1,452 @@
+use crate::parse::{self, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid, as (row, column).
+pub type Pos = (usize, usize);
+
+/// Offsets to the four orthogonal neighbours of a cell, clockwise from up.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to all eight neighbours of a cell, clockwise from up.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A cell that is drawn as a single character in a puzzle's map.
+pub trait Glyph: Sized {
+    /// The characters a cell can be drawn as, for error messages, e.g. `'.' or '#'`.
+    const GLYPHS: &'static str;
+
+    fn from_glyph(c: char) -> Option<Self>;
+    fn glyph(&self) -> char;
+}
+
+/// Open (`.`) and filled (`#`) squares, as used by most of the puzzle maps.
+impl Glyph for bool {
+    const GLYPHS: &'static str = "'.' or '#'";
+
+    fn from_glyph(c: char) -> Option<bool> {
+        match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }
+    }
+
+    fn glyph(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all be the same length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Build a `height` by `width` grid, filling each cell with `f` of its position.
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The position `offset` away from `pos`, if it is still in the grid.
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell alongside its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    /// The cells of a column, top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {} is out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// The orthogonal neighbours of `pos` that are in the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are in the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// The positions reached by repeatedly stepping `direction` from `pos` (not including `pos`
+    /// itself) until leaving the grid.
+    pub fn ray(&self, pos: Pos, direction: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, direction), move |&pos| {
+            self.offset(pos, direction)
+        })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swap rows and columns, mirroring the grid along its leading diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// Turn the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    /// Turn the grid a quarter turn counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirror the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(row, self.width - 1 - col)].clone()
+        })
+    }
+
+    /// Mirror the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - row, col)].clone()
+        })
+    }
+}
+
+impl<T: Glyph> Grid<T> {
+    /// Parse a map with a character per cell and a line per row, skipping blank lines.
+    pub fn parse(input: &str) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (i, line) in parse::lines(input) {
+            let row = line
+                .char_indices()
+                .map(|(j, c)| {
+                    T::from_glyph(c).ok_or_else(|| {
+                        ParseError::within(
+                            line,
+                            &line[j..j + c.len_utf8()],
+                            &format!("expected {}", T::GLYPHS),
+                        )
+                        .on_line(i)
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::within(
+                        line,
+                        line,
+                        &format!(
+                            "expected a row of {} cells to match the first row",
+                            first.len()
+                        ),
+                    )
+                    .on_line(i));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, height, width))
+    }
+}
+
+/// Draws the grid as the map it was parsed from, a line per row.
+impl<T: Glyph> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.glyph())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Grid::parse("#.\n\n.#\n"),
+            Ok(Grid::from_rows(vec![vec![true, false], vec![false, true]]))
+        )
+    }
+
+    #[test]
+    fn test_parse_bad_char() {
+        assert_eq!(
+            Grid::<bool>::parse("#.\n.x\n"),
+            Err(ParseError::new(2, 2, "x", "expected '.' or '#'"))
+        )
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert_eq!(
+            Grid::<bool>::parse("#.\n.##\n"),
+            Err(ParseError::new(
+                2,
+                1,
+                ".##",
+                "expected a row of 2 cells to match the first row"
+            ))
+        )
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(grid(&["#..", ".#."]).to_string(), "#..\n.#.")
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid(&["#..", ".#."]);
+        assert_eq!(
+            (grid.get((1, 1)), grid.get((2, 0)), grid.get((0, 3))),
+            (Some(&true), None, None)
+        )
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = grid(&["#..", ".#."]);
+        grid[(1, 2)] = true;
+        assert_eq!(grid.to_string(), "#..\n.##")
+    }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(
+            grid(&["#.", ".#"])
+                .iter()
+                .filter(|(_, cell)| **cell)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<Pos>>(),
+            vec![(0, 0), (1, 1)]
+        )
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid(&["#..", ".#."]);
+        assert_eq!(
+            (
+                grid.rows()
+                    .map(|row| row.to_vec())
+                    .collect::<Vec<Vec<bool>>>(),
+                grid.column(1).copied().collect::<Vec<bool>>()
+            ),
+            (
+                vec![vec![true, false, false], vec![false, true, false]],
+                vec![false, true]
+            )
+        )
+    }
+
+    #[test]
+    fn test_neighbours4_corner() {
+        assert_eq!(
+            grid(&["...", "..."])
+                .neighbours4((0, 0))
+                .collect::<Vec<Pos>>(),
+            vec![(0, 1), (1, 0)]
+        )
+    }
+
+    #[test]
+    fn test_neighbours8_middle() {
+        assert_eq!(grid(&["...", "...", "..."]).neighbours8((1, 1)).count(), 8)
+    }
+
+    #[test]
+    fn test_ray() {
+        assert_eq!(
+            grid(&["....", "....", "...."])
+                .ray((0, 0), (1, 1))
+                .collect::<Vec<Pos>>(),
+            vec![(1, 1), (2, 2)]
+        )
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(
+            grid(&["#.", ".."]).map(|_, cell| !cell),
+            grid(&[".#", "##"])
+        )
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(grid(&["#..", "##."]).transpose(), grid(&["##", ".#", ".."]))
+    }
+
+    #[test]
+    fn test_rotate_clockwise() {
+        assert_eq!(
+            grid(&["#..", "##."]).rotate_clockwise(),
+            grid(&["##", "#.", ".."])
+        )
+    }
+
+    #[test]
+    fn test_rotate_counter_clockwise() {
+        assert_eq!(
+            grid(&["#..", "##."]).rotate_counter_clockwise(),
+            grid(&["..", ".#", "##"])
+        )
+    }
+
+    #[test]
+    fn test_rotations_cancel() {
+        let grid = grid(&["#..", "##.", "..#", "#.."]);
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid)
+    }
+
+    #[test]
+    fn test_flip_horizontal() {
+        assert_eq!(
+            grid(&["#..", "##."]).flip_horizontal(),
+            grid(&["..#", ".##"])
+        )
+    }
+
+    #[test]
+    fn test_flip_vertical() {
+        assert_eq!(grid(&["#..", "##."]).flip_vertical(), grid(&["##.", "#.."]))
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Just enough of JSON to write machine-readable reports.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i128),
+    String(String),
+    Array(Vec<Json>),
+    /// Fields are written in the order given.
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object(fields: Vec<(&str, Json)>) -> Json {
+        Json::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    /// An answer written as text, like one on record: a number if it is an integer, otherwise
+    /// the text.
+    pub fn answer(answer: &str) -> Json {
+        match answer.parse::<i128>() {
+            Ok(n) => Json::Int(n),
+            Err(_) => Json::from(answer),
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Json {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Json {
+        Json::String(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Json {
+        Json::Int(value as i128)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Json {
+        Json::Int(value as i128)
+    }
+}
+
+/// Durations are written in whole nanoseconds.
+impl From<Duration> for Json {
+    fn from(value: Duration) -> Json {
+        Json::Int(value.as_nanos() as i128)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Json {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(n) => write!(f, "{}", n),
+            Json::String(s) => write_string(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_object() {
+        assert_eq!(
+            Json::object(vec![
+                ("day", Json::from(1u8)),
+                ("answer", Json::answer("514579")),
+                ("error", Json::Null),
+                ("ok", Json::Bool(true)),
+            ])
+            .to_string(),
+            r#"{"day":1,"answer":514579,"error":null,"ok":true}"#
+        )
+    }
+
+    #[test]
+    fn test_array() {
+        assert_eq!(
+            Json::Array(vec![
+                Json::Int(-1),
+                Json::Array(vec![]),
+                Json::object(vec![])
+            ])
+            .to_string(),
+            "[-1,[],{}]"
+        )
+    }
+
+    #[test]
+    fn test_answer_wide() {
+        assert_eq!(
+            Json::answer("18446744073709551615"),
+            Json::Int(u64::MAX as i128)
+        )
+    }
+
+    #[test]
+    fn test_answer_text() {
+        assert_eq!(Json::answer("(1, 35196)"), Json::from("(1, 35196)"))
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            Json::from("a \"b\"\\\n\u{1}").to_string(),
+            r#""a \"b\"\\\n\u0001""#
+        )
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(Json::from(Duration::from_micros(3)), Json::Int(3000))
+    }
+
+    #[test]
+    fn test_option() {
+        assert_eq!(Json::from(None::<&str>), Json::Null)
+    }
+}
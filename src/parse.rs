@@ -0,0 +1,180 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// An error found while parsing puzzle input, pointing at the text that could not be understood.
+///
+/// Parsers that only ever see a single line report errors on line 1; the parser for the whole
+/// input moves them to the right line with `on_line`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1-based line of the input the error was found on.
+    pub line: usize,
+    /// 1-based column (in characters) of the offending text within that line.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error for `part` found within a single `line` of input.  `part` should be a slice of
+    /// `line`; if it isn't, the first occurrence of it is used for the column.
+    pub fn within(line: &str, part: &str, message: &str) -> ParseError {
+        ParseError::new(1, column_of(line, part), part, message)
+    }
+
+    /// Move an error reported by a single-line parser to line `line` of the whole input.
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line += line - 1;
+        self
+    }
+
+    /// Render the error alongside the line of `input` it refers to, with the offending text
+    /// underlined.
+    pub fn render(&self, input: &str) -> String {
+        match input.lines().nth(self.line - 1) {
+            Some(source) => {
+                let underline = "^".repeat(self.text.chars().count().max(1));
+                format!(
+                    "{}\n    {}\n    {}{}",
+                    self,
+                    source,
+                    " ".repeat(self.column - 1),
+                    underline
+                )
+            }
+            None => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found '{}')", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based character column of `part` within `line`.
+fn column_of(line: &str, part: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+    let offset = if part_start >= line_start && part_start + part.len() <= line_start + line.len() {
+        part_start - line_start
+    } else {
+        line.find(part).unwrap_or(0)
+    };
+    line[..offset].chars().count() + 1
+}
+
+/// Parse `part` of `line` as a number, reporting where it sits in the line if it isn't one.
+pub fn number<T: FromStr>(line: &str, part: &str) -> Result<T, ParseError> {
+    part.parse::<T>()
+        .map_err(|_| ParseError::within(line, part, "expected a number"))
+}
+
+/// The non-empty lines of `input`, each paired with its 1-based line number.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// Read and parse the input file at `path`, exiting with a diagnostic if either step fails.
+pub fn load<T>(path: &str, parse: impl Fn(&str) -> Result<T, ParseError>) -> T {
+    let input = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Unable to open file {}: {}", path, e);
+        std::process::exit(1);
+    });
+    parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}: {}", path, e.render(&input));
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_within_slice() {
+        let line = "nop +0 nop";
+        assert_eq!(
+            ParseError::within(line, &line[7..], "bad"),
+            ParseError::new(1, 8, "nop", "bad")
+        )
+    }
+
+    #[test]
+    fn test_within_search() {
+        assert_eq!(
+            ParseError::within("acc +x", "+x", "bad"),
+            ParseError::new(1, 5, "+x", "bad")
+        )
+    }
+
+    #[test]
+    fn test_on_line() {
+        assert_eq!(
+            ParseError::new(1, 3, "x", "bad").on_line(4),
+            ParseError::new(4, 3, "x", "bad")
+        )
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u8>("a 12", "12"), Ok(12))
+    }
+
+    #[test]
+    fn test_number_error() {
+        let line = "a 1x";
+        assert_eq!(
+            number::<u8>(line, &line[2..]),
+            Err(ParseError::new(1, 3, "1x", "expected a number"))
+        )
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            lines("a\n\nb\n").collect::<Vec<(usize, &str)>>(),
+            vec![(1, "a"), (3, "b")]
+        )
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::new(2, 5, "+x", "expected a number").to_string(),
+            "line 2, column 5: expected a number (found '+x')"
+        )
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            ParseError::new(2, 5, "+x", "expected a number").render("nop +0\nacc +x\n"),
+            "line 2, column 5: expected a number (found '+x')\n    acc +x\n        ^^"
+        )
+    }
+}
@@ -0,0 +1,561 @@
+use crate::json::Json;
+use crate::parse::ParseError;
+use crate::*;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+
+/// A solution's answer, kept structured so that it can be written either as text or as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// An answer made up of several values, like a tuple or a grid.
+    List(Vec<Answer>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::List(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Answer {
+                Answer::Int(value as i128)
+            }
+        })*
+    };
+}
+
+int_answer!(u16, u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Answer {
+        Answer::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<A: Into<Answer>, B: Into<Answer>> From<(A, B)> for Answer {
+    fn from((a, b): (A, B)) -> Answer {
+        Answer::List(vec![a.into(), b.into()])
+    }
+}
+
+impl From<&Answer> for Json {
+    fn from(answer: &Answer) -> Json {
+        match answer {
+            Answer::Int(n) => Json::Int(*n),
+            Answer::Text(text) => Json::from(text.as_str()),
+            Answer::List(values) => Json::Array(values.iter().map(Json::from).collect()),
+        }
+    }
+}
+
+/// A parsed input ready to be solved, producing its answer or saying why it hasn't got one.
+pub type Solver = Box<dyn FnOnce() -> Result<Answer, String>>;
+
+/// Why a solution couldn't answer an input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input parsed, but has no answer.
+    Unsolved(String),
+}
+
+impl SolveError {
+    /// Describe the error, showing where in `input` it was found if it was a parse error.
+    pub fn render(&self, input: &str) -> String {
+        match self {
+            SolveError::Parse(e) => e.render(input),
+            SolveError::Unsolved(_) => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Unsolved(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+/// A single puzzle part that can be run against the text of an input file.
+///
+/// Parsing and solving are kept apart so they can be timed separately: `prepare` parses the
+/// input and returns the work still left to do as a `Solver`.
+pub struct Solution {
+    pub day: u8,
+    pub part: u8,
+    prepare: Prepare,
+}
+
+/// Rules to replace some of those in a day 19 input's grammar with.
+pub type Overrides = HashMap<usize, day19::Symbol>;
+
+/// How a solution parses its input.
+enum Prepare {
+    Input(fn(&str) -> Result<Solver, ParseError>),
+    /// The input's rules are patched with overrides, read from a file of their own.
+    WithOverrides(fn(&str, &Overrides) -> Result<Solver, ParseError>),
+}
+
+impl Solution {
+    pub fn new(day: u8, part: u8, prepare: fn(&str) -> Result<Solver, ParseError>) -> Solution {
+        Solution {
+            day,
+            part,
+            prepare: Prepare::Input(prepare),
+        }
+    }
+
+    /// A solution that also takes rule overrides.
+    pub fn with_overrides(
+        day: u8,
+        part: u8,
+        prepare: fn(&str, &Overrides) -> Result<Solver, ParseError>,
+    ) -> Solution {
+        Solution {
+            day,
+            part,
+            prepare: Prepare::WithOverrides(prepare),
+        }
+    }
+
+    /// Parse `input`, returning the work still left to do.  Solutions that take overrides leave
+    /// the input's rules as they are if there aren't any; the rest ignore them.
+    pub fn prepare(
+        &self,
+        input: &str,
+        overrides: Option<&Overrides>,
+    ) -> Result<Solver, ParseError> {
+        match self.prepare {
+            Prepare::Input(prepare) => prepare(input),
+            Prepare::WithOverrides(prepare) => prepare(input, overrides.unwrap_or(&HashMap::new())),
+        }
+    }
+
+    /// Parse and solve `input` in one go.
+    pub fn solve(&self, input: &str, overrides: Option<&Overrides>) -> Result<Answer, SolveError> {
+        self.prepare(input, overrides)?().map_err(SolveError::Unsolved)
+    }
+
+    /// Path of the puzzle input checked into the repository for this day.
+    pub fn default_input(&self) -> String {
+        format!("src/bin/day{:02}.txt", self.day)
+    }
+
+    /// Path of the overrides checked into the repository for this day, if the solution takes
+    /// any.
+    pub fn default_overrides(&self) -> Option<String> {
+        match self.prepare {
+            Prepare::Input(_) => None,
+            Prepare::WithOverrides(_) => Some(format!("src/bin/day{:02}-overrides.txt", self.day)),
+        }
+    }
+}
+
+/// Wrap the solving half of a solution, converting its answer into an `Answer`.
+fn solver<T: Into<Answer>>(solve: impl FnOnce() -> T + 'static) -> Result<Solver, ParseError> {
+    try_solver(move || Ok(solve()))
+}
+
+/// Wrap the solving half of a solution that can find its input has no answer.
+fn try_solver<T: Into<Answer>>(
+    solve: impl FnOnce() -> Result<T, String> + 'static,
+) -> Result<Solver, ParseError> {
+    Ok(Box::new(move || solve().map(Into::into)))
+}
+
+/// Every solution in the crate, ordered by day and then part.
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        Solution::new(1, 1, |input| {
+            let entries = day01::parse(input)?;
+            try_solver(move || {
+                day01::prob01::find(entries, 2020)
+                    .ok_or_else(|| "no two entries sum to 2020".to_string())
+            })
+        }),
+        Solution::new(1, 2, |input| {
+            let entries = day01::parse(input)?;
+            try_solver(move || {
+                day01::prob02::find(entries, 2020)
+                    .ok_or_else(|| "no three entries sum to 2020".to_string())
+            })
+        }),
+        Solution::new(2, 1, |input| {
+            let passwords = day02::parse(input)?;
+            solver(move || day02::prob01::count_valid(&passwords))
+        }),
+        Solution::new(2, 2, |input| {
+            let passwords = day02::parse(input)?;
+            solver(move || day02::prob02::count_valid(&passwords))
+        }),
+        Solution::new(3, 1, |input| {
+            let hill = Rc::new(day03::parse(input)?);
+            solver(move || day03::prob01::count_trees(hill))
+        }),
+        Solution::new(3, 2, |input| {
+            let hill = Rc::new(day03::parse(input)?);
+            solver(move || day03::prob02::multiply_slopes(hill))
+        }),
+        Solution::new(4, 1, |input| {
+            let passports = day04::parse(input)?;
+            solver(move || day04::prob01::count_valid(&passports))
+        }),
+        Solution::new(4, 2, |input| {
+            let passports = day04::parse(input)?;
+            solver(move || day04::prob02::count_valid(&passports))
+        }),
+        Solution::new(5, 1, |input| {
+            let passes = day05::parse(input)?;
+            try_solver(move || {
+                day05::prob01::max_id(&passes).ok_or_else(|| "no boarding passes".to_string())
+            })
+        }),
+        Solution::new(5, 2, |input| {
+            let passes = day05::parse(input)?;
+            try_solver(move || day05::prob02::find_seat(passes))
+        }),
+        Solution::new(6, 1, |input| {
+            let groups = day06::parse(input)?;
+            solver(move || day06::prob01::sum_counts(&groups))
+        }),
+        Solution::new(6, 2, |input| {
+            let groups = day06::parse(input)?;
+            solver(move || day06::prob02::sum_counts(&groups))
+        }),
+        Solution::new(7, 1, |input| {
+            let rules = day07::parse(input)?;
+            solver(move || day07::prob01::find_shiny_gold(rules).len())
+        }),
+        Solution::new(7, 2, |input| {
+            let rules = day07::parse(input)?;
+            try_solver(move || {
+                day07::prob02::shiny_gold_contains(rules)
+                    .ok_or_else(|| "no rule says what a shiny gold bag holds".to_string())
+            })
+        }),
+        Solution::new(8, 1, |input| {
+            let program = day08::parse(input)?;
+            try_solver(move || {
+                day08::prob01::run_program(program)
+                    .map_err(|outcome| format!("the program did not loop: {:?}", outcome))
+            })
+        }),
+        Solution::new(8, 2, |input| {
+            let program = day08::parse(input)?;
+            try_solver(move || {
+                day08::prob02::fix_bug(program)
+                    .map_err(|repair| format!("unable to fix program: {}", repair))
+            })
+        }),
+        Solution::new(9, 1, |input| {
+            let cypher_text = day09::parse(input)?;
+            try_solver(move || {
+                day09::prob01::xmas_corruption(&cypher_text, 25)
+                    .ok_or_else(|| "every number is the sum of two before it".to_string())
+            })
+        }),
+        Solution::new(9, 2, |input| {
+            let cypher_text = day09::parse(input)?;
+            try_solver(move || {
+                let corrupted = day09::prob01::xmas_corruption(&cypher_text, 25)
+                    .ok_or_else(|| "every number is the sum of two before it".to_string())?;
+                day09::prob02::xmas_fix(&cypher_text, corrupted)
+                    .ok_or_else(|| format!("no run of numbers sums to {}", corrupted))
+            })
+        }),
+        Solution::new(10, 1, |input| {
+            let adapters = day10::parse(input)?;
+            try_solver(move || day10::prob01::chain_adapters(adapters))
+        }),
+        Solution::new(10, 2, |input| {
+            let adapters = day10::parse(input)?;
+            try_solver(move || {
+                day10::prob02::chain_adapters(adapters).ok_or_else(|| "no adapters".to_string())
+            })
+        }),
+        Solution::new(11, 1, |input| {
+            let seats = day11::parse(input)?;
+            solver(move || day11::prob01::find_stable(seats))
+        }),
+        Solution::new(11, 2, |input| {
+            let seats = day11::parse(input)?;
+            solver(move || day11::prob02::find_stable(seats))
+        }),
+        Solution::new(12, 1, |input| {
+            let instructions = day12::parse(input)?;
+            solver(move || day12::prob01::navigate(instructions))
+        }),
+        Solution::new(12, 2, |input| {
+            let instructions = day12::parse(input)?;
+            solver(move || day12::prob02::navigate(instructions))
+        }),
+        Solution::new(13, 1, |input| {
+            let notes = day13::parse(input)?;
+            try_solver(move || {
+                day13::prob01::find_first_shuttle(notes.earliest, notes.in_service())
+            })
+        }),
+        Solution::new(13, 2, |input| {
+            let notes = day13::parse(input)?;
+            try_solver(move || day13::prob02::find_sequential_departures(notes.ids))
+        }),
+        Solution::new(14, 1, |input| {
+            let instructions = day14::parse(input, day14::prob01::parse_mask)?;
+            solver(move || day14::prob01::run(instructions))
+        }),
+        Solution::new(14, 2, |input| {
+            let instructions = day14::parse(input, day14::prob02::parse_mask)?;
+            solver(move || day14::prob02::run(instructions))
+        }),
+        Solution::new(15, 1, |input| {
+            let numbers = day15::parse(input)?;
+            solver(move || day15::prob01::play(numbers))
+        }),
+        Solution::new(15, 2, |input| {
+            let numbers = day15::parse(input)?;
+            solver(move || day15::prob02::play(numbers))
+        }),
+        Solution::new(16, 1, |input| {
+            let notes = day16::parse(input)?;
+            solver(move || day16::prob01::nearby_error_rate(&notes))
+        }),
+        Solution::new(16, 2, |input| {
+            let notes = day16::parse(input)?;
+            try_solver(move || {
+                day16::prob02::departure_product(&notes)
+                    .ok_or_else(|| "the tickets don't pin down every field".to_string())
+            })
+        }),
+        Solution::new(17, 1, |input| {
+            let cells = day17::prob01::initial_state(&day17::parse(input)?);
+            solver(move || day17::prob01::boot(cells))
+        }),
+        Solution::new(17, 2, |input| {
+            let cells = day17::prob02::initial_state(&day17::parse(input)?);
+            solver(move || day17::prob02::boot(cells))
+        }),
+        Solution::new(18, 1, |input| {
+            let exprs = day18::parse(input, day18::prob01::parse_expr)?;
+            try_solver(move || day18::sum(&exprs).map_err(|e| e.to_string()))
+        }),
+        Solution::new(18, 2, |input| {
+            let exprs = day18::parse(input, day18::prob02::parse_expr)?;
+            try_solver(move || day18::sum(&exprs).map_err(|e| e.to_string()))
+        }),
+        Solution::new(19, 1, |input| {
+            let (grammar, messages) = day19::parse(input);
+            let grammar = day19::Symbol::parse_grammar(grammar)?;
+            solver(move || day19::prob01::validate_messages(grammar, messages))
+        }),
+        Solution::with_overrides(19, 2, |input, overrides| {
+            let (grammar, messages) = day19::parse(input);
+            let grammar = day19::prob02::patch_grammar(
+                day19::Symbol::parse_grammar(grammar)?,
+                overrides.clone(),
+            );
+            solver(move || day19::prob02::validate_messages(grammar, messages))
+        }),
+        Solution::new(20, 1, |input| {
+            let tiles = day20::parse(input)?;
+            solver(move || day20::prob01::find_corners(tiles).iter().product::<usize>())
+        }),
+        Solution::new(20, 2, |input| {
+            let tiles = day20::parse(input)?;
+            try_solver(move || {
+                Ok(day20::prob02::assemble_tiles(tiles)?
+                    .iter()
+                    .map(|row| row.iter().map(|tile| tile.id).collect::<Vec<usize>>())
+                    .collect::<Vec<Vec<usize>>>())
+            })
+        }),
+        Solution::new(21, 1, |input| {
+            let foods = day21::parse(input)?;
+            solver(move || day21::prob01::problem1(foods))
+        }),
+        Solution::new(21, 2, |input| {
+            let foods = day21::parse(input)?;
+            solver(move || day21::prob02::problem2(foods))
+        }),
+        Solution::new(22, 1, |input| {
+            let (p1, p2) = day22::parse(input)?;
+            solver(move || day22::prob01::play_game(p1, p2))
+        }),
+        Solution::new(22, 2, |input| {
+            let (p1, p2) = day22::parse(input)?;
+            solver(move || day22::prob02::play_game(p1, p2).1)
+        }),
+        Solution::new(23, 1, |input| {
+            let cups = VecDeque::from(day23::parse(input)?);
+            solver(move || day23::prob01::steps(cups, 100))
+        }),
+        Solution::new(23, 2, |input| {
+            let cups = day23::parse(input)?
+                .into_iter()
+                .map(u32::from)
+                .collect::<VecDeque<u32>>();
+            solver(move || {
+                let next_two = day23::prob02::steps(cups, 10_000_000);
+                next_two.0 as u64 * next_two.1 as u64
+            })
+        }),
+        Solution::new(24, 1, |input| {
+            let directions = day24::parse(input)?;
+            solver(move || day24::prob01::flip_tiles(directions))
+        }),
+        Solution::new(24, 2, |input| {
+            let directions = day24::parse(input)?;
+            solver(move || day24::prob02::run_days(directions, 100))
+        }),
+        Solution::new(25, 1, |input| {
+            let (pubkey1, pubkey2) = day25::parse(input)?;
+            solver(move || {
+                let iterations = day25::prob01::get_iterations(pubkey2, 7);
+                day25::prob01::get_encryption_key(pubkey1, iterations)
+            })
+        }),
+    ]
+}
+
+/// Look up the solution for a single day and part.
+pub fn find(day: u8, part: u8) -> Option<Solution> {
+    solutions()
+        .into_iter()
+        .find(|solution| solution.day == day && solution.part == part)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_solutions_ordered() {
+        let keys = solutions()
+            .iter()
+            .map(|solution| (solution.day, solution.part))
+            .collect::<Vec<(u8, u8)>>();
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(keys, sorted)
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(16, 2).map(|s| (s.day, s.part)), Some((16, 2)))
+    }
+
+    #[test]
+    fn test_find_missing() {
+        assert!(find(25, 2).is_none())
+    }
+
+    #[test]
+    fn test_solve_from_text() {
+        let solution = find(1, 1).unwrap();
+        assert_eq!(
+            solution.solve("1721\n979\n366\n299\n675\n1456\n", None),
+            Ok(Answer::Int(514579))
+        )
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(
+            Answer::from(vec![(1u32, "a".to_string()), (2, "b".to_string())]).to_string(),
+            "[[1, a], [2, b]]"
+        )
+    }
+
+    #[test]
+    fn test_answer_json() {
+        assert_eq!(
+            Json::from(&Answer::from((u64::MAX, "x".to_string()))).to_string(),
+            r#"[18446744073709551615,"x"]"#
+        )
+    }
+
+    #[test]
+    fn test_solve_with_overrides() {
+        let solution = find(19, 2).unwrap();
+        let input = "0: 1\n1: 2\n2: \"a\"\n\na\naa\n";
+        let overrides = day19::Symbol::parse_overrides("1: 2 | 2 1").unwrap();
+        assert_eq!(
+            (
+                solution.solve(input, None),
+                solution.solve(input, Some(&overrides))
+            ),
+            (Ok(Answer::Int(1)), Ok(Answer::Int(2)))
+        )
+    }
+
+    #[test]
+    fn test_default_overrides() {
+        assert_eq!(
+            (
+                find(19, 1).unwrap().default_overrides(),
+                find(19, 2).unwrap().default_overrides()
+            ),
+            (None, Some("src/bin/day19-overrides.txt".to_string()))
+        )
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let solution = find(1, 1).unwrap();
+        assert_eq!(
+            solution.solve("1721\n97x\n", None),
+            Err(SolveError::Parse(ParseError::new(
+                2,
+                1,
+                "97x",
+                "expected a number"
+            )))
+        )
+    }
+
+    #[test]
+    fn test_solve_no_answer() {
+        let solution = find(1, 1).unwrap();
+        assert_eq!(
+            solution.solve("", None),
+            Err(SolveError::Unsolved(
+                "no two entries sum to 2020".to_string()
+            ))
+        )
+    }
+}
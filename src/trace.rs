@@ -0,0 +1,109 @@
+//! Runtime-selectable diagnostics for the solvers.
+//!
+//! Solvers report intermediate state with the `debug!` and `trace!` macros, which print to
+//! stderr only when the level has been raised (`-v` and `-vv` on the command line), so default
+//! runs stay quiet and stdout only ever holds answers.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Nothing but answers and errors.
+    Quiet = 0,
+    /// Progress and per-round summaries (`-v`).
+    Debug = 1,
+    /// Every step the solvers take (`-vv`).
+    Trace = 2,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Debug,
+        _ => Level::Trace,
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    self::level() >= level
+}
+
+/// The level selected by a verbosity flag: `-v` for `Debug`, `-vv` (or more) for `Trace`.
+pub fn parse_flag(flag: &str) -> Option<Level> {
+    let count = flag.strip_prefix('-')?.len();
+    if count == 0 || flag[1..].chars().any(|c| c != 'v') {
+        return None;
+    }
+    Some(if count == 1 {
+        Level::Debug
+    } else {
+        Level::Trace
+    })
+}
+
+/// Set the level from any verbosity flags among the program's arguments, for binaries that take
+/// no other arguments.
+pub fn init_from_args() {
+    if let Some(level) = std::env::args()
+        .skip(1)
+        .filter_map(|arg| parse_flag(&arg))
+        .max()
+    {
+        set_level(level);
+    }
+}
+
+/// Print to stderr when running with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Print to stderr when running with `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_flag() {
+        assert_eq!(
+            ["-v", "-vv", "-vvv", "-", "-x", "-vx", "v"]
+                .iter()
+                .map(|flag| parse_flag(flag))
+                .collect::<Vec<Option<Level>>>(),
+            vec![
+                Some(Level::Debug),
+                Some(Level::Trace),
+                Some(Level::Trace),
+                None,
+                None,
+                None,
+                None
+            ]
+        )
+    }
+
+    #[test]
+    fn test_levels_ordered() {
+        assert!(Level::Quiet < Level::Debug && Level::Debug < Level::Trace)
+    }
+}